@@ -0,0 +1,218 @@
+use crate::{
+    dependencies::BackstopClient,
+    errors::ShareTokenError,
+    events::ShareTokenEvents,
+    storage::{self, AllowanceValue},
+};
+use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, String};
+
+/// ### Backstop Share Token
+///
+/// A SEP-41 token representing the non-queued backstop shares of a single pool. Balances are
+/// read from the backstop, which remains the source of truth, and transfers move shares within
+/// the backstop so emissions are accrued correctly for both users.
+#[contract]
+pub struct BackstopShareTokenContract;
+
+#[contractclient(name = "BackstopShareTokenClient")]
+pub trait BackstopShareToken {
+    /// Fetch the allowance of `spender` for shares owned by `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The owner of the shares
+    /// * `spender` - The address allowed to transfer the shares
+    fn allowance(e: Env, from: Address, spender: Address) -> i128;
+
+    /// Set the allowance of `spender` for shares owned by `from`, overwriting any existing
+    /// allowance
+    ///
+    /// ### Arguments
+    /// * `from` - The owner of the shares
+    /// * `spender` - The address allowed to transfer the shares
+    /// * `amount` - The amount of shares the spender can transfer
+    /// * `expiration_ledger` - The ledger the allowance expires on
+    ///
+    /// ### Errors
+    /// If the amount is negative, or the expiration ledger has passed for a non-zero amount
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32);
+
+    /// Fetch the non-queued backstop shares of the pool owned by `id`
+    ///
+    /// ### Arguments
+    /// * `id` - The owner of the shares
+    fn balance(e: Env, id: Address) -> i128;
+
+    /// Transfer shares from `from` to `to`
+    ///
+    /// ### Arguments
+    /// * `from` - The address sending shares
+    /// * `to` - The address receiving shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the amount is negative, or `from` does not have enough non-queued shares
+    fn transfer(e: Env, from: Address, to: Address, amount: i128);
+
+    /// Transfer shares from `from` to `to` using the allowance of `spender`
+    ///
+    /// ### Arguments
+    /// * `spender` - The address spending its allowance
+    /// * `from` - The address sending shares
+    /// * `to` - The address receiving shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the amount is negative or over the allowance, or `from` does not have enough
+    /// non-queued shares
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128);
+
+    /// Not supported. Shares can only be redeemed through the backstop's withdrawal queue.
+    fn burn(e: Env, from: Address, amount: i128);
+
+    /// Not supported. Shares can only be redeemed through the backstop's withdrawal queue.
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128);
+
+    /// Fetch the decimals of the shares
+    fn decimals(e: Env) -> u32;
+
+    /// Fetch the name of the token
+    fn name(e: Env) -> String;
+
+    /// Fetch the symbol of the token
+    fn symbol(e: Env) -> String;
+
+    /// Fetch the backstop that holds the shares
+    fn backstop(e: Env) -> Address;
+
+    /// Fetch the pool the shares are for
+    fn pool(e: Env) -> Address;
+}
+
+#[contractimpl]
+impl BackstopShareTokenContract {
+    /// Construct the share token contract
+    ///
+    /// ### Arguments
+    /// * `backstop` - The backstop that holds the shares
+    /// * `pool` - The pool the shares are for
+    pub fn __constructor(e: Env, backstop: Address, pool: Address) {
+        storage::set_backstop(&e, &backstop);
+        storage::set_pool(&e, &pool);
+    }
+}
+
+/// @dev
+/// The contract implementation only manages the authorization / authentication required from the caller(s), and
+/// utilizes the backstop to carry out share transfers.
+#[contractimpl]
+impl BackstopShareToken for BackstopShareTokenContract {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        storage::get_allowance(&e, &from, &spender).amount
+    }
+
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        require_nonnegative(&e, amount);
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            panic_with_error!(&e, ShareTokenError::InvalidExpirationLedger);
+        }
+        storage::set_allowance(
+            &e,
+            &from,
+            &spender,
+            &AllowanceValue {
+                amount,
+                expiration_ledger,
+            },
+        );
+
+        ShareTokenEvents::approve(&e, from, spender, amount, expiration_ledger);
+    }
+
+    fn balance(e: Env, id: Address) -> i128 {
+        BackstopClient::new(&e, &storage::get_backstop(&e))
+            .user_balance(&storage::get_pool(&e), &id)
+            .shares
+    }
+
+    fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        require_nonnegative(&e, amount);
+        transfer_shares(&e, &from, &to, amount);
+
+        ShareTokenEvents::transfer(&e, from, to, amount);
+    }
+
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        spender.require_auth();
+
+        require_nonnegative(&e, amount);
+        let mut allowance = storage::get_allowance(&e, &from, &spender);
+        if allowance.amount < amount {
+            panic_with_error!(&e, ShareTokenError::AllowanceError);
+        }
+        if amount > 0 {
+            allowance.amount -= amount;
+            storage::set_allowance(&e, &from, &spender, &allowance);
+        }
+        transfer_shares(&e, &from, &to, amount);
+
+        ShareTokenEvents::transfer(&e, from, to, amount);
+    }
+
+    fn burn(e: Env, _from: Address, _amount: i128) {
+        panic_with_error!(&e, ShareTokenError::NotSupported);
+    }
+
+    fn burn_from(e: Env, _spender: Address, _from: Address, _amount: i128) {
+        panic_with_error!(&e, ShareTokenError::NotSupported);
+    }
+
+    fn decimals(_e: Env) -> u32 {
+        7
+    }
+
+    fn name(e: Env) -> String {
+        String::from_str(&e, "Backstop Pool Shares")
+    }
+
+    fn symbol(e: Env) -> String {
+        String::from_str(&e, "BSHARE")
+    }
+
+    fn backstop(e: Env) -> Address {
+        storage::get_backstop(&e)
+    }
+
+    fn pool(e: Env) -> Address {
+        storage::get_pool(&e)
+    }
+}
+
+/// Transfer shares within the backstop. The backstop authenticates the share token as the caller.
+fn transfer_shares(e: &Env, from: &Address, to: &Address, amount: i128) {
+    BackstopClient::new(e, &storage::get_backstop(e)).transfer_shares(
+        &storage::get_pool(e),
+        from,
+        to,
+        &amount,
+    );
+}
+
+/// Require that an incoming amount is not negative
+///
+/// ### Arguments
+/// * `amount` - The amount
+///
+/// ### Errors
+/// If the number is negative
+pub fn require_nonnegative(e: &Env, amount: i128) {
+    if amount.is_negative() {
+        panic_with_error!(e, ShareTokenError::NegativeAmountError);
+    }
+}
@@ -0,0 +1,40 @@
+/**
+ * Partial client for the backstop contract
+ */
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// A deposit that is queued for withdrawal
+#[derive(Clone)]
+#[contracttype]
+pub struct Q4W {
+    pub amount: i128, // the amount of shares queued for withdrawal
+    pub exp: u64,     // the expiration of the withdrawal
+}
+
+/// A user's backstop deposit for a pool
+#[derive(Clone)]
+#[contracttype]
+pub struct UserBalance {
+    pub shares: i128,  // the balance of shares the user owns, excludes Q4W
+    pub q4w: Vec<Q4W>, // a list of queued withdrawals
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "BackstopClient")]
+pub trait Backstop {
+    /// Fetch the balance of backstop shares of a pool for the user
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The user to fetch the balance for
+    fn user_balance(e: Env, pool: Address, user: Address) -> UserBalance;
+
+    /// (Only Share Token) Transfer non-queued shares of a pool's backstop from `from` to `to`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user sending shares
+    /// * `to` - The address of the user receiving shares
+    /// * `amount` - The amount of shares to transfer
+    fn transfer_shares(e: Env, pool_address: Address, from: Address, to: Address, amount: i128);
+}
@@ -0,0 +1,16 @@
+#![no_std]
+
+#[cfg(any(test, feature = "testutils"))]
+extern crate std;
+
+mod contract;
+mod dependencies;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod test;
+
+pub use contract::*;
+pub use errors::ShareTokenError;
@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, unwrap::UnwrapOptimized, Address, Env, Symbol};
+
+/********** Ledger Thresholds **********/
+
+const ONE_DAY_LEDGERS: u32 = 17280; // assumes 5s a ledger
+
+const LEDGER_THRESHOLD_INSTANCE: u32 = ONE_DAY_LEDGERS * 30; // ~ 30 days
+const LEDGER_BUMP_INSTANCE: u32 = LEDGER_THRESHOLD_INSTANCE + ONE_DAY_LEDGERS; // ~ 31 days
+
+/********** Storage Types **********/
+
+/// An allowance for a spender to transfer shares on behalf of the owner
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,           // the amount of shares the spender can transfer
+    pub expiration_ledger: u32, // the ledger the allowance expires on
+}
+
+/********** Storage Key Types **********/
+
+const BACKSTOP_KEY: &str = "Backstop";
+const POOL_KEY: &str = "Pool";
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AllowanceKey {
+    from: Address,
+    spender: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ShareTokenDataKey {
+    // The allowance of a spender for an owner's shares
+    Allowance(AllowanceKey),
+}
+
+/********** Storage **********/
+
+/// Bump the instance rent for the contract
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(LEDGER_THRESHOLD_INSTANCE, LEDGER_BUMP_INSTANCE);
+}
+
+/********** Backstop **********/
+
+/// Fetch the backstop that holds the shares
+pub fn get_backstop(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, BACKSTOP_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the backstop that holds the shares
+///
+/// ### Arguments
+/// * `backstop` - The address of the backstop
+pub fn set_backstop(e: &Env, backstop: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, BACKSTOP_KEY), backstop);
+}
+
+/********** Pool **********/
+
+/// Fetch the pool the shares are for
+pub fn get_pool(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, POOL_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the pool the shares are for
+///
+/// ### Arguments
+/// * `pool` - The address of the pool
+pub fn set_pool(e: &Env, pool: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, POOL_KEY), pool);
+}
+
+/********** Allowance **********/
+
+/// Fetch the allowance of a spender for an owner's shares. Expired allowances are returned
+/// with an amount of zero.
+///
+/// ### Arguments
+/// * `from` - The owner of the shares
+/// * `spender` - The address allowed to transfer the shares
+pub fn get_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = ShareTokenDataKey::Allowance(AllowanceKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    match e
+        .storage()
+        .temporary()
+        .get::<ShareTokenDataKey, AllowanceValue>(&key)
+    {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance,
+        Some(allowance) => AllowanceValue {
+            amount: 0,
+            expiration_ledger: allowance.expiration_ledger,
+        },
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+/// Set the allowance of a spender for an owner's shares. The entry lives until the allowance
+/// expires.
+///
+/// ### Arguments
+/// * `from` - The owner of the shares
+/// * `spender` - The address allowed to transfer the shares
+/// * `allowance` - The allowance
+pub fn set_allowance(e: &Env, from: &Address, spender: &Address, allowance: &AllowanceValue) {
+    let key = ShareTokenDataKey::Allowance(AllowanceKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    e.storage()
+        .temporary()
+        .set::<ShareTokenDataKey, AllowanceValue>(&key, allowance);
+    if allowance.amount > 0 {
+        let live_for = allowance.expiration_ledger - e.ledger().sequence();
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
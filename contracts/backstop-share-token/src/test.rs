@@ -0,0 +1,170 @@
+use crate::{dependencies::UserBalance, BackstopShareTokenClient, BackstopShareTokenContract};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+/// A minimal backstop that tracks non-queued shares for a single pool
+#[contract]
+pub struct MockBackstop;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MockBackstopDataKey {
+    ShareToken,
+    Shares(Address),
+}
+
+#[contractimpl]
+impl MockBackstop {
+    pub fn set_share_token(e: Env, share_token: Address) {
+        e.storage()
+            .instance()
+            .set(&MockBackstopDataKey::ShareToken, &share_token);
+    }
+
+    pub fn set_shares(e: Env, user: Address, shares: i128) {
+        e.storage()
+            .instance()
+            .set(&MockBackstopDataKey::Shares(user), &shares);
+    }
+
+    pub fn user_balance(e: Env, _pool: Address, user: Address) -> UserBalance {
+        UserBalance {
+            shares: e
+                .storage()
+                .instance()
+                .get(&MockBackstopDataKey::Shares(user))
+                .unwrap_or(0),
+            q4w: vec![&e],
+        }
+    }
+
+    pub fn transfer_shares(
+        e: Env,
+        pool_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        e.storage()
+            .instance()
+            .get::<MockBackstopDataKey, Address>(&MockBackstopDataKey::ShareToken)
+            .unwrap()
+            .require_auth();
+        let from_shares = Self::user_balance(e.clone(), pool_address.clone(), from.clone()).shares;
+        let to_shares = Self::user_balance(e.clone(), pool_address, to.clone()).shares;
+        if from_shares < amount {
+            panic!("insufficient shares");
+        }
+        Self::set_shares(e.clone(), from, from_shares - amount);
+        Self::set_shares(e, to, to_shares + amount);
+    }
+}
+
+fn create_share_token<'a>(e: &Env) -> (BackstopShareTokenClient<'a>, Address, Address) {
+    let backstop = e.register(MockBackstop {}, ());
+    let pool = Address::generate(e);
+    let share_token = e.register(
+        BackstopShareTokenContract {},
+        (backstop.clone(), pool.clone()),
+    );
+    MockBackstopClient::new(e, &backstop).set_share_token(&share_token);
+    (
+        BackstopShareTokenClient::new(e, &share_token),
+        backstop,
+        pool,
+    )
+}
+
+#[test]
+fn test_metadata() {
+    let e = Env::default();
+    let (share_token_client, backstop, pool) = create_share_token(&e);
+
+    assert_eq!(share_token_client.decimals(), 7);
+    assert_eq!(
+        share_token_client.name(),
+        String::from_str(&e, "Backstop Pool Shares")
+    );
+    assert_eq!(share_token_client.symbol(), String::from_str(&e, "BSHARE"));
+    assert_eq!(share_token_client.backstop(), backstop);
+    assert_eq!(share_token_client.pool(), pool);
+}
+
+#[test]
+fn test_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let (share_token_client, backstop, _) = create_share_token(&e);
+    MockBackstopClient::new(&e, &backstop).set_shares(&samwise, &100_0000000);
+
+    assert_eq!(share_token_client.balance(&samwise), 100_0000000);
+    assert_eq!(share_token_client.balance(&frodo), 0);
+
+    share_token_client.transfer(&samwise, &frodo, &40_0000000);
+
+    assert_eq!(share_token_client.balance(&samwise), 60_0000000);
+    assert_eq!(share_token_client.balance(&frodo), 40_0000000);
+}
+
+#[test]
+fn test_transfer_from() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let (share_token_client, backstop, _) = create_share_token(&e);
+    MockBackstopClient::new(&e, &backstop).set_shares(&samwise, &100_0000000);
+
+    let expiration_ledger = e.ledger().sequence() + 100;
+    share_token_client.approve(&samwise, &merry, &50_0000000, &expiration_ledger);
+    assert_eq!(share_token_client.allowance(&samwise, &merry), 50_0000000);
+
+    share_token_client.transfer_from(&merry, &samwise, &frodo, &30_0000000);
+
+    assert_eq!(share_token_client.allowance(&samwise, &merry), 20_0000000);
+    assert_eq!(share_token_client.balance(&samwise), 70_0000000);
+    assert_eq!(share_token_client.balance(&frodo), 30_0000000);
+
+    // the allowance is zero once it expires
+    e.ledger().set_sequence_number(expiration_ledger + 1);
+    assert_eq!(share_token_client.allowance(&samwise, &merry), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_transfer_from_over_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let samwise = Address::generate(&e);
+    let frodo = Address::generate(&e);
+    let merry = Address::generate(&e);
+    let (share_token_client, backstop, _) = create_share_token(&e);
+    MockBackstopClient::new(&e, &backstop).set_shares(&samwise, &100_0000000);
+
+    let expiration_ledger = e.ledger().sequence() + 100;
+    share_token_client.approve(&samwise, &merry, &50_0000000, &expiration_ledger);
+
+    share_token_client.transfer_from(&merry, &samwise, &frodo, &50_0000001);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1400)")]
+fn test_burn_not_supported() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let samwise = Address::generate(&e);
+    let (share_token_client, backstop, _) = create_share_token(&e);
+    MockBackstopClient::new(&e, &backstop).set_shares(&samwise, &100_0000000);
+
+    share_token_client.burn(&samwise, &1_0000000);
+}
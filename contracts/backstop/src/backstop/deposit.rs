@@ -2,7 +2,10 @@
 use sep_41_token::TokenClient;
 use soroban_sdk::{panic_with_error, Address, Env};
 
-use super::require_is_from_pool_factory;
+use super::{
+    fund_management::{join_backstop_token, require_backstop_token_underlying},
+    require_is_from_pool_factory,
+};
 
 /// Perform a deposit into the backstop module
 pub fn execute_deposit(e: &Env, from: &Address, pool_address: &Address, amount: i128) -> i128 {
@@ -32,14 +35,58 @@ pub fn execute_deposit(e: &Env, from: &Address, pool_address: &Address, amount:
     to_mint
 }
 
+/// Perform a single-sided deposit of BLND or USDC into the backstop module. The tokens are
+/// deposited into the backstop token's LP pool, and the LP tokens received are deposited.
+///
+/// Returns the amount of backstop tokens deposited and the amount of shares minted
+pub fn execute_deposit_single_sided(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    token: &Address,
+    amount: i128,
+    min_lp_tokens_out: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, amount);
+    if from == pool_address || from == &e.current_contract_address() {
+        panic_with_error!(e, &BackstopError::BadRequest)
+    }
+    require_backstop_token_underlying(e, token);
+    let mut pool_balance = storage::get_pool_balance(e, pool_address);
+    require_is_from_pool_factory(e, pool_address, pool_balance.shares);
+    let mut user_balance = storage::get_user_balance(e, pool_address, from);
+
+    emissions::update_emissions(e, pool_address, &pool_balance, from, &user_balance);
+
+    TokenClient::new(e, token).transfer(from, &e.current_contract_address(), &amount);
+    let lp_tokens_out = join_backstop_token(e, token, amount, min_lp_tokens_out);
+
+    let to_mint = pool_balance.convert_to_shares(lp_tokens_out);
+    if to_mint <= 0 {
+        panic_with_error!(e, &BackstopError::InvalidShareMintAmount);
+    }
+    pool_balance.deposit(lp_tokens_out, to_mint);
+    user_balance.add_shares(to_mint);
+
+    storage::set_pool_balance(e, pool_address, &pool_balance);
+    storage::set_user_balance(e, pool_address, from, &user_balance);
+
+    (lp_tokens_out, to_mint)
+}
+
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{testutils::Address as _, Address};
+    use soroban_fixed_point_math::FixedPoint;
+    use soroban_sdk::{testutils::Address as _, unwrap::UnwrapOptimized, Address};
 
     use crate::{
-        backstop::{execute_donate, execute_draw},
+        backstop::{execute_donate, execute_draw, PoolBalance},
         constants::SCALAR_7,
-        testutils::{create_backstop, create_backstop_token, create_mock_pool_factory},
+        testutils::{
+            create_backstop, create_backstop_token, create_blnd_token,
+            create_comet_lp_pool_with_tokens_per_share, create_mock_pool_factory,
+            create_usdc_token,
+        },
     };
 
     use super::*;
@@ -295,4 +342,99 @@ fn test_execute_deposit_drained_backstop() {
             execute_deposit(&e, &samwise, &pool_0_id, SCALAR_7);
         });
     }
+
+    #[test]
+    fn test_execute_deposit_single_sided() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_id, lp_client) = create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            10_0000000,
+            &usdc_id,
+            0_2500000,
+        );
+        usdc_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        let (lp_tokens_out, to_mint) = e.as_contract(&backstop_address, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_0_id,
+                &PoolBalance {
+                    shares: 20_0000000,
+                    tokens: 25_0000000,
+                    q4w: 0,
+                },
+            );
+
+            let (lp_tokens_out, to_mint) = execute_deposit_single_sided(
+                &e, &samwise, &pool_0_id, &usdc_id, 5_0000000, 1_0000000,
+            );
+            assert!(lp_tokens_out > 1_0000000);
+            assert_eq!(
+                to_mint,
+                lp_tokens_out
+                    .fixed_mul_floor(20_0000000, 25_0000000)
+                    .unwrap_optimized()
+            );
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(new_pool_balance.shares, 20_0000000 + to_mint);
+            assert_eq!(new_pool_balance.tokens, 25_0000000 + lp_tokens_out);
+            let new_user_balance = storage::get_user_balance(&e, &pool_0_id, &samwise);
+            assert_eq!(new_user_balance.shares, to_mint);
+            (lp_tokens_out, to_mint)
+        });
+        assert!(to_mint > 0);
+        assert_eq!(usdc_client.balance(&samwise), 95_0000000);
+        assert_eq!(usdc_client.balance(&lp_id), 30_0000000);
+        assert_eq!(usdc_client.balance(&backstop_address), 0);
+        assert_eq!(lp_client.balance(&backstop_address), lp_tokens_out);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_deposit_single_sided_requires_underlying() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (lp_id, _) = create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            10_0000000,
+            &usdc_id,
+            0_2500000,
+        );
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit_single_sided(&e, &samwise, &pool_0_id, &lp_id, 5_0000000, 0);
+        });
+    }
 }
@@ -1,17 +1,43 @@
-use crate::{contract::require_nonnegative, storage, BackstopError};
+use crate::{
+    contract::require_nonnegative,
+    dependencies::CometClient,
+    storage::{self, DrawData, DrawLimit},
+    BackstopError,
+};
 use sep_41_token::TokenClient;
-use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    panic_with_error, vec, Address, Env, IntoVal, Symbol, Val, Vec,
+};
 
 use super::require_is_from_pool_factory;
 
 /// Perform a draw from a pool's backstop
 ///
 /// `pool_address` MUST be authenticated before calling
+///
+/// ### Panics
+/// If the pool does not have enough backstop tokens, or the draw exceeds the pool's remaining
+/// draw limit and emergency draw approval
 pub fn execute_draw(e: &Env, pool_address: &Address, amount: i128, to: &Address) {
     require_nonnegative(e, amount);
 
     let mut pool_balance = storage::get_pool_balance(e, pool_address);
 
+    if let Some(draw_limit) = storage::get_draw_limit(e, pool_address) {
+        let mut draw_data = load_draw_data(e, pool_address, &draw_limit);
+        let remaining = (draw_limit.max_draw - draw_data.drawn).max(0);
+        if amount > remaining {
+            let excess = amount - remaining;
+            if excess > draw_data.emergency {
+                panic_with_error!(e, &BackstopError::DrawLimitExceeded);
+            }
+            draw_data.emergency -= excess;
+        }
+        draw_data.drawn += amount;
+        storage::set_draw_data(e, pool_address, &draw_data);
+    }
+
     pool_balance.withdraw(e, amount, 0);
     storage::set_pool_balance(e, pool_address, &pool_balance);
 
@@ -19,6 +45,67 @@ pub fn execute_draw(e: &Env, pool_address: &Address, amount: i128, to: &Address)
     backstop_token.transfer(&e.current_contract_address(), to, &amount);
 }
 
+/// Set or remove the limit on the amount a pool can draw from its backstop per draw window
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `draw_limit` - The draw limit, or None to remove the limit
+///
+/// ### Panics
+/// If the max draw is negative or the window is zero
+pub fn execute_set_draw_limit(e: &Env, pool_address: &Address, draw_limit: &Option<DrawLimit>) {
+    match draw_limit {
+        Some(draw_limit) => {
+            if draw_limit.max_draw < 0 || draw_limit.window == 0 {
+                panic_with_error!(e, &BackstopError::BadRequest);
+            }
+            storage::set_draw_limit(e, pool_address, draw_limit);
+        }
+        None => storage::del_draw_limit(e, pool_address),
+    }
+}
+
+/// Approve an amount a pool can draw from its backstop over its draw limit. Overwrites any
+/// existing approval, and is consumed as the pool draws over its limit.
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `amount` - The amount of backstop tokens that can be drawn over the limit
+pub fn execute_approve_emergency_draw(e: &Env, pool_address: &Address, amount: i128) {
+    require_nonnegative(e, amount);
+
+    let mut draw_data = storage::get_draw_data(e, pool_address);
+    draw_data.emergency = amount;
+    storage::set_draw_data(e, pool_address, &draw_data);
+}
+
+/// Load the amount of backstop tokens a pool can currently draw from its backstop
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `tokens` - The backstop tokens held by the pool's backstop
+pub fn load_draw_capacity(e: &Env, pool_address: &Address, tokens: i128) -> i128 {
+    match storage::get_draw_limit(e, pool_address) {
+        Some(draw_limit) => {
+            let draw_data = load_draw_data(e, pool_address, &draw_limit);
+            let remaining = (draw_limit.max_draw - draw_data.drawn).max(0) + draw_data.emergency;
+            remaining.min(tokens)
+        }
+        None => tokens,
+    }
+}
+
+/// Load the draw data for a pool, starting a new draw window if the current one has ended
+fn load_draw_data(e: &Env, pool_address: &Address, draw_limit: &DrawLimit) -> DrawData {
+    let mut draw_data = storage::get_draw_data(e, pool_address);
+    let timestamp = e.ledger().timestamp();
+    if timestamp >= draw_data.window_start + draw_limit.window {
+        draw_data.window_start = timestamp;
+        draw_data.drawn = 0;
+    }
+    draw_data
+}
+
 /// Perform a donation to a pool's backstop
 pub fn execute_donate(e: &Env, from: &Address, pool_address: &Address, amount: i128) {
     require_nonnegative(e, amount);
@@ -41,13 +128,105 @@ pub fn execute_donate(e: &Env, from: &Address, pool_address: &Address, amount: i
     storage::set_pool_balance(e, pool_address, &pool_balance);
 }
 
+/// Perform a donation of USDC to a pool's backstop. The USDC is single-sided deposited into the
+/// backstop token's LP pool, and the LP tokens received are added to the pool's backstop.
+///
+/// Returns the amount of backstop tokens donated
+pub fn execute_donate_usdc(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    amount: i128,
+    min_lp_tokens_out: i128,
+) -> i128 {
+    require_nonnegative(e, amount);
+    if from == pool_address || from == &e.current_contract_address() {
+        panic_with_error!(e, &BackstopError::BadRequest)
+    }
+
+    let mut pool_balance = storage::get_pool_balance(e, pool_address);
+    require_is_from_pool_factory(e, pool_address, pool_balance.shares);
+
+    let usdc_id = storage::get_usdc_token(e);
+    TokenClient::new(e, &usdc_id).transfer_from(
+        &e.current_contract_address(),
+        from,
+        &e.current_contract_address(),
+        &amount,
+    );
+    let lp_tokens_out = join_backstop_token(e, &usdc_id, amount, min_lp_tokens_out);
+
+    pool_balance.deposit(lp_tokens_out, 0);
+    storage::set_pool_balance(e, pool_address, &pool_balance);
+    lp_tokens_out
+}
+
+/// Require that a token is one of the underlying tokens of the backstop token's LP pool
+///
+/// ### Panics
+/// If the token is not BLND or USDC
+pub(super) fn require_backstop_token_underlying(e: &Env, token: &Address) {
+    if token != &storage::get_blnd_token(e) && token != &storage::get_usdc_token(e) {
+        panic_with_error!(e, &BackstopError::BadRequest);
+    }
+}
+
+/// Single-sided deposit `amount` of `token` held by the backstop into the backstop token's LP pool
+///
+/// Returns the amount of backstop tokens received
+///
+/// ### Arguments
+/// * `token` - The underlying token to deposit
+/// * `amount` - The amount of the underlying token to deposit
+/// * `min_lp_tokens_out` - The minimum amount of backstop tokens to receive
+pub(super) fn join_backstop_token(
+    e: &Env,
+    token: &Address,
+    amount: i128,
+    min_lp_tokens_out: i128,
+) -> i128 {
+    let lp_id = storage::get_backstop_token(e);
+    let approval_ledger = (e.ledger().sequence() / 100000 + 1) * 100000;
+    let args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e),
+        lp_id.into_val(e),
+        amount.into_val(e),
+        approval_ledger.into_val(e),
+    ];
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(e, "approve"),
+                args,
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+    CometClient::new(e, &lp_id).dep_tokn_amt_in_get_lp_tokns_out(
+        token,
+        &amount,
+        &min_lp_tokens_out,
+        &e.current_contract_address(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{testutils::Address as _, Address};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address,
+    };
 
     use crate::{
-        backstop::execute_deposit,
-        testutils::{create_backstop, create_backstop_token, create_mock_pool_factory},
+        backstop::{execute_deposit, PoolBalance},
+        testutils::{
+            create_backstop, create_backstop_token, create_blnd_token,
+            create_comet_lp_pool_with_tokens_per_share, create_mock_pool_factory,
+            create_usdc_token,
+        },
     };
 
     use super::*;
@@ -200,6 +379,59 @@ fn test_execute_donate_not_pool() {
         });
     }
 
+    #[test]
+    fn test_execute_donate_usdc() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_id = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_id, &bombadil);
+        let (usdc_id, usdc_client) = create_usdc_token(&e, &backstop_id, &bombadil);
+        let (lp_id, lp_client) = create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_id,
+            &bombadil,
+            &blnd_id,
+            10_0000000,
+            &usdc_id,
+            0_2500000,
+        );
+        usdc_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_id);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        usdc_client.approve(&samwise, &backstop_id, &5_0000000, &e.ledger().sequence());
+        let lp_tokens_out = e.as_contract(&backstop_id, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_0_id,
+                &PoolBalance {
+                    shares: 25_0000000,
+                    tokens: 25_0000000,
+                    q4w: 0,
+                },
+            );
+
+            let lp_tokens_out = execute_donate_usdc(&e, &samwise, &pool_0_id, 5_0000000, 1_0000000);
+            assert!(lp_tokens_out > 1_0000000);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(new_pool_balance.shares, 25_0000000);
+            assert_eq!(new_pool_balance.tokens, 25_0000000 + lp_tokens_out);
+            lp_tokens_out
+        });
+        assert_eq!(usdc_client.balance(&samwise), 95_0000000);
+        assert_eq!(usdc_client.balance(&lp_id), 30_0000000);
+        assert_eq!(lp_client.get_total_supply(), 100_0000000 + lp_tokens_out);
+        assert_eq!(lp_client.balance(&backstop_id), lp_tokens_out);
+    }
+
     #[test]
     fn test_execute_draw() {
         let e = Env::default();
@@ -266,6 +498,174 @@ fn test_execute_draw_only_can_take_from_pool() {
         });
     }
 
+    #[test]
+    fn test_execute_draw_with_draw_limit() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&frodo, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        // initialize pool 0 with funds
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &frodo, &pool_0_id, 50_0000000);
+            execute_set_draw_limit(
+                &e,
+                &pool_0_id,
+                &Some(DrawLimit {
+                    max_draw: 10_0000000,
+                    window: 24 * 60 * 60,
+                }),
+            );
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 50_0000000), 10_0000000);
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_draw(&e, &pool_0_id, 6_0000000, &samwise);
+            execute_draw(&e, &pool_0_id, 4_0000000, &samwise);
+
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 40_0000000), 0);
+            let draw_data = storage::get_draw_data(&e, &pool_0_id);
+            assert_eq!(draw_data.window_start, 1713139200);
+            assert_eq!(draw_data.drawn, 10_0000000);
+        });
+
+        // a new window starts once the current one ends
+        e.ledger().set_timestamp(1713139200 + 24 * 60 * 60);
+        e.as_contract(&backstop_address, || {
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 40_0000000), 10_0000000);
+            execute_draw(&e, &pool_0_id, 10_0000000, &samwise);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(new_pool_balance.tokens, 30_0000000);
+            assert_eq!(backstop_token_client.balance(&samwise), 20_0000000);
+            let draw_data = storage::get_draw_data(&e, &pool_0_id);
+            assert_eq!(draw_data.window_start, 1713139200 + 24 * 60 * 60);
+            assert_eq!(draw_data.drawn, 10_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1013)")]
+    fn test_execute_draw_over_draw_limit() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&frodo, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        // initialize pool 0 with funds
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &frodo, &pool_0_id, 50_0000000);
+            execute_set_draw_limit(
+                &e,
+                &pool_0_id,
+                &Some(DrawLimit {
+                    max_draw: 10_0000000,
+                    window: 24 * 60 * 60,
+                }),
+            );
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_draw(&e, &pool_0_id, 6_0000000, &samwise);
+            execute_draw(&e, &pool_0_id, 4_0000001, &samwise);
+        });
+    }
+
+    #[test]
+    fn test_execute_draw_with_emergency_approval() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&frodo, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_0_id);
+
+        // initialize pool 0 with funds
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &frodo, &pool_0_id, 50_0000000);
+            execute_set_draw_limit(
+                &e,
+                &pool_0_id,
+                &Some(DrawLimit {
+                    max_draw: 10_0000000,
+                    window: 24 * 60 * 60,
+                }),
+            );
+            execute_approve_emergency_draw(&e, &pool_0_id, 25_0000000);
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 50_0000000), 35_0000000);
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_draw(&e, &pool_0_id, 30_0000000, &samwise);
+
+            assert_eq!(backstop_token_client.balance(&samwise), 30_0000000);
+            let draw_data = storage::get_draw_data(&e, &pool_0_id);
+            assert_eq!(draw_data.drawn, 30_0000000);
+            assert_eq!(draw_data.emergency, 5_0000000);
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 20_0000000), 5_0000000);
+
+            // removing the limit allows the pool to draw its full balance
+            execute_set_draw_limit(&e, &pool_0_id, &None);
+            assert_eq!(load_draw_capacity(&e, &pool_0_id, 20_0000000), 20_0000000);
+            execute_draw(&e, &pool_0_id, 20_0000000, &samwise);
+            assert_eq!(backstop_token_client.balance(&samwise), 50_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_set_draw_limit_zero_window() {
+        let e = Env::default();
+
+        let backstop_address = create_backstop(&e);
+        let pool_0_id = Address::generate(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_set_draw_limit(
+                &e,
+                &pool_0_id,
+                &Some(DrawLimit {
+                    max_draw: 10_0000000,
+                    window: 0,
+                }),
+            );
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #8)")]
     fn test_execute_draw_negative_amount() {
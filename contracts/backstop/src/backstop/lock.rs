@@ -0,0 +1,541 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, Address, Env};
+
+use crate::{
+    constants::{LOCK_TIERS, SCALAR_7},
+    contract::require_nonnegative,
+    emissions,
+    errors::BackstopError,
+    storage::{self, UserLock},
+};
+
+use super::UserBalance;
+
+/// The raw and boosted emission weight of backstop shares
+#[derive(Clone)]
+#[contracttype]
+pub struct BoostedShares {
+    pub shares: i128,         // the number of non-queued shares
+    pub boosted_shares: i128, // the emission weight of the non-queued shares, including lock boosts
+}
+
+/// Perform a lock of a user's non-queued shares in a pool's backstop. Locked shares cannot be
+/// queued for withdrawal until the lock expires, and earn emissions on a boosted weight based on
+/// the lock duration. Locking more shares into an existing lock keeps the later unlock time.
+///
+/// Returns the user's lock and the emission weight added by the lock
+///
+/// ### Panics
+/// If the duration is not a lock tier, or the user does not have enough unlocked shares
+pub fn execute_lock_shares(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    amount: i128,
+    duration: u64,
+) -> (UserLock, i128) {
+    require_nonnegative(e, amount);
+    let multiplier = match LOCK_TIERS.iter().find(|(tier, _)| *tier == duration) {
+        Some((_, multiplier)) => *multiplier,
+        None => panic_with_error!(e, BackstopError::BadRequest),
+    };
+
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    let user_balance = storage::get_user_balance(e, pool_address, from);
+
+    // update emissions
+    emissions::update_emissions(e, pool_address, &pool_balance, from, &user_balance);
+
+    let locked = load_locked_shares(e, pool_address, from);
+    if amount == 0 || user_balance.shares - locked < amount {
+        panic_with_error!(e, BackstopError::BalanceError);
+    }
+
+    let boost = amount
+        .fixed_mul_floor(multiplier - SCALAR_7, SCALAR_7)
+        .unwrap_optimized();
+    let timestamp = e.ledger().timestamp();
+    let unlock_time = timestamp + duration;
+    let lock = match storage::get_user_lock(e, pool_address, from) {
+        Some(lock) => UserLock {
+            shares: lock.shares + amount,
+            unlock_time: lock.unlock_time.max(unlock_time),
+            boost: lock.boost + boost,
+            last_time: timestamp,
+        },
+        None => UserLock {
+            shares: amount,
+            unlock_time,
+            boost,
+            last_time: timestamp,
+        },
+    };
+    storage::set_user_lock(e, pool_address, from, &lock);
+    emissions::add_boost(e, pool_address, boost);
+
+    (lock, boost)
+}
+
+/// Perform an unlock of a user's expired lock in a pool's backstop, removing the emission boost
+/// of the locked shares. Anyone can unlock an expired lock.
+///
+/// Returns the amount of shares unlocked and the emission weight removed
+///
+/// ### Panics
+/// If the user does not have a lock, or the lock has not expired
+pub fn execute_unlock_shares(e: &Env, pool_address: &Address, user: &Address) -> (i128, i128) {
+    let lock = match storage::get_user_lock(e, pool_address, user) {
+        Some(lock) => lock,
+        None => panic_with_error!(e, BackstopError::BadRequest),
+    };
+    if lock.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, BackstopError::NotExpired);
+    }
+
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    let user_balance = storage::get_user_balance(e, pool_address, user);
+
+    // update emissions. This removes the boost if emissions are configured for the pool
+    emissions::update_emissions(e, pool_address, &pool_balance, user, &user_balance);
+
+    remove_lock(e, pool_address, user);
+    (lock.shares, lock.boost)
+}
+
+/// Require that `amount` of a user's non-queued shares are not locked. An expired lock is
+/// removed along with its emission boost.
+///
+/// Emissions for the pool and user MUST be updated before calling
+///
+/// ### Panics
+/// If the user has locked shares and less than `amount` unlocked shares
+pub fn require_unlocked_shares(
+    e: &Env,
+    pool_address: &Address,
+    user: &Address,
+    user_balance: &UserBalance,
+    amount: i128,
+) {
+    let locked = load_locked_shares(e, pool_address, user);
+    if locked > 0 && user_balance.shares - locked < amount {
+        panic_with_error!(e, BackstopError::SharesLocked);
+    }
+}
+
+/// Load a user's raw and boosted non-queued shares in a pool's backstop
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `user` - The address of the user
+pub fn load_user_boosted_shares(e: &Env, pool_address: &Address, user: &Address) -> BoostedShares {
+    let user_balance = storage::get_user_balance(e, pool_address, user);
+    // an expired lock no longer boosts emissions, even if it has not been removed yet
+    let boost = match storage::get_user_lock(e, pool_address, user) {
+        Some(lock) if lock.unlock_time > e.ledger().timestamp() => lock.boost,
+        _ => 0,
+    };
+    BoostedShares {
+        shares: user_balance.shares,
+        boosted_shares: user_balance.shares + boost,
+    }
+}
+
+/// Load a pool's raw and boosted non-queued shares
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+pub fn load_pool_boosted_shares(e: &Env, pool_address: &Address) -> BoostedShares {
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    let shares = pool_balance.shares - pool_balance.q4w;
+    BoostedShares {
+        shares,
+        boosted_shares: shares + storage::get_pool_boost(e, pool_address),
+    }
+}
+
+/// Load the amount of a user's locked shares, removing the lock if it has expired
+fn load_locked_shares(e: &Env, pool_address: &Address, user: &Address) -> i128 {
+    match storage::get_user_lock(e, pool_address, user) {
+        Some(lock) => {
+            if lock.unlock_time > e.ledger().timestamp() {
+                lock.shares
+            } else {
+                remove_lock(e, pool_address, user);
+                0
+            }
+        }
+        None => 0,
+    }
+}
+
+/// Remove a user's lock and any emission boost it still holds
+fn remove_lock(e: &Env, pool_address: &Address, user: &Address) {
+    if let Some(lock) = storage::get_user_lock(e, pool_address, user) {
+        emissions::remove_boost(e, pool_address, lock.boost);
+    }
+    storage::del_user_lock(e, pool_address, user);
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec,
+    };
+
+    use crate::{
+        backstop::{execute_queue_withdrawal, PoolBalance},
+        storage::BackstopEmissionData,
+        testutils::create_backstop,
+    };
+
+    use super::*;
+
+    const ONE_MONTH: u64 = 30 * 24 * 60 * 60;
+
+    #[test]
+    fn test_execute_lock_shares() {
+        let e = Env::default();
+        let block_timestamp = 1713139200;
+        e.ledger().set(LedgerInfo {
+            timestamp: block_timestamp,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_1,
+                &BackstopEmissionData {
+                    expiration: block_timestamp + 7 * 24 * 60 * 60,
+                    eps: 0_10000000000000,
+                    index: 0,
+                    last_time: block_timestamp,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            let (lock, boost) =
+                execute_lock_shares(&e, &samwise, &pool_1, 50_0000000, 6 * ONE_MONTH);
+            assert_eq!(lock.shares, 50_0000000);
+            assert_eq!(lock.unlock_time, block_timestamp + 6 * ONE_MONTH);
+            assert_eq!(boost, 50_0000000);
+
+            let user_shares = load_user_boosted_shares(&e, &pool_1, &samwise);
+            assert_eq!(user_shares.shares, 50_0000000);
+            assert_eq!(user_shares.boosted_shares, 100_0000000);
+            let pool_shares = load_pool_boosted_shares(&e, &pool_1);
+            assert_eq!(pool_shares.shares, 150_0000000);
+            assert_eq!(pool_shares.boosted_shares, 200_0000000);
+        });
+
+        // samwise earns half of the emissions with a third of the shares
+        e.ledger().set_timestamp(block_timestamp + 1000);
+        e.as_contract(&backstop_id, || {
+            let pool_balance = storage::get_pool_balance(&e, &pool_1);
+            let user_balance = storage::get_user_balance(&e, &pool_1, &samwise);
+            emissions::update_emissions(&e, &pool_1, &pool_balance, &samwise, &user_balance);
+
+            let emis_data = storage::get_backstop_emis_data(&e, &pool_1).unwrap_optimized();
+            assert_eq!(emis_data.index, 50000000000000);
+            assert_eq!(storage::get_pool_boost(&e, &pool_1), 50_0000000);
+            let user_emis_data =
+                storage::get_user_emis_data(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(user_emis_data.index, 50000000000000);
+            assert_eq!(user_emis_data.accrued, 50_0000000);
+            let lock = storage::get_user_lock(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(lock.boost, 50_0000000);
+            assert_eq!(lock.last_time, block_timestamp + 1000);
+        });
+    }
+
+    #[test]
+    fn test_boost_stops_at_unlock_time() {
+        let e = Env::default();
+        let block_timestamp = 1713139200;
+        e.ledger().set(LedgerInfo {
+            timestamp: block_timestamp,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_1,
+                &BackstopEmissionData {
+                    expiration: block_timestamp + 3 * ONE_MONTH,
+                    eps: 0_10000000000000,
+                    index: 0,
+                    last_time: block_timestamp,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            let (_, boost) = execute_lock_shares(&e, &samwise, &pool_1, 50_0000000, ONE_MONTH);
+            assert_eq!(boost, 12_5000000);
+        });
+
+        // samwise's emissions are updated a month after the lock expired, without unlocking
+        e.ledger().set_timestamp(block_timestamp + 2 * ONE_MONTH);
+        e.as_contract(&backstop_id, || {
+            let pool_balance = storage::get_pool_balance(&e, &pool_1);
+            let user_balance = storage::get_user_balance(&e, &pool_1, &samwise);
+            emissions::update_emissions(&e, &pool_1, &pool_balance, &samwise, &user_balance);
+
+            // the boost only accrues for the first of the two months
+            let emis_data = storage::get_backstop_emis_data(&e, &pool_1).unwrap_optimized();
+            assert_eq!(emis_data.index, 319015384615384615);
+            let user_emis_data =
+                storage::get_user_emis_data(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(user_emis_data.accrued, 1595076923076 + 199384615384);
+
+            // the boost is removed from the user and the pool
+            let lock = storage::get_user_lock(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(lock.shares, 50_0000000);
+            assert_eq!(lock.boost, 0);
+            assert_eq!(storage::get_pool_boost(&e, &pool_1), 0);
+            let user_shares = load_user_boosted_shares(&e, &pool_1, &samwise);
+            assert_eq!(user_shares.boosted_shares, 50_0000000);
+            let pool_shares = load_pool_boosted_shares(&e, &pool_1);
+            assert_eq!(pool_shares.boosted_shares, 150_0000000);
+        });
+
+        // samwise earns a third of the emissions with a third of the shares
+        e.ledger().set_timestamp(block_timestamp + 3 * ONE_MONTH);
+        e.as_contract(&backstop_id, || {
+            let pool_balance = storage::get_pool_balance(&e, &pool_1);
+            let user_balance = storage::get_user_balance(&e, &pool_1, &samwise);
+            emissions::update_emissions(&e, &pool_1, &pool_balance, &samwise, &user_balance);
+
+            let user_emis_data =
+                storage::get_user_emis_data(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(
+                user_emis_data.accrued,
+                1595076923076 + 199384615384 + 864000000000
+            );
+
+            // unlocking the expired lock removes no further boost
+            let (shares, boost) = execute_unlock_shares(&e, &pool_1, &samwise);
+            assert_eq!(shares, 50_0000000);
+            assert_eq!(boost, 0);
+            assert_eq!(storage::get_pool_boost(&e, &pool_1), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_lock_shares_invalid_duration() {
+        let e = Env::default();
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_lock_shares(&e, &samwise, &pool_1, 50_0000000, 2 * ONE_MONTH);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1014)")]
+    fn test_execute_queue_withdrawal_locked_shares() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_lock_shares(&e, &samwise, &pool_1, 30_0000000, ONE_MONTH);
+            execute_queue_withdrawal(&e, &samwise, &pool_1, 20_0000000);
+
+            e.ledger().set_timestamp(1713139200 + ONE_MONTH - 1);
+            execute_queue_withdrawal(&e, &samwise, &pool_1, 1);
+        });
+    }
+
+    #[test]
+    fn test_execute_unlock_shares() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &frodo,
+                &UserBalance {
+                    shares: 100_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_lock_shares(&e, &samwise, &pool_1, 40_0000000, 3 * ONE_MONTH);
+            execute_lock_shares(&e, &frodo, &pool_1, 100_0000000, ONE_MONTH);
+            assert_eq!(
+                load_pool_boosted_shares(&e, &pool_1).boosted_shares,
+                150_0000000 + 20_0000000 + 25_0000000
+            );
+        });
+
+        // an expired lock can be unlocked by anyone
+        e.ledger().set_timestamp(1713139200 + 3 * ONE_MONTH);
+        e.as_contract(&backstop_id, || {
+            let (shares, boost) = execute_unlock_shares(&e, &pool_1, &samwise);
+            assert_eq!(shares, 40_0000000);
+            assert_eq!(boost, 20_0000000);
+            assert!(storage::get_user_lock(&e, &pool_1, &samwise).is_none());
+            assert_eq!(
+                load_user_boosted_shares(&e, &pool_1, &samwise).boosted_shares,
+                50_0000000
+            );
+
+            // an expired lock is removed when the shares are queued
+            execute_queue_withdrawal(&e, &frodo, &pool_1, 100_0000000);
+            assert!(storage::get_user_lock(&e, &pool_1, &frodo).is_none());
+            assert_eq!(
+                load_user_boosted_shares(&e, &pool_1, &frodo).boosted_shares,
+                0
+            );
+
+            let pool_shares = load_pool_boosted_shares(&e, &pool_1);
+            assert_eq!(pool_shares.shares, 50_0000000);
+            assert_eq!(pool_shares.boosted_shares, 50_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1001)")]
+    fn test_execute_unlock_shares_not_expired() {
+        let e = Env::default();
+        e.ledger().set_timestamp(1713139200);
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 50_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_lock_shares(&e, &samwise, &pool_1, 50_0000000, ONE_MONTH);
+
+            e.ledger().set_timestamp(1713139200 + ONE_MONTH - 1);
+            execute_unlock_shares(&e, &pool_1, &samwise);
+        });
+    }
+}
@@ -1,11 +1,23 @@
 mod deposit;
-pub use deposit::execute_deposit;
+pub use deposit::{execute_deposit, execute_deposit_single_sided};
 
 mod fund_management;
-pub use fund_management::{execute_donate, execute_draw};
+pub use fund_management::{
+    execute_approve_emergency_draw, execute_donate, execute_donate_usdc, execute_draw,
+    execute_set_draw_limit, load_draw_capacity,
+};
 
 mod withdrawal;
-pub use withdrawal::{execute_dequeue_withdrawal, execute_queue_withdrawal, execute_withdraw};
+pub use withdrawal::{
+    execute_dequeue_withdrawal, execute_queue_withdrawal, execute_withdraw, execute_withdraw_early,
+    execute_withdraw_single_sided,
+};
+
+mod lock;
+pub use lock::{
+    execute_lock_shares, execute_unlock_shares, load_pool_boosted_shares, load_user_boosted_shares,
+    BoostedShares,
+};
 
 mod pool;
 pub use pool::{
@@ -13,5 +25,19 @@
     PoolBackstopData, PoolBalance,
 };
 
+mod share_token;
+pub use share_token::{execute_deploy_share_token, execute_transfer_shares};
+
+mod transfer;
+pub use transfer::execute_transfer_between_pools;
+
+mod ttl;
+pub use ttl::{execute_bump_users, load_user_ledger_keys};
+
+mod upgrade;
+pub use upgrade::{
+    execute_cancel_upgrade, execute_migrate_storage, execute_queue_upgrade, execute_upgrade,
+};
+
 mod user;
 pub use user::{UserBalance, Q4W};
@@ -3,11 +3,13 @@
 
 use crate::{
     constants::SCALAR_7,
-    dependencies::{CometClient, PoolFactoryClient},
+    dependencies::{CometClient, PoolClient, PoolFactoryClient},
     errors::BackstopError,
     storage,
 };
 
+use super::load_draw_capacity;
+
 /// The pool's backstop data
 #[derive(Clone)]
 #[contracttype]
@@ -18,6 +20,7 @@ pub struct PoolBackstopData {
     pub blnd: i128,    // the amount of blnd held in the pool's backstop via backstop tokens
     pub usdc: i128,    // the amount of usdc held in the pool's backstop via backstop tokens
     pub token_spot_price: i128, // the spot price sans fees in USDC of the backstop token (7 decimals)
+    pub draw_capacity: i128,    // the amount of backstop tokens the pool can currently draw
 }
 
 pub fn load_pool_backstop_data(e: &Env, address: &Address) -> PoolBackstopData {
@@ -51,6 +54,8 @@ pub fn load_pool_backstop_data(e: &Env, address: &Address) -> PoolBackstopData {
     // LP token is 20% USDC, so 5x is the spot price without slippage/fees
     let tkn_spot_price_sans_fee = usdc_per_tkn * 5;
 
+    let draw_capacity = load_draw_capacity(e, address, pool_balance.tokens);
+
     if pool_balance.tokens > 0 {
         let blnd = pool_balance
             .tokens
@@ -67,6 +72,7 @@ pub fn load_pool_backstop_data(e: &Env, address: &Address) -> PoolBackstopData {
             blnd,
             usdc,
             token_spot_price: tkn_spot_price_sans_fee,
+            draw_capacity,
         }
     } else {
         PoolBackstopData {
@@ -76,6 +82,7 @@ pub fn load_pool_backstop_data(e: &Env, address: &Address) -> PoolBackstopData {
             blnd: 0,
             usdc: 0,
             token_spot_price: tkn_spot_price_sans_fee,
+            draw_capacity,
         }
     }
 }
@@ -99,6 +106,25 @@ pub fn require_is_from_pool_factory(e: &Env, address: &Address, balance: i128) {
     }
 }
 
+/// Require that the pool has no open user liquidation or bad debt auctions. Either can leave
+/// bad debt for the pool's backstop to cover, so deposits cannot skip the withdrawal queue
+/// while one is open.
+///
+/// ### Arguments
+/// * `address` - The pool address
+///
+/// ### Panics
+/// If the pool has an open user liquidation or bad debt auction
+pub(super) fn require_no_open_auctions(e: &Env, address: &Address) {
+    let pool_client = PoolClient::new(e, address);
+    // 0 - user liquidation auction, 1 - bad debt auction
+    for auction_type in [0, 1] {
+        if pool_client.get_auction_count(&auction_type) > 0 {
+            panic_with_error!(e, BackstopError::AuctionInProgress);
+        }
+    }
+}
+
 /// Calculate the threshold for the pool's backstop balance
 ///
 /// Returns true if the pool's backstop balance is above the threshold
@@ -194,6 +220,19 @@ pub fn withdraw(&mut self, e: &Env, tokens: i128, shares: i128) {
         self.q4w -= shares;
     }
 
+    /// Withdraw tokens and shares that are not queued for withdrawal from the pool
+    ///
+    /// ### Arguments
+    /// * `tokens` - The amount of tokens to withdraw
+    /// * `shares` - The amount of shares to withdraw
+    pub fn withdraw_non_queued(&mut self, e: &Env, tokens: i128, shares: i128) {
+        if tokens > self.tokens || shares > self.shares - self.q4w {
+            panic_with_error!(e, BackstopError::InsufficientFunds);
+        }
+        self.tokens -= tokens;
+        self.shares -= shares;
+    }
+
     /// Queue withdraw for the pool
     ///
     /// ### Arguments
@@ -264,6 +303,7 @@ fn test_load_pool_data() {
             assert_eq!(pool_data.blnd, 1_250_0000000);
             assert_eq!(pool_data.usdc, 12_5000000);
             assert_eq!(pool_data.token_spot_price, 0_2500000);
+            assert_eq!(pool_data.draw_capacity, 250_0000000);
         });
     }
 
@@ -416,6 +456,7 @@ fn test_require_pool_above_threshold_under() {
             shares: 15_000_0000000,
             usdc: 6_249_0000000,
             token_spot_price: 0_1000000,
+            draw_capacity: 0,
         }; // ~99% threshold
 
         let result = is_pool_above_threshold(&pool_backstop_data);
@@ -434,6 +475,7 @@ fn test_require_pool_above_threshold_zero() {
             shares: 500_0000000,
             usdc: 1_000_0000000,
             token_spot_price: 0_1000000,
+            draw_capacity: 0,
         }; // ~3.6% threshold - rounds to zero in calc
 
         let result = is_pool_above_threshold(&pool_backstop_data);
@@ -452,6 +494,7 @@ fn test_require_pool_above_threshold_over() {
             shares: 14_000_0000000,
             usdc: 6_250_0000000,
             token_spot_price: 0_1000000,
+            draw_capacity: 0,
         }; // 100% threshold
 
         let result = is_pool_above_threshold(&pool_backstop_data);
@@ -470,6 +513,7 @@ fn test_require_pool_above_threshold_saturates() {
             shares: 1_099_999_0000000,
             usdc: 10_000_000_0000000,
             token_spot_price: 0_1000000,
+            draw_capacity: 0,
         }; // 362x threshold
 
         let result = is_pool_above_threshold(&pool_backstop_data);
@@ -661,6 +705,35 @@ fn test_withdraw_too_much() {
         pool_balance.withdraw(&e, 201, 25);
     }
 
+    #[test]
+    fn test_withdraw_non_queued() {
+        let e = Env::default();
+        let mut pool_balance = PoolBalance {
+            shares: 100,
+            tokens: 200,
+            q4w: 25,
+        };
+
+        pool_balance.withdraw_non_queued(&e, 150, 75);
+
+        assert_eq!(pool_balance.shares, 25);
+        assert_eq!(pool_balance.tokens, 50);
+        assert_eq!(pool_balance.q4w, 25);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1003)")]
+    fn test_withdraw_non_queued_includes_q4w() {
+        let e = Env::default();
+        let mut pool_balance = PoolBalance {
+            shares: 100,
+            tokens: 200,
+            q4w: 25,
+        };
+
+        pool_balance.withdraw_non_queued(&e, 152, 76);
+    }
+
     #[test]
     fn test_dequeue_q4w() {
         let e = Env::default();
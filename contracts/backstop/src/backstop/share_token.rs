@@ -0,0 +1,233 @@
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Address, Env};
+
+use crate::{contract::require_nonnegative, emissions, errors::BackstopError, storage};
+
+use super::{lock::require_unlocked_shares, require_is_from_pool_factory};
+
+/// Deploy the SEP-41 share token for a pool's backstop deposits. The share token reads
+/// balances from the backstop, so it represents the non-queued shares of each depositor.
+///
+/// Returns the address of the share token
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+///
+/// ### Panics
+/// If the share token wasm is not set, the pool already has a share token, or the pool was not
+/// deployed by the pool factory
+pub fn execute_deploy_share_token(e: &Env, pool_address: &Address) -> Address {
+    let wasm_hash = match storage::get_share_token_wasm(e) {
+        Some(wasm_hash) => wasm_hash,
+        None => panic_with_error!(e, BackstopError::BadRequest),
+    };
+    if storage::get_share_token(e, pool_address).is_some() {
+        panic_with_error!(e, BackstopError::BadRequest);
+    }
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    require_is_from_pool_factory(e, pool_address, pool_balance.shares);
+
+    let salt = e.crypto().sha256(&pool_address.clone().to_xdr(e));
+    let share_token = e.deployer().with_current_contract(salt).deploy_v2(
+        wasm_hash,
+        (e.current_contract_address(), pool_address.clone()),
+    );
+    storage::set_share_token(e, pool_address, &share_token);
+    share_token
+}
+
+/// Transfer non-queued shares of a pool's backstop from `from` to `to`. Emissions are accrued
+/// for both users before their balances change.
+///
+/// The pool's share token MUST be authenticated before calling
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `from` - The address of the user sending shares
+/// * `to` - The address of the user receiving shares
+/// * `amount` - The amount of shares to transfer
+///
+/// ### Panics
+/// If `from` and `to` are the same, or `from` does not have enough non-queued shares
+pub fn execute_transfer_shares(
+    e: &Env,
+    pool_address: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    require_nonnegative(e, amount);
+    if from == to {
+        panic_with_error!(e, BackstopError::BadRequest);
+    }
+
+    let pool_balance = storage::get_pool_balance(e, pool_address);
+    let mut from_balance = storage::get_user_balance(e, pool_address, from);
+    let mut to_balance = storage::get_user_balance(e, pool_address, to);
+
+    // update emissions
+    emissions::update_emissions(e, pool_address, &pool_balance, from, &from_balance);
+    emissions::update_emissions(e, pool_address, &pool_balance, to, &to_balance);
+
+    require_unlocked_shares(e, pool_address, from, &from_balance, amount);
+    if from_balance.shares < amount {
+        panic_with_error!(e, BackstopError::BalanceError);
+    }
+    from_balance.shares -= amount;
+    to_balance.add_shares(amount);
+
+    storage::set_user_balance(e, pool_address, from, &from_balance);
+    storage::set_user_balance(e, pool_address, to, &to_balance);
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        unwrap::UnwrapOptimized,
+        vec,
+    };
+
+    use crate::{
+        backstop::{PoolBalance, UserBalance, Q4W},
+        storage::{BackstopEmissionData, UserEmissionData},
+        testutils::create_backstop,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_execute_transfer_shares() {
+        let e = Env::default();
+        let block_timestamp = 1713139200 + 1234;
+        e.ledger().set(LedgerInfo {
+            timestamp: block_timestamp,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_last_distribution_time(&e, &1713139200);
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_1,
+                &BackstopEmissionData {
+                    expiration: 1713139200 + 7 * 24 * 60 * 60,
+                    eps: 0_10000000000000,
+                    index: 222220000000,
+                    last_time: 1713139200,
+                },
+            );
+            storage::set_user_emis_data(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserEmissionData {
+                    index: 111110000000,
+                    accrued: 3,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 9_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_transfer_shares(&e, &pool_1, &samwise, &frodo, 4_0000000);
+
+            let samwise_balance = storage::get_user_balance(&e, &pool_1, &samwise);
+            assert_eq!(samwise_balance.shares, 5_0000000);
+            let frodo_balance = storage::get_user_balance(&e, &pool_1, &frodo);
+            assert_eq!(frodo_balance.shares, 4_0000000);
+
+            // samwise accrues emissions on the shares held before the transfer, and frodo
+            // starts accruing from the current index
+            let samwise_emis =
+                storage::get_user_emis_data(&e, &pool_1, &samwise).unwrap_optimized();
+            assert_eq!(samwise_emis.index, 82488886666666);
+            assert_eq!(samwise_emis.accrued, 7_4140001);
+            let frodo_emis = storage::get_user_emis_data(&e, &pool_1, &frodo).unwrap_optimized();
+            assert_eq!(frodo_emis.index, 82488886666666);
+            assert_eq!(frodo_emis.accrued, 0);
+
+            // the pool's shares are unchanged
+            let pool_balance = storage::get_pool_balance(&e, &pool_1);
+            assert_eq!(pool_balance.shares, 150_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_execute_transfer_shares_excludes_queued_shares() {
+        let e = Env::default();
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_1,
+                &PoolBalance {
+                    shares: 150_0000000,
+                    tokens: 200_0000000,
+                    q4w: 1_0000000,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_1,
+                &samwise,
+                &UserBalance {
+                    shares: 9_0000000,
+                    q4w: vec![
+                        &e,
+                        Q4W {
+                            amount: 1_0000000,
+                            exp: 12345,
+                        },
+                    ],
+                },
+            );
+
+            execute_transfer_shares(&e, &pool_1, &samwise, &frodo, 9_0000001);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_deploy_share_token_requires_wasm() {
+        let e = Env::default();
+
+        let backstop_id = create_backstop(&e);
+        let pool_1 = Address::generate(&e);
+
+        e.as_contract(&backstop_id, || {
+            execute_deploy_share_token(&e, &pool_1);
+        });
+    }
+}
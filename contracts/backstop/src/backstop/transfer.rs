@@ -0,0 +1,403 @@
+use crate::{
+    constants::MAX_TRANSFER_Q4W_PCT, contract::require_nonnegative, dependencies::PoolClient,
+    emissions, storage, BackstopError,
+};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::{
+    is_pool_above_threshold, load_pool_backstop_data, lock::require_unlocked_shares,
+    pool::require_no_open_auctions, require_is_from_pool_factory,
+};
+
+/// Perform a transfer of non-queued shares from one pool's backstop to another pool's backstop.
+/// The tokens backing the shares are moved instantly without entering the withdrawal queue.
+///
+/// Returns the amount of tokens moved and the amount of shares minted in the destination pool
+///
+/// ### Panics
+/// If the source pool has bad debt or an open user liquidation or bad debt auction, the
+/// destination pool was not deployed by the pool factory, or if after the transfer the source pool is below the backstop threshold or has
+/// `MAX_TRANSFER_Q4W_PCT` or more of its shares queued for withdrawal
+pub fn execute_transfer_between_pools(
+    e: &Env,
+    from: &Address,
+    from_pool_address: &Address,
+    to_pool_address: &Address,
+    amount: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, amount);
+    if from_pool_address == to_pool_address {
+        panic_with_error!(e, &BackstopError::BadRequest);
+    }
+
+    let pool_client = PoolClient::new(e, from_pool_address);
+    let backstop_positions = pool_client.get_positions(&e.current_contract_address());
+    if !backstop_positions.liabilities.is_empty() {
+        panic_with_error!(e, &BackstopError::BadDebtExists);
+    }
+    require_no_open_auctions(e, from_pool_address);
+
+    let mut from_pool_balance = storage::get_pool_balance(e, from_pool_address);
+    let mut to_pool_balance = storage::get_pool_balance(e, to_pool_address);
+    require_is_from_pool_factory(e, to_pool_address, to_pool_balance.shares);
+    let mut from_user_balance = storage::get_user_balance(e, from_pool_address, from);
+    let mut to_user_balance = storage::get_user_balance(e, to_pool_address, from);
+
+    // update emissions for both pools before the user's balances change
+    emissions::update_emissions(
+        e,
+        from_pool_address,
+        &from_pool_balance,
+        from,
+        &from_user_balance,
+    );
+    emissions::update_emissions(e, to_pool_address, &to_pool_balance, from, &to_user_balance);
+
+    require_unlocked_shares(e, from_pool_address, from, &from_user_balance, amount);
+    if from_user_balance.shares < amount {
+        panic_with_error!(e, &BackstopError::BalanceError);
+    }
+    from_user_balance.shares -= amount;
+    let tokens = from_pool_balance.convert_to_tokens(amount);
+    if tokens <= 0 {
+        panic_with_error!(e, &BackstopError::InvalidTokenWithdrawAmount);
+    }
+    from_pool_balance.withdraw_non_queued(e, tokens, amount);
+
+    let to_mint = to_pool_balance.convert_to_shares(tokens);
+    if to_mint <= 0 {
+        panic_with_error!(e, &BackstopError::InvalidShareMintAmount);
+    }
+    to_pool_balance.deposit(tokens, to_mint);
+    to_user_balance.add_shares(to_mint);
+
+    storage::set_pool_balance(e, from_pool_address, &from_pool_balance);
+    storage::set_pool_balance(e, to_pool_address, &to_pool_balance);
+    storage::set_user_balance(e, from_pool_address, from, &from_user_balance);
+    storage::set_user_balance(e, to_pool_address, from, &to_user_balance);
+
+    // the source pool must remain healthy after the transfer
+    let from_pool_data = load_pool_backstop_data(e, from_pool_address);
+    if !is_pool_above_threshold(&from_pool_data) || from_pool_data.q4w_pct >= MAX_TRANSFER_Q4W_PCT {
+        panic_with_error!(e, &BackstopError::InvalidPoolTransfer);
+    }
+
+    (tokens, to_mint)
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        unwrap::UnwrapOptimized,
+        vec,
+    };
+
+    use crate::{
+        backstop::{PoolBalance, UserBalance},
+        storage::{BackstopEmissionData, UserEmissionData},
+        testutils::{
+            create_backstop, create_blnd_token, create_comet_lp_pool_with_tokens_per_share,
+            create_mock_pool, create_usdc_token,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_execute_transfer_between_pools() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        let block_timestamp = 1713139200 + 1000;
+        e.ledger().set(LedgerInfo {
+            timestamp: block_timestamp,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop_address = create_backstop(&e);
+        let (pool_0_id, _) = create_mock_pool(&e);
+        let pool_1_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            5_0000000,
+            &usdc_id,
+            0_0500000,
+        );
+
+        e.as_contract(&backstop_address, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_0_id,
+                &PoolBalance {
+                    shares: 100_000_0000000,
+                    tokens: 100_000_0000000,
+                    q4w: 10_000_0000000,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1_id,
+                &PoolBalance {
+                    shares: 10_000_0000000,
+                    tokens: 20_000_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_0_id,
+                &samwise,
+                &UserBalance {
+                    shares: 20_000_0000000,
+                    q4w: vec![&e],
+                },
+            );
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_0_id,
+                &BackstopEmissionData {
+                    expiration: 1713139200 + 7 * 24 * 60 * 60,
+                    eps: 0_10000000000000,
+                    index: 0,
+                    last_time: 1713139200,
+                },
+            );
+            storage::set_user_emis_data(
+                &e,
+                &pool_0_id,
+                &samwise,
+                &UserEmissionData {
+                    index: 0,
+                    accrued: 0,
+                },
+            );
+            storage::set_backstop_emis_data(
+                &e,
+                &pool_1_id,
+                &BackstopEmissionData {
+                    expiration: 1713139200 + 7 * 24 * 60 * 60,
+                    eps: 0,
+                    index: 1234567,
+                    last_time: 1713139200,
+                },
+            );
+
+            let (tokens, to_mint) = execute_transfer_between_pools(
+                &e,
+                &samwise,
+                &pool_0_id,
+                &pool_1_id,
+                20_000_0000000,
+            );
+            assert_eq!(tokens, 20_000_0000000);
+            assert_eq!(to_mint, 10_000_0000000);
+
+            let pool_0_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(pool_0_balance.shares, 80_000_0000000);
+            assert_eq!(pool_0_balance.tokens, 80_000_0000000);
+            assert_eq!(pool_0_balance.q4w, 10_000_0000000);
+            let pool_1_balance = storage::get_pool_balance(&e, &pool_1_id);
+            assert_eq!(pool_1_balance.shares, 20_000_0000000);
+            assert_eq!(pool_1_balance.tokens, 40_000_0000000);
+            assert_eq!(pool_1_balance.q4w, 0);
+
+            let user_0_balance = storage::get_user_balance(&e, &pool_0_id, &samwise);
+            assert_eq!(user_0_balance.shares, 0);
+            let user_1_balance = storage::get_user_balance(&e, &pool_1_id, &samwise);
+            assert_eq!(user_1_balance.shares, 10_000_0000000);
+
+            // samwise accrues emissions on the shares held in the source pool, and starts
+            // accruing from the current index in the destination pool
+            let user_0_emis =
+                storage::get_user_emis_data(&e, &pool_0_id, &samwise).unwrap_optimized();
+            assert_eq!(user_0_emis.index, 111111111111);
+            assert_eq!(user_0_emis.accrued, 22_2222222);
+            let user_1_emis =
+                storage::get_user_emis_data(&e, &pool_1_id, &samwise).unwrap_optimized();
+            assert_eq!(user_1_emis.index, 1234567);
+            assert_eq!(user_1_emis.accrued, 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_transfer_between_pools_below_threshold() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_0_id, _) = create_mock_pool(&e);
+        let pool_1_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            5_0000000,
+            &usdc_id,
+            0_0500000,
+        );
+
+        e.as_contract(&backstop_address, || {
+            // 51k tokens is above the threshold, 50k tokens is below
+            storage::set_pool_balance(
+                &e,
+                &pool_0_id,
+                &PoolBalance {
+                    shares: 60_000_0000000,
+                    tokens: 60_000_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1_id,
+                &PoolBalance {
+                    shares: 10_000_0000000,
+                    tokens: 10_000_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_0_id,
+                &samwise,
+                &UserBalance {
+                    shares: 10_000_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            // transferring 9k shares leaves the source pool above the threshold
+            execute_transfer_between_pools(&e, &samwise, &pool_0_id, &pool_1_id, 9_000_0000000);
+            let pool_0_balance = storage::get_pool_balance(&e, &pool_0_id);
+            assert_eq!(pool_0_balance.tokens, 51_000_0000000);
+
+            // transferring the last 1k shares drops the source pool below the threshold
+            execute_transfer_between_pools(&e, &samwise, &pool_0_id, &pool_1_id, 1_000_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1012)")]
+    fn test_execute_transfer_between_pools_max_q4w() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_0_id, _) = create_mock_pool(&e);
+        let pool_1_id = Address::generate(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, _) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, _) = create_usdc_token(&e, &backstop_address, &bombadil);
+        create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            5_0000000,
+            &usdc_id,
+            0_0500000,
+        );
+
+        e.as_contract(&backstop_address, || {
+            storage::set_pool_balance(
+                &e,
+                &pool_0_id,
+                &PoolBalance {
+                    shares: 100_000_0000000,
+                    tokens: 100_000_0000000,
+                    q4w: 20_000_0000000,
+                },
+            );
+            storage::set_pool_balance(
+                &e,
+                &pool_1_id,
+                &PoolBalance {
+                    shares: 10_000_0000000,
+                    tokens: 10_000_0000000,
+                    q4w: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_0_id,
+                &samwise,
+                &UserBalance {
+                    shares: 20_000_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            // the source pool remains above the threshold, but its q4w reaches 25%
+            execute_transfer_between_pools(&e, &samwise, &pool_0_id, &pool_1_id, 20_000_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1015)")]
+    fn test_execute_transfer_between_pools_open_auction() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_0_id, mock_pool_client) = create_mock_pool(&e);
+        let pool_1_id = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        // the pool has a bad debt auction open
+        mock_pool_client.set_auction_count(&1, &1);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_user_balance(
+                &e,
+                &pool_0_id,
+                &samwise,
+                &UserBalance {
+                    shares: 1_000_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            execute_transfer_between_pools(&e, &samwise, &pool_0_id, &pool_1_id, 1_000_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_transfer_between_pools_same_pool() {
+        let e = Env::default();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_0_id, _) = create_mock_pool(&e);
+        let samwise = Address::generate(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_transfer_between_pools(&e, &samwise, &pool_0_id, &pool_0_id, 1_0000000);
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{self, BackstopDataKey};
+
+/// Load the ledger keys of the balance, emission and lock entries of a user's deposit in a
+/// pool's backstop
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `user` - The owner of the deposit
+pub fn load_user_ledger_keys(
+    e: &Env,
+    pool_address: &Address,
+    user: &Address,
+) -> Vec<BackstopDataKey> {
+    storage::get_user_storage_keys(e, pool_address, user)
+}
+
+/// Extend the TTL of every entry stored for each user's deposit in a pool's backstop
+///
+/// Returns the number of entries bumped
+///
+/// ### Arguments
+/// * `pool_address` - The address of the pool
+/// * `users` - The addresses of the users
+pub fn execute_bump_users(e: &Env, pool_address: &Address, users: &Vec<Address>) -> u32 {
+    let mut bumped: u32 = 0;
+    for user in users.iter() {
+        for key in storage::get_user_storage_keys(e, pool_address, &user).iter() {
+            storage::bump_user_storage_key(e, &key);
+            bumped += 1;
+        }
+    }
+    bumped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backstop::UserBalance, storage::UserEmissionData, testutils::create_backstop};
+    use soroban_sdk::{
+        testutils::{storage::Persistent, Address as _, Ledger, LedgerInfo},
+        vec,
+    };
+
+    const ONE_DAY_LEDGERS: u32 = 17280;
+
+    #[test]
+    fn test_bump_users() {
+        let e = Env::default();
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 50 * ONE_DAY_LEDGERS,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop_address = create_backstop(&e);
+        let pool_address = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_user_balance(
+                &e,
+                &pool_address,
+                &samwise,
+                &UserBalance {
+                    shares: 100_0000000,
+                    q4w: vec![&e],
+                },
+            );
+            storage::set_user_emis_data(
+                &e,
+                &pool_address,
+                &samwise,
+                &UserEmissionData {
+                    index: 1,
+                    accrued: 0,
+                },
+            );
+            storage::set_user_balance(
+                &e,
+                &pool_address,
+                &frodo,
+                &UserBalance {
+                    shares: 1_0000000,
+                    q4w: vec![&e],
+                },
+            );
+
+            assert_eq!(load_user_ledger_keys(&e, &pool_address, &samwise).len(), 2);
+            assert_eq!(load_user_ledger_keys(&e, &pool_address, &frodo).len(), 1);
+            assert_eq!(
+                load_user_ledger_keys(&e, &Address::generate(&e), &samwise).len(),
+                0
+            );
+        });
+
+        // 30 days pass without either user interacting with the backstop
+        e.ledger().set_sequence_number(1234 + 30 * ONE_DAY_LEDGERS);
+
+        e.as_contract(&backstop_address, || {
+            let keys = load_user_ledger_keys(&e, &pool_address, &samwise);
+            for key in keys.iter() {
+                assert_eq!(e.storage().persistent().get_ttl(&key), 90 * ONE_DAY_LEDGERS);
+            }
+
+            let bumped =
+                execute_bump_users(&e, &pool_address, &vec![&e, samwise.clone(), frodo.clone()]);
+            assert_eq!(bumped, 3);
+            for key in keys.iter() {
+                assert_eq!(
+                    e.storage().persistent().get_ttl(&key),
+                    120 * ONE_DAY_LEDGERS
+                );
+            }
+        });
+    }
+}
@@ -0,0 +1,185 @@
+use soroban_sdk::{panic_with_error, BytesN, Env};
+
+use crate::{
+    constants::{STORAGE_VERSION, UPGRADE_LOCK_TIME},
+    errors::BackstopError,
+    storage::{self, QueuedUpgrade},
+};
+
+/// Queue an upgrade of the backstop's wasm
+///
+/// Returns the time the upgrade unlocks
+///
+/// ### Arguments
+/// * `wasm_hash` - The hash of the uploaded wasm to upgrade to
+///
+/// ### Errors
+/// If an upgrade is already queued
+pub fn execute_queue_upgrade(e: &Env, wasm_hash: &BytesN<32>) -> u64 {
+    if storage::get_queued_upgrade(e).is_some() {
+        panic_with_error!(e, BackstopError::BadRequest);
+    }
+
+    let unlock_time = e.ledger().timestamp() + UPGRADE_LOCK_TIME;
+    storage::set_queued_upgrade(
+        e,
+        &QueuedUpgrade {
+            wasm_hash: wasm_hash.clone(),
+            unlock_time,
+        },
+    );
+    unlock_time
+}
+
+/// Cancel the queued upgrade of the backstop's wasm
+///
+/// Returns the hash of the cancelled wasm
+///
+/// ### Errors
+/// If no upgrade is queued
+pub fn execute_cancel_upgrade(e: &Env) -> BytesN<32> {
+    let queued_upgrade = load_queued_upgrade(e);
+    storage::del_queued_upgrade(e);
+    queued_upgrade.wasm_hash
+}
+
+/// Upgrade the backstop's wasm to the queued wasm once its timelock has expired. The new wasm
+/// takes effect after the current invocation.
+///
+/// Returns the hash of the new wasm
+///
+/// ### Errors
+/// If no upgrade is queued or the upgrade is still locked
+pub fn execute_upgrade(e: &Env) -> BytesN<32> {
+    let queued_upgrade = load_queued_upgrade(e);
+    if queued_upgrade.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, BackstopError::NotExpired);
+    }
+
+    storage::del_queued_upgrade(e);
+    e.deployer()
+        .update_current_contract_wasm(queued_upgrade.wasm_hash.clone());
+    queued_upgrade.wasm_hash
+}
+
+/// Migrate the backstop's storage from the stored version to `STORAGE_VERSION`
+///
+/// Returns the storage version before the migration
+///
+/// ### Errors
+/// If the storage is already at `STORAGE_VERSION`
+pub fn execute_migrate_storage(e: &Env) -> u32 {
+    let version = storage::get_storage_version(e);
+    if version >= STORAGE_VERSION {
+        panic_with_error!(e, BackstopError::BadRequest);
+    }
+
+    // @dev: storage migrations for each version after `version` are applied here, in order
+
+    storage::set_storage_version(e, STORAGE_VERSION);
+    version
+}
+
+fn load_queued_upgrade(e: &Env) -> QueuedUpgrade {
+    match storage::get_queued_upgrade(e) {
+        Some(queued_upgrade) => queued_upgrade,
+        None => panic_with_error!(e, BackstopError::BadRequest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sep_41_token::testutils::MockTokenWASM;
+    use soroban_sdk::testutils::{BytesN as _, Ledger};
+
+    use crate::testutils::create_backstop;
+
+    use super::*;
+
+    #[test]
+    fn test_queue_and_upgrade() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+        let wasm_hash = e.deployer().upload_contract_wasm(MockTokenWASM);
+
+        e.as_contract(&backstop_address, || {
+            let unlock_time = execute_queue_upgrade(&e, &wasm_hash);
+            assert_eq!(unlock_time, e.ledger().timestamp() + UPGRADE_LOCK_TIME);
+            let queued_upgrade = storage::get_queued_upgrade(&e).unwrap();
+            assert_eq!(queued_upgrade.wasm_hash, wasm_hash);
+            assert_eq!(queued_upgrade.unlock_time, unlock_time);
+
+            e.ledger().set_timestamp(unlock_time);
+            assert_eq!(execute_upgrade(&e), wasm_hash);
+            assert!(storage::get_queued_upgrade(&e).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1001)")]
+    fn test_upgrade_locked() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+        let wasm_hash = e.deployer().upload_contract_wasm(MockTokenWASM);
+
+        e.as_contract(&backstop_address, || {
+            let unlock_time = execute_queue_upgrade(&e, &wasm_hash);
+            e.ledger().set_timestamp(unlock_time - 1);
+
+            execute_upgrade(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_queue_upgrade_already_queued() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_queue_upgrade(&e, &BytesN::random(&e));
+            execute_queue_upgrade(&e, &BytesN::random(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_cancel_upgrade() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+        let wasm_hash = BytesN::random(&e);
+
+        e.as_contract(&backstop_address, || {
+            let unlock_time = execute_queue_upgrade(&e, &wasm_hash);
+            assert_eq!(execute_cancel_upgrade(&e), wasm_hash);
+            assert!(storage::get_queued_upgrade(&e).is_none());
+
+            e.ledger().set_timestamp(unlock_time);
+            execute_upgrade(&e);
+        });
+    }
+
+    #[test]
+    fn test_migrate_storage() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        e.as_contract(&backstop_address, || {
+            storage::set_storage_version(&e, STORAGE_VERSION - 1);
+
+            assert_eq!(execute_migrate_storage(&e), STORAGE_VERSION - 1);
+            assert_eq!(storage::get_storage_version(&e), STORAGE_VERSION);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_migrate_storage_already_migrated() {
+        let e = Env::default();
+        let backstop_address = create_backstop(&e);
+
+        e.as_contract(&backstop_address, || {
+            execute_migrate_storage(&e);
+        });
+    }
+}
@@ -1,7 +1,8 @@
-use soroban_sdk::{contracttype, panic_with_error, vec, Env, Vec};
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{contracttype, panic_with_error, unwrap::UnwrapOptimized, vec, Env, Vec};
 
 use crate::{
-    constants::{MAX_Q4W_SIZE, Q4W_LOCK_TIME},
+    constants::{MAX_EARLY_WITHDRAWAL_PENALTY, MAX_Q4W_SIZE, Q4W_LOCK_TIME, SCALAR_7},
     errors::BackstopError,
 };
 
@@ -57,7 +58,7 @@ pub fn queue_shares_for_withdrawal(&mut self, e: &Env, to_q: i128) {
         if self.q4w.len() >= MAX_Q4W_SIZE {
             panic_with_error!(e, BackstopError::TooManyQ4WEntries);
         }
-        self.shares = self.shares - to_q;
+        self.shares -= to_q;
 
         // user has enough tokens to withdrawal, add Q4W
         let new_q4w = Q4W {
@@ -106,6 +107,41 @@ pub fn withdraw_shares(&mut self, e: &Env, to_withdraw: i128) {
         }
     }
 
+    /// Withdraw shares from the withdrawal queue before they expire. Withdraws the oldest queued
+    /// shares first. Each Q4W entry is charged a penalty that decays linearly from
+    /// `MAX_EARLY_WITHDRAWAL_PENALTY` when queued to zero at its expiration.
+    ///
+    /// Returns the amount of withdrawn shares forfeited as a penalty
+    ///
+    /// ### Arguments
+    /// * `to_withdraw` - The amount of shares to withdraw from the withdrawal queue
+    ///
+    /// ### Errors
+    /// If the user does not have enough queued shares to withdraw
+    pub fn withdraw_shares_early(&mut self, e: &Env, to_withdraw: i128) -> i128 {
+        let mut left_to_withdraw: i128 = to_withdraw;
+        let mut penalty: i128 = 0;
+        while left_to_withdraw > 0 {
+            let mut cur_q4w = match self.q4w.pop_front() {
+                Some(q4w) => q4w,
+                None => panic_with_error!(e, BackstopError::BalanceError),
+            };
+            let amount = cur_q4w.amount.min(left_to_withdraw);
+            let time_left = i128::from(cur_q4w.exp.saturating_sub(e.ledger().timestamp()));
+            let penalty_pct = MAX_EARLY_WITHDRAWAL_PENALTY * time_left / i128::from(Q4W_LOCK_TIME);
+            penalty += amount
+                .fixed_mul_ceil(penalty_pct, SCALAR_7)
+                .unwrap_optimized();
+            left_to_withdraw -= amount;
+            if cur_q4w.amount > amount {
+                // last record we need to update, but the q4w should remain
+                cur_q4w.amount -= amount;
+                self.q4w.push_front(cur_q4w);
+            }
+        }
+        penalty
+    }
+
     /// Dequeue shares from the withdrawal queue. Dequeues the most recently queued shares first.
     ///
     /// ### Arguments
@@ -577,6 +613,81 @@ fn test_withdraw_shares_over_total() {
         user.withdraw_shares(&e, to_dequeue);
     }
 
+    // withdraw_shares_early
+
+    #[test]
+    fn test_withdraw_shares_early_penalty_decays() {
+        let e = Env::default();
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 1,
+            timestamp: 10000000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let cur_q4w = vec![
+            &e,
+            // expired, no penalty
+            Q4W {
+                amount: 50_0000000,
+                exp: 10000000 - 1,
+            },
+            // half of the lock remaining, 10% penalty
+            Q4W {
+                amount: 100_0000000,
+                exp: 10000000 + Q4W_LOCK_TIME / 2,
+            },
+            // just queued, 20% penalty
+            Q4W {
+                amount: 100_0000000,
+                exp: 10000000 + Q4W_LOCK_TIME,
+            },
+        ];
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: cur_q4w.clone(),
+        };
+
+        let penalty = user.withdraw_shares_early(&e, 200_0000000);
+        assert_eq!(penalty, 10_0000000 + 10_0000000);
+        assert_eq!(user.shares, 1000);
+        assert_eq_vec_q4w(
+            &user.q4w,
+            &vec![
+                &e,
+                Q4W {
+                    amount: 50_0000000,
+                    exp: 10000000 + Q4W_LOCK_TIME,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_withdraw_shares_early_over_total() {
+        let e = Env::default();
+
+        let cur_q4w = vec![
+            &e,
+            Q4W {
+                amount: 125,
+                exp: 10000000,
+            },
+        ];
+        let mut user = UserBalance {
+            shares: 1000,
+            q4w: cur_q4w.clone(),
+        };
+
+        user.withdraw_shares_early(&e, 126);
+    }
+
     // dequeue_shares
 
     #[test]
@@ -1,10 +1,15 @@
 use crate::{
-    contract::require_nonnegative, dependencies::PoolClient, emissions, storage, BackstopError,
+    contract::require_nonnegative,
+    dependencies::{CometClient, PoolClient},
+    emissions, storage, BackstopError,
 };
 use sep_41_token::TokenClient;
 use soroban_sdk::{panic_with_error, unwrap::UnwrapOptimized, Address, Env};
 
-use super::Q4W;
+use super::{
+    fund_management::require_backstop_token_underlying, lock::require_unlocked_shares,
+    pool::require_no_open_auctions, Q4W,
+};
 
 /// Perform a queue for withdraw from the backstop module
 pub fn execute_queue_withdrawal(
@@ -21,6 +26,7 @@ pub fn execute_queue_withdrawal(
     // update emissions
     emissions::update_emissions(e, pool_address, &pool_balance, from, &user_balance);
 
+    require_unlocked_shares(e, pool_address, from, &user_balance, amount);
     user_balance.queue_shares_for_withdrawal(e, amount);
     pool_balance.queue_for_withdraw(amount);
 
@@ -50,11 +56,52 @@ pub fn execute_dequeue_withdrawal(e: &Env, from: &Address, pool_address: &Addres
 
 /// Perform a withdraw from the backstop module
 pub fn execute_withdraw(e: &Env, from: &Address, pool_address: &Address, amount: i128) -> i128 {
+    let to_return = withdraw_backstop_tokens(e, from, pool_address, amount);
+
+    let backstop_token_client = TokenClient::new(e, &storage::get_backstop_token(e));
+    backstop_token_client.transfer(&e.current_contract_address(), from, &to_return);
+
+    to_return
+}
+
+/// Perform a withdraw from the backstop module that exits the backstop tokens from the LP pool
+/// to a single underlying token, BLND or USDC.
+///
+/// Returns the amount of backstop tokens withdrawn and the amount of `token` returned
+pub fn execute_withdraw_single_sided(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    token: &Address,
+    amount: i128,
+    min_tokens_out: i128,
+) -> (i128, i128) {
+    require_backstop_token_underlying(e, token);
+
+    let to_exit = withdraw_backstop_tokens(e, from, pool_address, amount);
+
+    let comet_client = CometClient::new(e, &storage::get_backstop_token(e));
+    let tokens_out = comet_client.wdr_tokn_amt_in_get_lp_tokns_out(
+        token,
+        &to_exit,
+        &min_tokens_out,
+        &e.current_contract_address(),
+    );
+    TokenClient::new(e, token).transfer(&e.current_contract_address(), from, &tokens_out);
+
+    (to_exit, tokens_out)
+}
+
+/// Burn `amount` of `from`s expired queued shares and remove the backing backstop tokens from the
+/// pool's backstop. The caller is responsible for sending the backstop tokens.
+///
+/// Returns the amount of backstop tokens withdrawn
+fn withdraw_backstop_tokens(e: &Env, from: &Address, pool_address: &Address, amount: i128) -> i128 {
     require_nonnegative(e, amount);
 
     let pool_client = PoolClient::new(e, pool_address);
     let backstop_positions = pool_client.get_positions(&e.current_contract_address());
-    if backstop_positions.liabilities.len() > 0 {
+    if !backstop_positions.liabilities.is_empty() {
         panic_with_error!(e, &BackstopError::BadDebtExists);
     }
 
@@ -72,10 +119,51 @@ pub fn execute_withdraw(e: &Env, from: &Address, pool_address: &Address, amount:
     storage::set_user_balance(e, pool_address, from, &user_balance);
     storage::set_pool_balance(e, pool_address, &pool_balance);
 
+    to_return
+}
+
+/// Perform an early withdraw of queued deposits from the backstop module. The shares are burned
+/// immediately, and the tokens backing the forfeited penalty shares remain in the pool's
+/// backstop for the remaining depositors.
+///
+/// Returns the amount of tokens returned and the amount of shares forfeited as a penalty
+///
+/// ### Panics
+/// If the pool's backstop has bad debt, or the pool has an open user liquidation or bad debt
+/// auction
+pub fn execute_withdraw_early(
+    e: &Env,
+    from: &Address,
+    pool_address: &Address,
+    amount: i128,
+) -> (i128, i128) {
+    require_nonnegative(e, amount);
+
+    let pool_client = PoolClient::new(e, pool_address);
+    let backstop_positions = pool_client.get_positions(&e.current_contract_address());
+    if !backstop_positions.liabilities.is_empty() {
+        panic_with_error!(e, &BackstopError::BadDebtExists);
+    }
+    require_no_open_auctions(e, pool_address);
+
+    let mut pool_balance = storage::get_pool_balance(e, pool_address);
+    let mut user_balance = storage::get_user_balance(e, pool_address, from);
+
+    let penalty = user_balance.withdraw_shares_early(e, amount);
+
+    let to_return = pool_balance.convert_to_tokens(amount - penalty);
+    if to_return == 0 {
+        panic_with_error!(e, &BackstopError::InvalidTokenWithdrawAmount);
+    }
+    pool_balance.withdraw(e, to_return, amount);
+
+    storage::set_user_balance(e, pool_address, from, &user_balance);
+    storage::set_pool_balance(e, pool_address, &pool_balance);
+
     let backstop_token_client = TokenClient::new(e, &storage::get_backstop_token(e));
     backstop_token_client.transfer(&e.current_contract_address(), from, &to_return);
 
-    to_return
+    (to_return, penalty)
 }
 
 #[cfg(test)]
@@ -88,10 +176,11 @@ mod tests {
     };
 
     use crate::{
-        backstop::{execute_deposit, execute_donate, execute_draw},
+        backstop::{execute_deposit, execute_deposit_single_sided, execute_donate, execute_draw},
         testutils::{
-            assert_eq_vec_q4w, create_backstop, create_backstop_token, create_mock_pool,
-            create_mock_pool_factory,
+            assert_eq_vec_q4w, create_backstop, create_backstop_token, create_blnd_token,
+            create_comet_lp_pool_with_tokens_per_share, create_mock_pool, create_mock_pool_factory,
+            create_usdc_token,
         },
     };
 
@@ -401,6 +490,249 @@ fn test_execute_withdrawal() {
         });
     }
 
+    #[test]
+    fn test_execute_withdraw_single_sided() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd_id, blnd_client) = create_blnd_token(&e, &backstop_address, &bombadil);
+        let (usdc_id, usdc_client) = create_usdc_token(&e, &backstop_address, &bombadil);
+        let (_, lp_client) = create_comet_lp_pool_with_tokens_per_share(
+            &e,
+            &backstop_address,
+            &bombadil,
+            &blnd_id,
+            10_0000000,
+            &usdc_id,
+            0_2500000,
+        );
+        usdc_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let shares = e.as_contract(&backstop_address, || {
+            let (_, shares) =
+                execute_deposit_single_sided(&e, &samwise, &pool_address, &usdc_id, 5_0000000, 0);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, shares);
+            shares
+        });
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 + 1,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            let (tokens, blnd_out) = execute_withdraw_single_sided(
+                &e,
+                &samwise,
+                &pool_address,
+                &blnd_id,
+                shares,
+                1_0000000,
+            );
+            assert_eq!(tokens, shares);
+            assert!(blnd_out > 1_0000000);
+
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(new_user_balance.shares, 0);
+            assert_eq!(new_user_balance.q4w.len(), 0);
+
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.q4w, 0);
+            assert_eq!(new_pool_balance.shares, 0);
+            assert_eq!(new_pool_balance.tokens, 0);
+
+            assert_eq!(lp_client.balance(&backstop_address), 0);
+            assert_eq!(blnd_client.balance(&backstop_address), 0);
+            assert_eq!(blnd_client.balance(&samwise), blnd_out);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_execute_withdraw_single_sided_requires_underlying() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (backstop_token_id, _) = create_backstop_token(&e, &backstop_address, &bombadil);
+        create_blnd_token(&e, &backstop_address, &bombadil);
+        create_usdc_token(&e, &backstop_address, &bombadil);
+
+        e.as_contract(&backstop_address, || {
+            execute_withdraw_single_sided(
+                &e,
+                &samwise,
+                &pool_address,
+                &backstop_token_id,
+                1_0000000,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    fn test_execute_withdraw_early() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+        backstop_token_client.mint(&frodo, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_deposit(&e, &frodo, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 50_0000000);
+
+            // queued shares count towards the pool's q4w until withdrawn
+            let pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(pool_balance.q4w, 50_0000000);
+        });
+
+        // withdraw with a quarter of the lock remaining
+        e.ledger().set(LedgerInfo {
+            protocol_version: 22,
+            sequence_number: 200,
+            timestamp: 10000 + 17 * 24 * 60 * 60 * 3 / 4,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        e.as_contract(&backstop_address, || {
+            let (tokens, penalty) = execute_withdraw_early(&e, &samwise, &pool_address, 50_0000000);
+            assert_eq!(penalty, 2_5000000);
+            assert_eq!(tokens, 47_5000000);
+
+            let new_user_balance = storage::get_user_balance(&e, &pool_address, &samwise);
+            assert_eq!(new_user_balance.shares, 50_0000000);
+            assert_eq!(new_user_balance.q4w.len(), 0);
+
+            // the penalty stays with the remaining depositors
+            let new_pool_balance = storage::get_pool_balance(&e, &pool_address);
+            assert_eq!(new_pool_balance.q4w, 0);
+            assert_eq!(new_pool_balance.shares, 150_0000000);
+            assert_eq!(new_pool_balance.tokens, 152_5000000);
+            assert_eq!(new_pool_balance.convert_to_tokens(100_0000000), 101_6666666);
+
+            assert_eq!(
+                backstop_token_client.balance(&backstop_address),
+                152_5000000
+            );
+            assert_eq!(backstop_token_client.balance(&samwise), 47_5000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #10)")]
+    fn test_execute_withdraw_early_requires_queued_shares() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, _) = create_mock_pool(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 10_0000000);
+
+            execute_withdraw_early(&e, &samwise, &pool_address, 10_0000001);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1015)")]
+    fn test_execute_withdraw_early_open_auction() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let backstop_address = create_backstop(&e);
+        let (pool_address, mock_pool_client) = create_mock_pool(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (_, backstop_token_client) = create_backstop_token(&e, &backstop_address, &bombadil);
+        backstop_token_client.mint(&samwise, &100_0000000);
+
+        let (_, mock_pool_factory_client) = create_mock_pool_factory(&e, &backstop_address);
+        mock_pool_factory_client.set_pool(&pool_address);
+
+        // the pool is liquidating a user
+        mock_pool_client.set_auction_count(&0, &1);
+
+        e.as_contract(&backstop_address, || {
+            execute_deposit(&e, &samwise, &pool_address, 100_0000000);
+            execute_queue_withdrawal(&e, &samwise, &pool_address, 10_0000000);
+
+            execute_withdraw_early(&e, &samwise, &pool_address, 10_0000000);
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #8)")]
     fn test_execute_withdrawal_negative_amount() {
@@ -13,6 +13,38 @@
 /// The time in seconds that a Q4W entry is locked for (17 days).
 pub const Q4W_LOCK_TIME: u64 = 17 * 24 * 60 * 60;
 
+/// The penalty charged on queued shares withdrawn as soon as they are queued (20%). The penalty
+/// decays linearly to zero as the Q4W entry approaches its expiration.
+pub const MAX_EARLY_WITHDRAWAL_PENALTY: i128 = 2 * SCALAR_7 / 10;
+
+/// The maximum percentage of a pool's backstop shares that can be queued for withdrawal after
+/// shares are transferred out of it to another pool (25%). Kept below the default 30% that puts a
+/// pool on-ice.
+pub const MAX_TRANSFER_Q4W_PCT: i128 = SCALAR_7 / 4;
+
+/// The durations in seconds backstop shares can be locked for (1, 3 and 6 months), and the
+/// emission multiplier earned by the locked shares (7 decimals).
+pub const LOCK_TIERS: [(u64, i128); 3] = [
+    (30 * 24 * 60 * 60, 1_2500000),
+    (90 * 24 * 60 * 60, 1_5000000),
+    (180 * 24 * 60 * 60, 2_0000000),
+];
+
 /// The maximum amount of backfilled emissions that can be emitted.
 /// Represents between 3-4 months worth of token emissions.
 pub const MAX_BACKFILLED_EMISSIONS: i128 = 10_000_000 * SCALAR_7;
+
+/// The time in seconds that a queued contract upgrade is locked for (7 days).
+pub const UPGRADE_LOCK_TIME: u64 = 7 * 24 * 60 * 60;
+
+/// The version of the backstop's storage layout. Bump when an upgrade requires a storage migration.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// The default minimum time in seconds since the last distribution for a keeper to be rewarded (1 hour).
+pub const KEEPER_MIN_INTERVAL: u64 = 60 * 60;
+
+/// The default length of a keeper reward period in seconds (1 day).
+pub const KEEPER_PERIOD: u64 = 24 * 60 * 60;
+
+/// The default maximum BLND paid to keepers per keeper reward period.
+pub const KEEPER_MAX_PER_PERIOD: i128 = 100 * SCALAR_7;
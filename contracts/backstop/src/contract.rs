@@ -1,13 +1,15 @@
 use crate::{
-    backstop::{self, load_pool_backstop_data, PoolBackstopData, UserBalance, Q4W},
-    constants::{MAX_BACKFILLED_EMISSIONS, SCALAR_7},
+    backstop::{self, load_pool_backstop_data, BoostedShares, PoolBackstopData, UserBalance, Q4W},
+    constants::{MAX_BACKFILLED_EMISSIONS, SCALAR_7, STORAGE_VERSION},
     dependencies::EmitterClient,
     emissions,
     errors::BackstopError,
     events::BackstopEvents,
-    storage,
+    storage::{self, BackstopDataKey, DrawLimit, KeeperConfig, UserLock},
+};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, panic_with_error, Address, BytesN, Env, Vec,
 };
-use soroban_sdk::{contract, contractclient, contractimpl, panic_with_error, Address, Env, Vec};
 
 /// ### Backstop
 ///
@@ -57,6 +59,79 @@ pub trait Backstop {
     /// * `amount` - The amount of shares to withdraw
     fn withdraw(e: Env, from: Address, pool_address: Address, amount: i128) -> i128;
 
+    /// Withdraw shares from `from`s withdraw queue for a backstop of a pool before the queue
+    /// expires. Oldest queued shares are withdrawn first, and a penalty that decays linearly with
+    /// each entry's remaining lock time is forfeited to the pool's remaining depositors. Not
+    /// allowed while the pool has an open user liquidation or bad debt auction.
+    ///
+    /// Returns the amount of tokens returned
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of queued shares to withdraw, including the penalty
+    fn withdraw_early(e: Env, from: Address, pool_address: Address, amount: i128) -> i128;
+
+    /// Deposit BLND or USDC from `from` into the backstop of a pool. The tokens are single-sided
+    /// deposited into the backstop token's LP pool, and the backstop tokens received are deposited.
+    ///
+    /// Returns the number of backstop pool shares minted
+    ///
+    /// ### Arguments
+    /// * `from` - The address depositing into the backstop
+    /// * `pool_address` - The address of the pool
+    /// * `token` - The address of the token to deposit, either BLND or USDC
+    /// * `amount` - The amount of `token` to deposit
+    /// * `min_lp_tokens_out` - The minimum amount of backstop tokens to receive from the LP pool
+    fn deposit_single_sided(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        token: Address,
+        amount: i128,
+        min_lp_tokens_out: i128,
+    ) -> i128;
+
+    /// Withdraw shares from `from`s withdraw queue for a backstop of a pool, and exit the backstop
+    /// tokens from the LP pool to a single token, either BLND or USDC
+    ///
+    /// Returns the amount of `token` returned
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being withdrawn
+    /// * `pool_address` - The address of the pool
+    /// * `token` - The address of the token to withdraw, either BLND or USDC
+    /// * `amount` - The amount of shares to withdraw
+    /// * `min_tokens_out` - The minimum amount of `token` to receive from the LP pool
+    fn withdraw_single_sided(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        token: Address,
+        amount: i128,
+        min_tokens_out: i128,
+    ) -> i128;
+
+    /// Transfer non-queued shares from `from`s deposit in the backstop of one pool to the
+    /// backstop of another pool without entering the withdrawal queue. The source pool must
+    /// remain above the backstop threshold with less than 25% of its shares queued for withdrawal,
+    /// and have no open user liquidation or bad debt auctions.
+    ///
+    /// Returns the amount of shares minted in the destination pool
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being transferred
+    /// * `from_pool_address` - The address of the pool the shares are transferred from
+    /// * `to_pool_address` - The address of the pool the shares are transferred to
+    /// * `amount` - The amount of shares to transfer from the source pool
+    fn transfer_between_pools(
+        e: Env,
+        from: Address,
+        from_pool_address: Address,
+        to_pool_address: Address,
+        amount: i128,
+    ) -> i128;
+
     /// Fetch the balance of backstop shares of a pool for the user
     ///
     /// ### Arguments
@@ -66,7 +141,8 @@ pub trait Backstop {
 
     /// Fetch the backstop data for the pool
     ///
-    /// Return a summary of the pool's backstop data
+    /// Return a summary of the pool's backstop data, including the amount of backstop tokens the
+    /// pool can currently draw
     ///
     /// ### Arguments
     /// * `pool_address` - The address of the pool
@@ -75,9 +151,30 @@ pub trait Backstop {
     /// Fetch the backstop token for the backstop
     fn backstop_token(e: Env) -> Address;
 
+    /// Fetch the USDC token for the backstop
+    fn usdc_token(e: Env) -> Address;
+
     /// Fetch the reward zone for the backstop
     fn reward_zone(e: Env) -> Vec<Address>;
 
+    /// Fetch the ledger keys of the entries stored for the user's deposit in a pool's backstop.
+    /// The backstop cannot tell how long these entries have left before they are archived, so
+    /// keepers check each key off-chain before calling `bump_users`.
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The owner of the deposit
+    fn user_ledger_keys(e: Env, pool_address: Address, user: Address) -> Vec<BackstopDataKey>;
+
+    /// Extend the TTL of every entry stored for each user's deposit in a pool's backstop
+    ///
+    /// Returns the number of entries bumped
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `users` - The addresses of the users
+    fn bump_users(e: Env, pool_address: Address, users: Vec<Address>) -> u32;
+
     /********** Emissions **********/
 
     /// Update the backstop with new emissions for all reward zone pools
@@ -85,6 +182,27 @@ pub trait Backstop {
     /// Returns the amount of new emissions for all reward zone pools
     fn distribute(e: Env) -> i128;
 
+    /// Update the backstop with new emissions for all reward zone pools, rewarding the keeper
+    /// if the distribution was due
+    ///
+    /// Returns the amount of new emissions for all reward zone pools, after the keeper reward
+    ///
+    /// ### Arguments
+    /// * `keeper` - The address of the keeper to reward
+    fn keeper_distribute(e: Env, keeper: Address) -> i128;
+
+    /// Fetch the keeper reward configuration
+    fn keeper_config(e: Env) -> KeeperConfig;
+
+    /// (Only Admin) Update the keeper reward configuration
+    ///
+    /// ### Arguments
+    /// * `config` - The new keeper reward configuration
+    ///
+    /// ### Errors
+    /// If the reward is negative, the reward exceeds the max reward per period, or the period is zero
+    fn set_keeper_config(e: Env, config: KeeperConfig);
+
     /// Distribute emissions to a reward zone pool and its backstop
     ///
     /// Returns the amount of BLND emissions distributed to the pool
@@ -131,6 +249,61 @@ pub trait Backstop {
     /// Drop initial BLND to a list of addresses through the emitter
     fn drop(e: Env);
 
+    /********** Share Locks **********/
+
+    /// Lock non-queued shares of `from` in the backstop of a pool. Locked shares cannot be queued
+    /// for withdrawal or transferred until the lock expires, and earn emissions on a boosted
+    /// weight. Locks of 1 month earn 1.25x, 3 months 1.5x and 6 months 2x.
+    ///
+    /// Returns the user's lock
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose shares are being locked
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of shares to lock
+    /// * `duration` - The duration of the lock in seconds
+    ///
+    /// ### Errors
+    /// If the duration is not 1, 3 or 6 months, or `from` does not have enough unlocked shares
+    fn lock_shares(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> UserLock;
+
+    /// Unlock the expired lock of `user` in the backstop of a pool, removing the emission boost
+    /// of the locked shares. Anyone can unlock an expired lock.
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The address of the user whose lock expired
+    ///
+    /// ### Errors
+    /// If the user does not have a lock, or the lock has not expired
+    fn unlock_shares(e: Env, pool_address: Address, user: Address);
+
+    /// Fetch the locked shares of a user in the backstop of a pool, if any
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The address of the user
+    fn user_lock(e: Env, pool_address: Address, user: Address) -> Option<UserLock>;
+
+    /// Fetch the raw and boosted non-queued shares of a user in the backstop of a pool
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The address of the user
+    fn user_boosted_shares(e: Env, pool_address: Address, user: Address) -> BoostedShares;
+
+    /// Fetch the raw and boosted non-queued shares of the backstop of a pool
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    fn pool_boosted_shares(e: Env, pool_address: Address) -> BoostedShares;
+
     /********** Fund Management *********/
 
     /// (Only Pool) Take backstop token from a pools backstop
@@ -142,8 +315,8 @@ pub trait Backstop {
     /// * `to` - The address to send the backstop tokens to
     ///
     /// ### Errors
-    /// If the pool does not have enough backstop tokens, or if the pool does
-    /// not authorize the call
+    /// If the pool does not have enough backstop tokens, the draw exceeds the pool's draw limit
+    /// and emergency draw approval, or if the pool does not authorize the call
     fn draw(e: Env, pool_address: Address, amount: i128, to: Address);
 
     /// (Only Pool) Sends backstop tokens from `from` to a pools backstop
@@ -159,6 +332,120 @@ pub trait Backstop {
     /// If the `pool_address` is not valid, backstop does not have sufficient allowance from `from`, or if the pool does not
     /// authorize the call
     fn donate(e: Env, from: Address, pool_address: Address, amount: i128);
+
+    /// (Only Pool) Sends USDC from `from` to a pools backstop. The USDC is deposited into the
+    /// backstop token's LP pool, and the received backstop tokens are donated to the pool's backstop.
+    ///
+    /// NOTE: This is not a deposit, and `from` will permanently lose access to the funds
+    ///
+    /// Returns the amount of backstop tokens donated
+    ///
+    /// ### Arguments
+    /// * `from` - The address donating USDC to the backstop
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of USDC to add
+    /// * `min_lp_tokens_out` - The minimum amount of backstop tokens to receive for the USDC
+    ///
+    /// ### Errors
+    /// If the `pool_address` is not valid, backstop does not have sufficient allowance from `from`,
+    /// less than `min_lp_tokens_out` are received, or if the pool does not authorize the call
+    fn donate_usdc(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        min_lp_tokens_out: i128,
+    ) -> i128;
+
+    /// (Only Admin) Set or remove the maximum amount of backstop tokens a pool can draw from its
+    /// backstop per draw window
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `draw_limit` - The draw limit, or None to remove the limit
+    ///
+    /// ### Errors
+    /// If the max draw is negative or the window is zero
+    fn set_draw_limit(e: Env, pool_address: Address, draw_limit: Option<DrawLimit>);
+
+    /// (Only Admin) Approve an amount of backstop tokens a pool can draw over its draw limit in
+    /// an emergency. Overwrites any existing approval.
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of backstop tokens that can be drawn over the limit
+    fn approve_emergency_draw(e: Env, pool_address: Address, amount: i128);
+
+    /********** Share Tokens **********/
+
+    /// Fetch the SEP-41 share token for a pool's backstop deposits, if one has been deployed
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    fn share_token(e: Env, pool_address: Address) -> Option<Address>;
+
+    /// Deploy the SEP-41 share token for a pool's backstop deposits. The share token represents
+    /// each depositor's non-queued shares, and `user_balance` remains the source of truth.
+    ///
+    /// Returns the address of the share token
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    ///
+    /// ### Errors
+    /// If the share token wasm is not set, the pool already has a share token, or the pool was
+    /// not deployed by the pool factory
+    fn deploy_share_token(e: Env, pool_address: Address) -> Address;
+
+    /// (Only Share Token) Transfer non-queued shares of a pool's backstop from `from` to `to`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user sending shares
+    /// * `to` - The address of the user receiving shares
+    /// * `amount` - The amount of shares to transfer
+    ///
+    /// ### Errors
+    /// If the pool has no share token, or `from` does not have enough non-queued shares
+    fn transfer_shares(e: Env, pool_address: Address, from: Address, to: Address, amount: i128);
+
+    /// (Only Admin) Set the wasm deployed for pool share tokens
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the uploaded share token wasm
+    fn set_share_token_wasm(e: Env, wasm_hash: BytesN<32>);
+
+    /********** Upgrades *********/
+
+    /// (Only Admin) Queue an upgrade of the backstop's wasm. The upgrade unlocks after 7 days
+    /// so users can exit before it is applied.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the uploaded wasm to upgrade to
+    ///
+    /// ### Errors
+    /// If an upgrade is already queued
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>);
+
+    /// (Only Admin) Cancel the queued upgrade of the backstop's wasm
+    ///
+    /// ### Errors
+    /// If no upgrade is queued
+    fn cancel_upgrade(e: Env);
+
+    /// Upgrade the backstop's wasm to the queued wasm once its timelock has expired
+    ///
+    /// Returns the hash of the new wasm
+    ///
+    /// ### Errors
+    /// If no upgrade is queued or the upgrade is still locked
+    fn upgrade(e: Env) -> BytesN<32>;
+
+    /// Migrate the backstop's storage to the current storage version after an upgrade
+    ///
+    /// ### Errors
+    /// If the storage is already at the current storage version
+    fn migrate(e: Env);
 }
 
 #[contractimpl]
@@ -172,6 +459,7 @@ impl BackstopContract {
     /// * `usdc_token` - The USDC token ID
     /// * `pool_factory` - The pool factory ID
     /// * `drop_list` - The list of addresses to distribute initial BLND to and the percent of the distribution they should receive
+    /// * `admin` - The admin that can queue upgrades of the backstop
     pub fn __constructor(
         e: Env,
         backstop_token: Address,
@@ -180,7 +468,9 @@ pub fn __constructor(
         usdc_token: Address,
         pool_factory: Address,
         drop_list: Vec<(Address, i128)>,
+        admin: Address,
     ) {
+        storage::set_admin(&e, &admin);
         storage::set_backstop_token(&e, &backstop_token);
         storage::set_blnd_token(&e, &blnd_token);
         storage::set_usdc_token(&e, &usdc_token);
@@ -243,6 +533,111 @@ fn withdraw(e: Env, from: Address, pool_address: Address, amount: i128) -> i128
         to_withdraw
     }
 
+    fn withdraw_early(e: Env, from: Address, pool_address: Address, amount: i128) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (to_withdraw, penalty) =
+            backstop::execute_withdraw_early(&e, &from, &pool_address, amount);
+
+        BackstopEvents::withdraw_early(&e, pool_address, from, amount, to_withdraw, penalty);
+        to_withdraw
+    }
+
+    fn transfer_between_pools(
+        e: Env,
+        from: Address,
+        from_pool_address: Address,
+        to_pool_address: Address,
+        amount: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (tokens, to_mint) = backstop::execute_transfer_between_pools(
+            &e,
+            &from,
+            &from_pool_address,
+            &to_pool_address,
+            amount,
+        );
+
+        BackstopEvents::transfer_between_pools(
+            &e,
+            from_pool_address,
+            to_pool_address,
+            from,
+            amount,
+            tokens,
+            to_mint,
+        );
+        to_mint
+    }
+
+    fn deposit_single_sided(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        token: Address,
+        amount: i128,
+        min_lp_tokens_out: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (tokens_in, to_mint) = backstop::execute_deposit_single_sided(
+            &e,
+            &from,
+            &pool_address,
+            &token,
+            amount,
+            min_lp_tokens_out,
+        );
+
+        BackstopEvents::deposit_single_sided(
+            &e,
+            pool_address,
+            from,
+            token,
+            amount,
+            tokens_in,
+            to_mint,
+        );
+        to_mint
+    }
+
+    fn withdraw_single_sided(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        token: Address,
+        amount: i128,
+        min_tokens_out: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (to_withdraw, tokens_out) = backstop::execute_withdraw_single_sided(
+            &e,
+            &from,
+            &pool_address,
+            &token,
+            amount,
+            min_tokens_out,
+        );
+
+        BackstopEvents::withdraw_single_sided(
+            &e,
+            pool_address,
+            from,
+            token,
+            amount,
+            to_withdraw,
+            tokens_out,
+        );
+        tokens_out
+    }
+
     fn user_balance(e: Env, pool: Address, user: Address) -> UserBalance {
         storage::get_user_balance(&e, &pool, &user)
     }
@@ -255,20 +650,54 @@ fn backstop_token(e: Env) -> Address {
         storage::get_backstop_token(&e)
     }
 
+    fn usdc_token(e: Env) -> Address {
+        storage::get_usdc_token(&e)
+    }
+
     fn reward_zone(e: Env) -> Vec<Address> {
         storage::get_reward_zone(&e)
     }
 
+    fn user_ledger_keys(e: Env, pool_address: Address, user: Address) -> Vec<BackstopDataKey> {
+        backstop::load_user_ledger_keys(&e, &pool_address, &user)
+    }
+
+    fn bump_users(e: Env, pool_address: Address, users: Vec<Address>) -> u32 {
+        storage::extend_instance(&e);
+        backstop::execute_bump_users(&e, &pool_address, &users)
+    }
+
     /********** Emissions **********/
 
     fn distribute(e: Env) -> i128 {
         storage::extend_instance(&e);
-        let new_emissions = emissions::distribute(&e);
+        let new_emissions = emissions::distribute(&e, None);
+
+        BackstopEvents::distribute(&e, new_emissions);
+        new_emissions
+    }
+
+    fn keeper_distribute(e: Env, keeper: Address) -> i128 {
+        storage::extend_instance(&e);
+        let new_emissions = emissions::distribute(&e, Some(keeper));
 
         BackstopEvents::distribute(&e, new_emissions);
         new_emissions
     }
 
+    fn keeper_config(e: Env) -> KeeperConfig {
+        storage::get_keeper_config(&e)
+    }
+
+    fn set_keeper_config(e: Env, config: KeeperConfig) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        emissions::execute_set_keeper_config(&e, &config);
+
+        BackstopEvents::set_keeper_config(&e, config);
+    }
+
     fn gulp_emissions(e: Env, pool: Address) -> i128 {
         storage::extend_instance(&e);
         pool.require_auth();
@@ -310,6 +739,45 @@ fn drop(e: Env) {
         emitter_client.drop(&drop_list)
     }
 
+    /********** Share Locks **********/
+
+    fn lock_shares(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> UserLock {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let (lock, boost) =
+            backstop::execute_lock_shares(&e, &from, &pool_address, amount, duration);
+
+        BackstopEvents::lock_shares(&e, pool_address, from, amount, lock.unlock_time, boost);
+        lock
+    }
+
+    fn unlock_shares(e: Env, pool_address: Address, user: Address) {
+        storage::extend_instance(&e);
+
+        let (shares, boost) = backstop::execute_unlock_shares(&e, &pool_address, &user);
+
+        BackstopEvents::unlock_shares(&e, pool_address, user, shares, boost);
+    }
+
+    fn user_lock(e: Env, pool_address: Address, user: Address) -> Option<UserLock> {
+        storage::get_user_lock(&e, &pool_address, &user)
+    }
+
+    fn user_boosted_shares(e: Env, pool_address: Address, user: Address) -> BoostedShares {
+        backstop::load_user_boosted_shares(&e, &pool_address, &user)
+    }
+
+    fn pool_boosted_shares(e: Env, pool_address: Address) -> BoostedShares {
+        backstop::load_pool_boosted_shares(&e, &pool_address)
+    }
+
     /********** Fund Management *********/
 
     fn draw(e: Env, pool_address: Address, amount: i128, to: Address) {
@@ -330,6 +798,112 @@ fn donate(e: Env, from: Address, pool_address: Address, amount: i128) {
 
         BackstopEvents::donate(&e, pool_address, from, amount);
     }
+
+    fn donate_usdc(
+        e: Env,
+        from: Address,
+        pool_address: Address,
+        amount: i128,
+        min_lp_tokens_out: i128,
+    ) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+        pool_address.require_auth();
+
+        let lp_tokens_out =
+            backstop::execute_donate_usdc(&e, &from, &pool_address, amount, min_lp_tokens_out);
+
+        BackstopEvents::donate(&e, pool_address, from, lp_tokens_out);
+        lp_tokens_out
+    }
+
+    fn set_draw_limit(e: Env, pool_address: Address, draw_limit: Option<DrawLimit>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        backstop::execute_set_draw_limit(&e, &pool_address, &draw_limit);
+
+        BackstopEvents::set_draw_limit(&e, pool_address, draw_limit);
+    }
+
+    fn approve_emergency_draw(e: Env, pool_address: Address, amount: i128) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        backstop::execute_approve_emergency_draw(&e, &pool_address, amount);
+
+        BackstopEvents::approve_emergency_draw(&e, pool_address, amount);
+    }
+
+    /********** Share Tokens **********/
+
+    fn share_token(e: Env, pool_address: Address) -> Option<Address> {
+        storage::get_share_token(&e, &pool_address)
+    }
+
+    fn deploy_share_token(e: Env, pool_address: Address) -> Address {
+        storage::extend_instance(&e);
+
+        let share_token = backstop::execute_deploy_share_token(&e, &pool_address);
+
+        BackstopEvents::deploy_share_token(&e, pool_address, share_token.clone());
+        share_token
+    }
+
+    fn transfer_shares(e: Env, pool_address: Address, from: Address, to: Address, amount: i128) {
+        storage::extend_instance(&e);
+        storage::get_share_token(&e, &pool_address)
+            .unwrap_or_else(|| panic_with_error!(&e, BackstopError::BadRequest))
+            .require_auth();
+
+        backstop::execute_transfer_shares(&e, &pool_address, &from, &to, amount);
+
+        BackstopEvents::transfer_shares(&e, pool_address, from, to, amount);
+    }
+
+    fn set_share_token_wasm(e: Env, wasm_hash: BytesN<32>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        storage::set_share_token_wasm(&e, &wasm_hash);
+    }
+
+    /********** Upgrades *********/
+
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        let unlock_time = backstop::execute_queue_upgrade(&e, &wasm_hash);
+
+        BackstopEvents::queue_upgrade(&e, wasm_hash, unlock_time);
+    }
+
+    fn cancel_upgrade(e: Env) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        let wasm_hash = backstop::execute_cancel_upgrade(&e);
+
+        BackstopEvents::cancel_upgrade(&e, wasm_hash);
+    }
+
+    fn upgrade(e: Env) -> BytesN<32> {
+        storage::extend_instance(&e);
+
+        let wasm_hash = backstop::execute_upgrade(&e);
+
+        BackstopEvents::upgrade(&e, wasm_hash.clone());
+        wasm_hash
+    }
+
+    fn migrate(e: Env) {
+        storage::extend_instance(&e);
+
+        let from_version = backstop::execute_migrate_storage(&e);
+
+        BackstopEvents::migrate_storage(&e, from_version, STORAGE_VERSION);
+    }
 }
 
 /// Require that an incoming amount is not negative
@@ -19,4 +19,10 @@ pub trait Pool {
     /// ### Arguments
     /// * `address` - The address to fetch positions for
     fn get_positions(e: Env, address: Address) -> Positions;
+
+    /// Fetch the number of auctions of a type in the pool
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction
+    fn get_auction_count(e: Env, auction_type: u32) -> u32;
 }
@@ -41,10 +41,10 @@ pub fn execute_claim(
         let approval_ledger = (e.ledger().sequence() / 100000 + 1) * 100000;
         let args: Vec<Val> = vec![
             e,
-            (&e.current_contract_address()).into_val(e),
-            (&lp_id).into_val(e),
-            (&claimed).into_val(e),
-            (&approval_ledger).into_val(e),
+            e.current_contract_address().into_val(e),
+            lp_id.into_val(e),
+            claimed.into_val(e),
+            approval_ledger.into_val(e),
         ];
         e.authorize_as_current_contract(vec![
             &e,
@@ -60,7 +60,7 @@ pub fn execute_claim(
         let lp_tokens_out = CometClient::new(e, &lp_id).dep_tokn_amt_in_get_lp_tokns_out(
             &blnd_id,
             &claimed,
-            &min_lp_tokens_out,
+            min_lp_tokens_out,
             &e.current_contract_address(),
         );
         for pool_id in pool_addresses.iter() {
@@ -73,16 +73,18 @@ pub fn update_emission_data(
 
             let unqueued_shares = pool_balance.shares - pool_balance.q4w;
             require_nonnegative(e, unqueued_shares);
-            let additional_idx: i128;
-            if unqueued_shares == 0 {
+            // locked shares are weighted by their boost
+            let boosted_shares = unqueued_shares + storage::get_pool_boost(e, pool_id);
+
+            let additional_idx: i128 = if boosted_shares == 0 {
                 // all shares q4w, omit emissions
-                additional_idx = 0;
+                0
             } else {
                 // Eps is in 14 decimals and needs to be converted to 7 decimals to match emission token decimals
-                additional_idx = (i128(max_timestamp - emis_data.last_time) * i128(emis_data.eps))
-                    .fixed_div_floor(unqueued_shares, SCALAR_7)
-                    .unwrap_optimized();
-            }
+                (i128(max_timestamp - emis_data.last_time) * i128(emis_data.eps))
+                    .fixed_div_floor(boosted_shares, SCALAR_7)
+                    .unwrap_optimized()
+            };
             let new_data = BackstopEmissionData {
                 eps: emis_data.eps,
                 expiration: emis_data.expiration,
@@ -93,7 +95,7 @@ pub fn update_emission_data(
             storage::set_backstop_emis_data(e, pool_id, &new_data);
             Some(new_data)
         }
-        None => return None, // no emission exist, no update is required
+        None => None, // no emission exist, no update is required
     }
 }
 
@@ -119,22 +121,23 @@ fn update_user_emissions(
                 let to_accrue = (user_balance.shares)
                     .fixed_mul_floor(delta_index, SCALAR_14)
                     .unwrap_optimized();
-                accrual += to_accrue;
+                accrual += to_accrue + accrue_boost(e, pool, user, delta_index);
             }
             return set_user_emissions(e, pool, user, emis_data.index, accrual, to_claim);
         }
         // no accrual occured and no claim requested
-        return 0;
+        0
     } else if user_balance.shares == 0 {
         // first time the user registered an action with the asset since emissions were added
-        return set_user_emissions(e, pool, user, emis_data.index, 0, to_claim);
+        set_user_emissions(e, pool, user, emis_data.index, 0, to_claim)
     } else {
         // user had tokens before emissions began, they are due any historical emissions
         let to_accrue = user_balance
             .shares
             .fixed_mul_floor(emis_data.index, SCALAR_14)
-            .unwrap_optimized();
-        return set_user_emissions(e, pool, user, emis_data.index, to_accrue, to_claim);
+            .unwrap_optimized()
+            + accrue_boost(e, pool, user, emis_data.index);
+        set_user_emissions(e, pool, user, emis_data.index, to_accrue, to_claim)
     }
 }
 
@@ -155,6 +158,64 @@ fn set_user_emissions(
     }
 }
 
+/// Accrue the emissions earned by the boost of a user's locked shares over `delta_index`.
+///
+/// Boosted accrual stops at the lock's unlock time. The index at the unlock time is not
+/// tracked, so the first accrual at or after the unlock time is split pro-rata by time
+/// between the boosted and unboosted periods, and the boost is removed from the user and
+/// the pool. The unboosted part of that accrual is not distributed.
+///
+/// Returns the emissions accrued by the boost
+fn accrue_boost(e: &Env, pool_id: &Address, user_id: &Address, delta_index: i128) -> i128 {
+    let mut lock = match storage::get_user_lock(e, pool_id, user_id) {
+        Some(lock) if lock.boost > 0 => lock,
+        _ => return 0,
+    };
+    let timestamp = e.ledger().timestamp();
+    let mut to_accrue = lock
+        .boost
+        .fixed_mul_floor(delta_index, SCALAR_14)
+        .unwrap_optimized();
+    if lock.unlock_time <= timestamp {
+        if timestamp > lock.last_time {
+            let boosted_time = i128(lock.unlock_time.saturating_sub(lock.last_time));
+            to_accrue = to_accrue
+                .fixed_mul_floor(boosted_time, i128(timestamp - lock.last_time))
+                .unwrap_optimized();
+        }
+        remove_boost(e, pool_id, lock.boost);
+        lock.boost = 0;
+    }
+    lock.last_time = timestamp;
+    storage::set_user_lock(e, pool_id, user_id, &lock);
+    to_accrue
+}
+
+/// Add emission weight to a pool's backstop shares for newly locked shares
+///
+/// Emissions for the pool MUST be updated before calling
+///
+/// ### Arguments
+/// * `pool_id` - The pool
+/// * `boost` - The emission weight to add
+pub fn add_boost(e: &Env, pool_id: &Address, boost: i128) {
+    require_nonnegative(e, boost);
+    storage::set_pool_boost(e, pool_id, storage::get_pool_boost(e, pool_id) + boost);
+}
+
+/// Remove emission weight from a pool's backstop shares for unlocked shares
+///
+/// Emissions for the pool MUST be updated before calling
+///
+/// ### Arguments
+/// * `pool_id` - The pool
+/// * `boost` - The emission weight to remove
+pub fn remove_boost(e: &Env, pool_id: &Address, boost: i128) {
+    if boost > 0 {
+        storage::set_pool_boost(e, pool_id, storage::get_pool_boost(e, pool_id) - boost);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{testutils::create_backstop, Q4W};
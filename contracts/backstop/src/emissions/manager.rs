@@ -8,7 +8,8 @@
     constants::{MAX_BACKFILLED_EMISSIONS, MAX_RZ_SIZE, SCALAR_7},
     dependencies::EmitterClient,
     errors::BackstopError,
-    storage::{self, BackstopEmissionData, RzEmissions},
+    events::BackstopEvents,
+    storage::{self, BackstopEmissionData, KeeperConfig, RzEmissions},
     PoolBalance,
 };
 
@@ -31,7 +32,7 @@ pub fn add_to_reward_zone(e: &Env, to_add: Address, to_remove: Option<Address>)
     }
 
     // if updating the rz list, ensure distribute was run recently
-    if reward_zone.len() > 0 {
+    if !reward_zone.is_empty() {
         require_distribute_run_recently(e);
     }
 
@@ -93,13 +94,16 @@ fn require_distribute_run_recently(e: &Env) {
 
 /// Distribute emissions from the emitter to the reward zone and backstop depositors. This also implements
 /// backfilling emissions if the emitter has not distributed to this version of the backstop before.
-pub fn distribute(e: &Env) -> i128 {
+///
+/// If a `keeper` is provided and at least the keeper config's `min_interval` has passed since the last
+/// distribution, the keeper reward is paid out of the new emissions before they are distributed to the reward zone.
+pub fn distribute(e: &Env, keeper: Option<Address>) -> i128 {
     let is_backfill: bool;
     let mut needs_reset: bool = false;
     let last_backfill_status = storage::get_backfill_status(e);
     let emitter = storage::get_emitter(e);
     let emitter_last_distribution =
-        match EmitterClient::new(&e, &emitter).try_get_last_distro(&e.current_contract_address()) {
+        match EmitterClient::new(e, &emitter).try_get_last_distro(&e.current_contract_address()) {
             Ok(distro) => {
                 is_backfill = false;
                 if last_backfill_status != Some(false) {
@@ -175,6 +179,13 @@ pub fn distribute(e: &Env) -> i128 {
     }
     storage::set_last_distribution_time(e, &emitter_last_distribution);
 
+    if let Some(keeper) = keeper {
+        let keeper_config = storage::get_keeper_config(e);
+        if emitter_last_distribution - last_distribution >= keeper_config.min_interval {
+            new_emissions -= pay_keeper_reward(e, &keeper_config, &keeper, new_emissions);
+        }
+    }
+
     let mut rz_balance: Vec<(Address, PoolBalance)> = vec![e];
 
     // fetch total non-queued backstop tokens in the reward zone
@@ -200,7 +211,55 @@ pub fn distribute(e: &Env) -> i128 {
         storage::set_rz_emis(e, &rz_pool, &accrued_emissions);
     }
 
-    return new_emissions;
+    new_emissions
+}
+
+/// Update the backstop's keeper reward configuration
+///
+/// ### Arguments
+/// * `config` - The new keeper reward configuration
+///
+/// ### Panics
+/// If the reward is negative, the reward exceeds the max reward per period, or the period is zero
+pub fn execute_set_keeper_config(e: &Env, config: &KeeperConfig) {
+    if config.reward < 0 || config.max_per_period < config.reward || config.period == 0 {
+        panic_with_error!(e, BackstopError::BadRequest);
+    }
+    storage::set_keeper_config(e, config);
+}
+
+/// Pay the keeper reward out of `new_emissions`, capped by the max reward per keeper period
+///
+/// Returns the amount of BLND paid to the keeper
+fn pay_keeper_reward(
+    e: &Env,
+    config: &KeeperConfig,
+    keeper: &Address,
+    new_emissions: i128,
+) -> i128 {
+    let mut keeper_data = storage::get_keeper_data(e);
+    let timestamp = e.ledger().timestamp();
+    if timestamp >= keeper_data.period_start + config.period {
+        keeper_data.period_start = timestamp;
+        keeper_data.paid = 0;
+    }
+    let reward = config
+        .reward
+        .min(config.max_per_period - keeper_data.paid)
+        .min(new_emissions);
+    if reward <= 0 {
+        return 0;
+    }
+    keeper_data.paid += reward;
+    storage::set_keeper_data(e, &keeper_data);
+
+    TokenClient::new(e, &storage::get_blnd_token(e)).transfer(
+        &e.current_contract_address(),
+        keeper,
+        &reward,
+    );
+    BackstopEvents::keeper_reward(e, keeper.clone(), reward);
+    reward
 }
 
 /// Assign backstop and pool emissions to `pool` based on the reward zone and the backstop emissions index
@@ -246,7 +305,7 @@ pub fn gulp_emissions(e: &Env, pool: &Address) -> (i128, i128) {
         set_backstop_emission_eps(e, pool, &pool_balance, new_backstop_emissions);
         return (new_backstop_emissions, new_pool_emissions);
     }
-    return (0, 0);
+    (0, 0)
 }
 
 /// Set a new EPS for the backstop
@@ -259,7 +318,7 @@ pub fn set_backstop_emission_eps(
     let mut tokens_left_to_emit = new_tokens;
     let expiration = e.ledger().timestamp() + 7 * 24 * 60 * 60;
 
-    if let Some(mut emission_data) = update_emission_data(e, pool_id, &pool_balance) {
+    if let Some(mut emission_data) = update_emission_data(e, pool_id, pool_balance) {
         // a previous data exists - update with old data before setting new EPS
         if emission_data.last_time != e.ledger().timestamp() {
             // force the emission data to be updated to the current timestamp
@@ -471,7 +530,7 @@ fn test_gulp_emissions() {
             );
             blnd_token_client.approve(&backstop, &pool_1, &100_123_0000000, &e.ledger().sequence());
 
-            distribute(&e);
+            distribute(&e, None);
             gulp_emissions(&e, &pool_1);
             gulp_emissions(&e, &pool_2);
             gulp_emissions(&e, &pool_3);
@@ -612,7 +671,7 @@ fn test_distribute() {
             );
             storage::set_rz_emis(&e, &pool_3, &start_pool_3_accrued);
 
-            distribute(&e);
+            distribute(&e, None);
 
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, emitter_distro_time);
@@ -620,7 +679,7 @@ fn test_distribute() {
             assert_eq!(backfilled_emissions, 0);
 
             let pool_1_accrued = storage::get_rz_emis(&e, &pool_1);
-            assert_eq!(pool_1_accrued.accrued, 25_920_0000000 + 0);
+            assert_eq!(pool_1_accrued.accrued, 25_920_0000000);
             assert_eq!(pool_1_accrued.last_time, 0);
             let pool_2_accrued = storage::get_rz_emis(&e, &pool_2);
             assert_eq!(
@@ -641,6 +700,287 @@ fn test_distribute() {
         });
     }
 
+    #[test]
+    fn test_distribute_pays_keeper() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1713139200,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop = create_backstop(&e);
+        let keeper = Address::generate(&e);
+        let emitter_distro_time = 1713139200 - 10;
+        let (_, blnd_token_client) = create_blnd_token(&e, &backstop, &Address::generate(&e));
+        create_emitter(
+            &e,
+            &backstop,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            emitter_distro_time,
+        );
+        blnd_token_client.mint(&backstop, &100_000_0000000);
+
+        let pool_1 = Address::generate(&e);
+        let pool_2 = Address::generate(&e);
+        let reward_zone: Vec<Address> = vec![&e, pool_1.clone(), pool_2.clone()];
+
+        e.as_contract(&backstop, || {
+            storage::set_backfill_status(&e, &false);
+            storage::set_last_distribution_time(&e, &(emitter_distro_time - (60 * 60 * 24)));
+            storage::set_reward_zone(&e, &reward_zone);
+            let pool_balance = PoolBalance {
+                tokens: 300_000_0000000,
+                shares: 200_000_0000000,
+                q4w: 0,
+            };
+            storage::set_pool_balance(&e, &pool_1, &pool_balance);
+            storage::set_pool_balance(&e, &pool_2, &pool_balance);
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 10_0000000,
+                    min_interval: 60 * 60,
+                    period: 60 * 60 * 24,
+                    max_per_period: 100_0000000,
+                },
+            );
+
+            let new_emissions = distribute(&e, Some(keeper.clone()));
+
+            assert_eq!(new_emissions, 43_195_0000000 * 2);
+            assert_eq!(storage::get_rz_emis(&e, &pool_1).accrued, 43_195_0000000);
+            assert_eq!(storage::get_rz_emis(&e, &pool_2).accrued, 43_195_0000000);
+            assert_eq!(storage::get_keeper_data(&e).paid, 10_0000000);
+        });
+        assert_eq!(blnd_token_client.balance(&keeper), 10_0000000);
+    }
+
+    #[test]
+    fn test_distribute_default_keeper_config_no_reward() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1713139200,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop = create_backstop(&e);
+        let keeper = Address::generate(&e);
+        let emitter_distro_time = 1713139200 - 10;
+        let (_, blnd_token_client) = create_blnd_token(&e, &backstop, &Address::generate(&e));
+        create_emitter(
+            &e,
+            &backstop,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            emitter_distro_time,
+        );
+        blnd_token_client.mint(&backstop, &100_000_0000000);
+
+        let pool_1 = Address::generate(&e);
+        let reward_zone: Vec<Address> = vec![&e, pool_1.clone()];
+
+        e.as_contract(&backstop, || {
+            storage::set_backfill_status(&e, &false);
+            storage::set_last_distribution_time(&e, &(emitter_distro_time - (60 * 60 * 24)));
+            storage::set_reward_zone(&e, &reward_zone);
+            let pool_balance = PoolBalance {
+                tokens: 300_000_0000000,
+                shares: 200_000_0000000,
+                q4w: 0,
+            };
+            storage::set_pool_balance(&e, &pool_1, &pool_balance);
+
+            let new_emissions = distribute(&e, Some(keeper.clone()));
+
+            assert_eq!(new_emissions, 86_400_0000000);
+            assert_eq!(storage::get_keeper_data(&e).paid, 0);
+        });
+        assert_eq!(blnd_token_client.balance(&keeper), 0);
+    }
+
+    #[test]
+    fn test_distribute_pays_keeper_with_config() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1713139200,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop = create_backstop(&e);
+        let keeper = Address::generate(&e);
+        let emitter_distro_time = 1713139200 - 10;
+        let (_, blnd_token_client) = create_blnd_token(&e, &backstop, &Address::generate(&e));
+        create_emitter(
+            &e,
+            &backstop,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            emitter_distro_time,
+        );
+        blnd_token_client.mint(&backstop, &100_000_0000000);
+
+        let pool_1 = Address::generate(&e);
+        let reward_zone: Vec<Address> = vec![&e, pool_1.clone()];
+
+        e.as_contract(&backstop, || {
+            storage::set_backfill_status(&e, &false);
+            storage::set_last_distribution_time(&e, &(emitter_distro_time - (60 * 60 * 24)));
+            storage::set_reward_zone(&e, &reward_zone);
+            let pool_balance = PoolBalance {
+                tokens: 300_000_0000000,
+                shares: 200_000_0000000,
+                q4w: 0,
+            };
+            storage::set_pool_balance(&e, &pool_1, &pool_balance);
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 25_0000000,
+                    min_interval: 60 * 60 * 12,
+                    period: 60 * 60 * 24 * 7,
+                    max_per_period: 200_0000000,
+                },
+            );
+
+            let new_emissions = distribute(&e, Some(keeper.clone()));
+
+            assert_eq!(new_emissions, 86_400_0000000 - 25_0000000);
+            assert_eq!(storage::get_keeper_data(&e).paid, 25_0000000);
+        });
+        assert_eq!(blnd_token_client.balance(&keeper), 25_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_set_keeper_config_reward_over_max_per_period() {
+        let e = Env::default();
+        let backstop = create_backstop(&e);
+
+        e.as_contract(&backstop, || {
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 25_0000000,
+                    min_interval: 60 * 60,
+                    period: 60 * 60 * 24,
+                    max_per_period: 10_0000000,
+                },
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1000)")]
+    fn test_set_keeper_config_zero_period() {
+        let e = Env::default();
+        let backstop = create_backstop(&e);
+
+        e.as_contract(&backstop, || {
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 10_0000000,
+                    min_interval: 60 * 60,
+                    period: 0,
+                    max_per_period: 100_0000000,
+                },
+            );
+        });
+    }
+
+    #[test]
+    fn test_distribute_under_keeper_interval_no_reward() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1713139200,
+            protocol_version: 22,
+            sequence_number: 0,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let backstop = create_backstop(&e);
+        let keeper = Address::generate(&e);
+        let emitter_distro_time = 1713139200 - 10;
+        let (_, blnd_token_client) = create_blnd_token(&e, &backstop, &Address::generate(&e));
+        create_emitter(
+            &e,
+            &backstop,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            emitter_distro_time,
+        );
+        blnd_token_client.mint(&backstop, &100_000_0000000);
+
+        let pool_1 = Address::generate(&e);
+        let pool_2 = Address::generate(&e);
+        let reward_zone: Vec<Address> = vec![&e, pool_1.clone(), pool_2.clone()];
+
+        e.as_contract(&backstop, || {
+            storage::set_backfill_status(&e, &false);
+            storage::set_last_distribution_time(&e, &(emitter_distro_time - (60 * 30)));
+            storage::set_reward_zone(&e, &reward_zone);
+            let pool_balance = PoolBalance {
+                tokens: 300_000_0000000,
+                shares: 200_000_0000000,
+                q4w: 0,
+            };
+            storage::set_pool_balance(&e, &pool_1, &pool_balance);
+            storage::set_pool_balance(&e, &pool_2, &pool_balance);
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 10_0000000,
+                    min_interval: 60 * 60,
+                    period: 60 * 60 * 24,
+                    max_per_period: 100_0000000,
+                },
+            );
+
+            let new_emissions = distribute(&e, Some(keeper.clone()));
+
+            assert_eq!(new_emissions, 900_0000000 * 2);
+            assert_eq!(storage::get_rz_emis(&e, &pool_1).accrued, 900_0000000);
+            assert_eq!(storage::get_rz_emis(&e, &pool_2).accrued, 900_0000000);
+            assert_eq!(storage::get_keeper_data(&e).paid, 0);
+        });
+        assert_eq!(blnd_token_client.balance(&keeper), 0);
+    }
+
     #[test]
     fn test_distribute_one_block_rounding_ok() {
         let e = Env::default();
@@ -718,7 +1058,7 @@ fn test_distribute_one_block_rounding_ok() {
             );
             storage::set_rz_emis(&e, &pool_3, &start_pool_3_accrued);
 
-            distribute(&e);
+            distribute(&e, None);
 
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, emitter_distro_time);
@@ -726,7 +1066,7 @@ fn test_distribute_one_block_rounding_ok() {
             assert_eq!(backfilled_emissions, 0);
 
             let pool_1_accrued = storage::get_rz_emis(&e, &pool_1);
-            assert_eq!(pool_1_accrued.accrued, 330 + 0);
+            assert_eq!(pool_1_accrued.accrued, 330);
             assert_eq!(pool_1_accrued.last_time, 0);
             let pool_2_accrued = storage::get_rz_emis(&e, &pool_2);
             assert_eq!(
@@ -791,7 +1131,7 @@ fn test_distribute_empty_rz() {
                 },
             );
 
-            distribute(&e);
+            distribute(&e, None);
         });
     }
 
@@ -856,7 +1196,7 @@ fn test_distribute_no_last_dist_time() {
                 },
             );
 
-            let new_emissions = distribute(&e);
+            let new_emissions = distribute(&e, None);
 
             assert_eq!(new_emissions, 0);
             let last_distro_time = storage::get_last_distribution_time(&e);
@@ -944,7 +1284,7 @@ fn test_distribute_under_5_block_time_panics() {
                 },
             );
 
-            distribute(&e);
+            distribute(&e, None);
         });
     }
 
@@ -1028,7 +1368,7 @@ fn test_distribute_last_distro_panics_errors() {
             );
             storage::set_rz_emis(&e, &pool_3, &start_pool_3_accrued);
 
-            distribute(&e);
+            distribute(&e, None);
         });
     }
 
@@ -1108,7 +1448,7 @@ fn test_distribute_backfill_emissions() {
             );
             storage::set_rz_emis(&e, &pool_3, &start_pool_3_accrued);
 
-            distribute(&e);
+            distribute(&e, None);
 
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, e.ledger().timestamp());
@@ -1121,7 +1461,7 @@ fn test_distribute_backfill_emissions() {
             assert_eq!(is_backfill, Some(true));
 
             let pool_1_accrued = storage::get_rz_emis(&e, &pool_1);
-            assert_eq!(pool_1_accrued.accrued, 25_920_0000000 + 0);
+            assert_eq!(pool_1_accrued.accrued, 25_920_0000000);
             assert_eq!(pool_1_accrued.last_time, 0);
             let pool_2_accrued = storage::get_rz_emis(&e, &pool_2);
             assert_eq!(
@@ -1200,7 +1540,7 @@ fn test_distribute_backfill_emissions_first_call() {
                 },
             );
 
-            distribute(&e);
+            distribute(&e, None);
 
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, e.ledger().timestamp());
@@ -1286,7 +1626,7 @@ fn test_distribute_backfill_emissions_distributes_at_most_max() {
                 },
             );
 
-            distribute(&e);
+            distribute(&e, None);
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, e.ledger().timestamp());
             let backfilled_emissions = storage::get_backfill_emissions(&e);
@@ -1373,7 +1713,7 @@ fn test_distribute_backfill_emissions_at_max_panics() {
                 },
             );
 
-            distribute(&e);
+            distribute(&e, None);
         });
     }
 
@@ -1454,7 +1794,7 @@ fn test_distribute_backfill_emissions_over_needs_reset() {
             );
             storage::set_rz_emis(&e, &pool_3, &start_pool_3_accrued);
 
-            distribute(&e);
+            distribute(&e, None);
 
             let last_distro_time = storage::get_last_distribution_time(&e);
             assert_eq!(last_distro_time, emitter_distro_time);
@@ -2,7 +2,10 @@
 pub use claim::execute_claim;
 
 mod distributor;
-pub use distributor::update_emissions;
+pub use distributor::{add_boost, remove_boost, update_emissions};
 
 mod manager;
-pub use manager::{add_to_reward_zone, distribute, gulp_emissions, remove_from_reward_zone};
+pub use manager::{
+    add_to_reward_zone, distribute, execute_set_keeper_config, gulp_emissions,
+    remove_from_reward_zone,
+};
@@ -29,4 +29,8 @@ pub enum BackstopError {
     RewardZoneFull = 1009,
     MaxBackfillEmissions = 1010,
     BadDebtExists = 1011,
+    InvalidPoolTransfer = 1012,
+    DrawLimitExceeded = 1013,
+    SharesLocked = 1014,
+    AuctionInProgress = 1015,
 }
@@ -1,4 +1,6 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::storage::{DrawLimit, KeeperConfig};
 
 pub struct BackstopEvents {}
 
@@ -25,6 +27,32 @@ pub fn deposit(
             .publish(topics, (tokens_in, backstop_shares_minted));
     }
 
+    /// Emitted when BLND or USDC is single-sided deposited into a backstop
+    ///
+    /// - topics - `["deposit_single_sided", pool_address: Address, from: Address]`
+    /// - data - `[token: Address, amount: i128, tokens_in: i128, backstop_shares_minted: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user depositing tokens
+    /// * `token` - The address of the token deposited into the LP pool
+    /// * `amount` - The amount of `token` deposited into the LP pool
+    /// * `tokens_in` - The amount of backstop tokens deposited into the backstop
+    /// * `backstop_shares_minted` - The amount of backstop shares minted
+    pub fn deposit_single_sided(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        token: Address,
+        amount: i128,
+        tokens_in: i128,
+        backstop_shares_minted: i128,
+    ) {
+        let topics = (Symbol::new(e, "deposit_single_sided"), pool_address, from);
+        e.events()
+            .publish(topics, (token, amount, tokens_in, backstop_shares_minted));
+    }
+
     /// Emitted when a withdrawal is queued
     ///
     /// - topics - `["queue_withdrawal", pool_address: Address, from: Address]`
@@ -75,6 +103,85 @@ pub fn withdraw(e: &Env, pool_address: Address, from: Address, amount: i128, tok
         e.events().publish(topics, (amount, tokens_out));
     }
 
+    /// Emitted when queued tokens are withdrawn from the backstop and exited to BLND or USDC
+    ///
+    /// - topics - `["withdraw_single_sided", pool_address: Address, from: Address]`
+    /// - data - `[token: Address, amount: i128, tokens_out: i128, amount_out: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user withdrawing tokens
+    /// * `token` - The address of the token exited from the LP pool
+    /// * `amount` - The amount of backstop shares being burned
+    /// * `tokens_out` - The amount of backstop tokens being withdrawn
+    /// * `amount_out` - The amount of `token` sent to the user
+    pub fn withdraw_single_sided(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        token: Address,
+        amount: i128,
+        tokens_out: i128,
+        amount_out: i128,
+    ) {
+        let topics = (Symbol::new(e, "withdraw_single_sided"), pool_address, from);
+        e.events()
+            .publish(topics, (token, amount, tokens_out, amount_out));
+    }
+
+    /// Emitted when queued tokens are withdrawn from the backstop before the queue expires
+    ///
+    /// - topics - `["withdraw_early", pool_address: Address, from: Address]`
+    /// - data - `[amount: i128, tokens_out: i128, penalty: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user withdrawing tokens
+    /// * `amount` - The amount of backstop shares being burned
+    /// * `tokens_out` - The amount of tokens being withdrawn
+    /// * `penalty` - The amount of burned shares forfeited to the pool's remaining depositors
+    pub fn withdraw_early(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        amount: i128,
+        tokens_out: i128,
+        penalty: i128,
+    ) {
+        let topics = (Symbol::new(e, "withdraw_early"), pool_address, from);
+        e.events().publish(topics, (amount, tokens_out, penalty));
+    }
+
+    /// Emitted when shares are transferred from the backstop of one pool to another
+    ///
+    /// - topics - `["transfer_between_pools", from_pool_address: Address, to_pool_address: Address, from: Address]`
+    /// - data - `[amount: i128, tokens: i128, shares_minted: i128]`
+    ///
+    /// ### Arguments
+    /// * `from_pool_address` - The address of the pool the shares are transferred from
+    /// * `to_pool_address` - The address of the pool the shares are transferred to
+    /// * `from` - The address of the user transferring shares
+    /// * `amount` - The amount of shares burned in the source pool
+    /// * `tokens` - The amount of tokens moved between the pools
+    /// * `shares_minted` - The amount of shares minted in the destination pool
+    pub fn transfer_between_pools(
+        e: &Env,
+        from_pool_address: Address,
+        to_pool_address: Address,
+        from: Address,
+        amount: i128,
+        tokens: i128,
+        shares_minted: i128,
+    ) {
+        let topics = (
+            Symbol::new(e, "transfer_between_pools"),
+            from_pool_address,
+            to_pool_address,
+            from,
+        );
+        e.events().publish(topics, (amount, tokens, shares_minted));
+    }
+
     /// Emitted when new emissions are distributed
     /// - topics - `["distribute"]`
     /// - data - `[new_tokens_emitted: i128]`
@@ -86,6 +193,18 @@ pub fn distribute(e: &Env, new_tokens_emitted: i128) {
         e.events().publish(topics, new_tokens_emitted);
     }
 
+    /// Emitted when a keeper is rewarded for a distribution
+    /// - topics - `["keeper_reward", keeper: Address]`
+    /// - data - `[reward: i128]`
+    ///
+    /// ### Arguments
+    /// * `keeper` - The address of the keeper
+    /// * `reward` - The amount of BLND paid to the keeper
+    pub fn keeper_reward(e: &Env, keeper: Address, reward: i128) {
+        let topics = (Symbol::new(e, "keeper_reward"), keeper);
+        e.events().publish(topics, reward);
+    }
+
     /// Emitted when new emissions are gulped
     ///
     /// - topics - `["gulp_emissions", pool_address: Address]`
@@ -131,6 +250,44 @@ pub fn rw_zone_remove(e: &Env, to_remove: Address) {
         e.events().publish(topics, to_remove);
     }
 
+    /// Emitted when shares are locked in a backstop
+    ///
+    /// - topics - `["lock_shares", pool_address: Address, from: Address]`
+    /// - data - `[amount: i128, unlock_time: u64, boost: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user locking shares
+    /// * `amount` - The amount of shares locked
+    /// * `unlock_time` - The time the user's locked shares unlock
+    /// * `boost` - The emission weight added by the locked shares
+    pub fn lock_shares(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        amount: i128,
+        unlock_time: u64,
+        boost: i128,
+    ) {
+        let topics = (Symbol::new(e, "lock_shares"), pool_address, from);
+        e.events().publish(topics, (amount, unlock_time, boost));
+    }
+
+    /// Emitted when an expired lock is removed from a backstop
+    ///
+    /// - topics - `["unlock_shares", pool_address: Address, user: Address]`
+    /// - data - `[shares: i128, boost: i128]`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `user` - The address of the user whose lock expired
+    /// * `shares` - The amount of shares unlocked
+    /// * `boost` - The emission weight removed
+    pub fn unlock_shares(e: &Env, pool_address: Address, user: Address, shares: i128, boost: i128) {
+        let topics = (Symbol::new(e, "unlock_shares"), pool_address, user);
+        e.events().publish(topics, (shares, boost));
+    }
+
     /// Emitted when emissions are claimed
     ///
     /// - topics - `["claim", from: Address]`
@@ -158,6 +315,44 @@ pub fn draw(e: &Env, pool_address: Address, to: Address, amount: i128) {
         e.events().publish(topics, (to, amount));
     }
 
+    /// Emitted when the draw limit for a pool is set or removed
+    ///
+    /// - topics - `["set_draw_limit", pool_address: Address]`
+    /// - data - `draw_limit: Option<DrawLimit>`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `draw_limit` - The draw limit, or None if the limit was removed
+    pub fn set_draw_limit(e: &Env, pool_address: Address, draw_limit: Option<DrawLimit>) {
+        let topics = (Symbol::new(e, "set_draw_limit"), pool_address);
+        e.events().publish(topics, draw_limit);
+    }
+
+    /// Emitted when the admin updates the keeper reward configuration
+    ///
+    /// - topics - `["set_keeper_config"]`
+    /// - data - `config: KeeperConfig`
+    ///
+    /// ### Arguments
+    /// * `config` - The new keeper reward configuration
+    pub fn set_keeper_config(e: &Env, config: KeeperConfig) {
+        let topics = (Symbol::new(e, "set_keeper_config"),);
+        e.events().publish(topics, config);
+    }
+
+    /// Emitted when the admin approves an emergency draw over a pool's draw limit
+    ///
+    /// - topics - `["approve_emergency_draw", pool_address: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `amount` - The amount of tokens that can be drawn over the limit
+    pub fn approve_emergency_draw(e: &Env, pool_address: Address, amount: i128) {
+        let topics = (Symbol::new(e, "approve_emergency_draw"), pool_address);
+        e.events().publish(topics, amount);
+    }
+
     /// Emitted when tokens are donated to the backstop
     ///
     /// - topics - `["donate", pool_address: Address, from: Address]`
@@ -171,4 +366,88 @@ pub fn donate(e: &Env, pool_address: Address, from: Address, amount: i128) {
         let topics = (Symbol::new(e, "donate"), pool_address, from);
         e.events().publish(topics, amount);
     }
+
+    /// Emitted when a share token is deployed for a pool
+    ///
+    /// - topics - `["deploy_share_token", pool_address: Address]`
+    /// - data - `share_token: Address`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `share_token` - The address of the pool's share token
+    pub fn deploy_share_token(e: &Env, pool_address: Address, share_token: Address) {
+        let topics = (Symbol::new(e, "deploy_share_token"), pool_address);
+        e.events().publish(topics, share_token);
+    }
+
+    /// Emitted when shares are transferred through a pool's share token
+    ///
+    /// - topics - `["transfer_shares", pool_address: Address, from: Address, to: Address]`
+    /// - data - `amount: i128`
+    ///
+    /// ### Arguments
+    /// * `pool_address` - The address of the pool
+    /// * `from` - The address of the user sending shares
+    /// * `to` - The address of the user receiving shares
+    /// * `amount` - The amount of shares transferred
+    pub fn transfer_shares(
+        e: &Env,
+        pool_address: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        let topics = (Symbol::new(e, "transfer_shares"), pool_address, from, to);
+        e.events().publish(topics, amount);
+    }
+
+    /// Emitted when a contract upgrade is queued
+    ///
+    /// - topics - `["queue_upgrade"]`
+    /// - data - `[wasm_hash: BytesN<32>, unlock_time: u64]`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the wasm the backstop will be upgraded to
+    /// * `unlock_time` - The earliest time the upgrade can be applied
+    pub fn queue_upgrade(e: &Env, wasm_hash: BytesN<32>, unlock_time: u64) {
+        let topics = (Symbol::new(e, "queue_upgrade"),);
+        e.events().publish(topics, (wasm_hash, unlock_time));
+    }
+
+    /// Emitted when a queued contract upgrade is cancelled
+    ///
+    /// - topics - `["cancel_upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the cancelled wasm
+    pub fn cancel_upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "cancel_upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the contract is upgraded
+    ///
+    /// - topics - `["upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the new wasm
+    pub fn upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the backstop's storage is migrated after an upgrade
+    ///
+    /// - topics - `["migrate_storage"]`
+    /// - data - `[from_version: u32, to_version: u32]`
+    ///
+    /// ### Arguments
+    /// * `from_version` - The storage version before the migration
+    /// * `to_version` - The storage version after the migration
+    pub fn migrate_storage(e: &Env, from_version: u32, to_version: u32) {
+        let topics = (Symbol::new(e, "migrate_storage"),);
+        e.events().publish(topics, (from_version, to_version));
+    }
 }
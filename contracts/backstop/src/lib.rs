@@ -13,7 +13,10 @@
 mod storage;
 mod testutils;
 
-pub use backstop::{PoolBackstopData, PoolBalance, UserBalance, Q4W};
+pub use backstop::{BoostedShares, PoolBackstopData, PoolBalance, UserBalance, Q4W};
 pub use contract::*;
 pub use errors::BackstopError;
-pub use storage::{BackstopDataKey, BackstopEmissionData, PoolUserKey, UserEmissionData};
+pub use storage::{
+    BackstopDataKey, BackstopEmissionData, DrawLimit, KeeperConfig, PoolUserKey, UserEmissionData,
+    UserLock,
+};
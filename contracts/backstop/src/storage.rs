@@ -1,8 +1,12 @@
 use soroban_sdk::{
-    contracttype, unwrap::UnwrapOptimized, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+    contracttype, unwrap::UnwrapOptimized, vec, Address, BytesN, Env, IntoVal, Symbol, TryFromVal,
+    Val, Vec,
 };
 
-use crate::backstop::{PoolBalance, UserBalance};
+use crate::{
+    backstop::{PoolBalance, UserBalance},
+    constants::{KEEPER_MAX_PER_PERIOD, KEEPER_MIN_INTERVAL, KEEPER_PERIOD},
+};
 
 /********** Ledger Thresholds **********/
 
@@ -51,8 +55,79 @@ pub struct UserEmissionData {
     pub accrued: i128,
 }
 
+/// A user's locked backstop shares
+#[derive(Clone)]
+#[contracttype]
+pub struct UserLock {
+    // The amount of shares locked
+    pub shares: i128,
+    // The time the shares unlock
+    pub unlock_time: u64,
+    // The emission weight added to the user's shares by the locked shares
+    pub boost: i128,
+    // The last time emissions were accrued on the boost
+    pub last_time: u64,
+}
+
+/// The backstop's keeper reward configuration
+#[derive(Clone)]
+#[contracttype]
+pub struct KeeperConfig {
+    // The BLND paid to a keeper for a distribution
+    pub reward: i128,
+    // The minimum time in seconds since the last distribution for a keeper to be rewarded
+    pub min_interval: u64,
+    // The length of a keeper reward period in seconds
+    pub period: u64,
+    // The maximum BLND paid to keepers per keeper reward period
+    pub max_per_period: i128,
+}
+
+/// The keeper rewards paid by the backstop during the current keeper period
+#[derive(Clone)]
+#[contracttype]
+pub struct KeeperData {
+    // The start time of the current keeper period
+    pub period_start: u64,
+    // The BLND paid to keepers during the current keeper period
+    pub paid: i128,
+}
+
+/// The maximum amount a pool can draw from its backstop per draw window
+#[derive(Clone)]
+#[contracttype]
+pub struct DrawLimit {
+    // The maximum amount of backstop tokens that can be drawn per window
+    pub max_draw: i128,
+    // The length of a draw window in seconds
+    pub window: u64,
+}
+
+/// The draws made by a pool during the current draw window
+#[derive(Clone)]
+#[contracttype]
+pub struct DrawData {
+    // The start time of the current draw window
+    pub window_start: u64,
+    // The backstop tokens drawn during the current draw window
+    pub drawn: i128,
+    // The backstop tokens the admin approved to be drawn over the limit
+    pub emergency: i128,
+}
+
+/// A contract wasm upgrade waiting for its timelock to expire
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedUpgrade {
+    // The hash of the wasm to upgrade to
+    pub wasm_hash: BytesN<32>,
+    // The earliest time the upgrade can be applied
+    pub unlock_time: u64,
+}
+
 /********** Storage Key Types **********/
 
+const ADMIN_KEY: &str = "Admin";
 const EMITTER_KEY: &str = "Emitter";
 const BACKSTOP_TOKEN_KEY: &str = "BToken";
 const POOL_FACTORY_KEY: &str = "PoolFact";
@@ -60,9 +135,14 @@ pub struct UserEmissionData {
 const USDC_TOKEN_KEY: &str = "USDCTkn";
 const LAST_DISTRO_KEY: &str = "LastDist";
 const REWARD_ZONE_KEY: &str = "RZ";
+const KEEPER_DATA_KEY: &str = "Keeper";
+const KEEPER_CONFIG_KEY: &str = "KeeperCfg";
 const DROP_LIST_KEY: &str = "DropList";
 const BACKFILL_EMISSIONS_KEY: &str = "BackfillEmis";
 const BACKFILL_STATUS_KEY: &str = "Backfill";
+const UPGRADE_KEY: &str = "Upgrade";
+const VERSION_KEY: &str = "Version";
+const SHARE_TOKEN_WASM_KEY: &str = "ShareWasm";
 
 #[derive(Clone)]
 #[contracttype]
@@ -80,6 +160,11 @@ pub enum BackstopDataKey {
     RzEmis(Address),
     BEmisData(Address),
     UEmisData(PoolUserKey),
+    ShareToken(Address),
+    DrawLimit(Address),
+    DrawData(Address),
+    UserLock(PoolUserKey),
+    PoolBoost(Address),
 }
 
 /****************************
@@ -113,6 +198,64 @@ fn get_persistent_default<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>, F: FnOn
 
 /********** Instance Storage **********/
 
+/// Fetch the admin
+pub fn get_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY))
+        .unwrap_optimized()
+}
+
+/// Set the admin
+///
+/// ### Arguments
+/// * `admin` - The address of the admin
+pub fn set_admin(e: &Env, admin: &Address) {
+    e.storage()
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, ADMIN_KEY), admin);
+}
+
+/// Fetch the queued contract upgrade, if any
+pub fn get_queued_upgrade(e: &Env) -> Option<QueuedUpgrade> {
+    e.storage()
+        .instance()
+        .get::<Symbol, QueuedUpgrade>(&Symbol::new(e, UPGRADE_KEY))
+}
+
+/// Set the queued contract upgrade
+///
+/// ### Arguments
+/// * `upgrade` - The queued contract upgrade
+pub fn set_queued_upgrade(e: &Env, upgrade: &QueuedUpgrade) {
+    e.storage()
+        .instance()
+        .set::<Symbol, QueuedUpgrade>(&Symbol::new(e, UPGRADE_KEY), upgrade);
+}
+
+/// Delete the queued contract upgrade
+pub fn del_queued_upgrade(e: &Env) {
+    e.storage().instance().remove(&Symbol::new(e, UPGRADE_KEY));
+}
+
+/// Fetch the version of the backstop's storage layout
+pub fn get_storage_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get::<Symbol, u32>(&Symbol::new(e, VERSION_KEY))
+        .unwrap_or(1)
+}
+
+/// Set the version of the backstop's storage layout
+///
+/// ### Arguments
+/// * `version` - The storage version
+pub fn set_storage_version(e: &Env, version: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, VERSION_KEY), &version);
+}
+
 /// Fetch the emitter id
 pub fn get_emitter(e: &Env) -> Address {
     e.storage()
@@ -203,6 +346,55 @@ pub fn set_backstop_token(e: &Env, backstop_token_id: &Address) {
         .set::<Symbol, Address>(&Symbol::new(e, BACKSTOP_TOKEN_KEY), backstop_token_id);
 }
 
+/********** Share Tokens **********/
+
+/// Fetch the hash of the wasm deployed for pool share tokens, if set
+pub fn get_share_token_wasm(e: &Env) -> Option<BytesN<32>> {
+    e.storage()
+        .instance()
+        .get::<Symbol, BytesN<32>>(&Symbol::new(e, SHARE_TOKEN_WASM_KEY))
+}
+
+/// Set the hash of the wasm deployed for pool share tokens
+///
+/// ### Arguments
+/// * `wasm_hash` - The hash of the uploaded share token wasm
+pub fn set_share_token_wasm(e: &Env, wasm_hash: &BytesN<32>) {
+    e.storage()
+        .instance()
+        .set::<Symbol, BytesN<32>>(&Symbol::new(e, SHARE_TOKEN_WASM_KEY), wasm_hash);
+}
+
+/// Fetch the share token for a pool, if one has been deployed
+///
+/// ### Arguments
+/// * `pool` - The pool the share token is for
+pub fn get_share_token(e: &Env, pool: &Address) -> Option<Address> {
+    let key = BackstopDataKey::ShareToken(pool.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the share token for a pool
+///
+/// ### Arguments
+/// * `pool` - The pool the share token is for
+/// * `share_token` - The address of the share token
+pub fn set_share_token(e: &Env, pool: &Address, share_token: &Address) {
+    let key = BackstopDataKey::ShareToken(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, Address>(&key, share_token);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /********** User Shares **********/
 
 /// Fetch the balance's for a given user
@@ -282,6 +474,81 @@ pub fn set_pool_balance(e: &Env, pool: &Address, balance: &PoolBalance) {
         .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/********** Draw Limits **********/
+
+/// Fetch the draw limit for a pool, if one is set
+///
+/// ### Arguments
+/// * `pool` - The pool the draw limit is for
+pub fn get_draw_limit(e: &Env, pool: &Address) -> Option<DrawLimit> {
+    let key = BackstopDataKey::DrawLimit(pool.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the draw limit for a pool
+///
+/// ### Arguments
+/// * `pool` - The pool the draw limit is for
+/// * `draw_limit` - The draw limit
+pub fn set_draw_limit(e: &Env, pool: &Address, draw_limit: &DrawLimit) {
+    let key = BackstopDataKey::DrawLimit(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, DrawLimit>(&key, draw_limit);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Remove the draw limit for a pool
+///
+/// ### Arguments
+/// * `pool` - The pool the draw limit is for
+pub fn del_draw_limit(e: &Env, pool: &Address) {
+    let key = BackstopDataKey::DrawLimit(pool.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/// Fetch the draws made by a pool during the current draw window
+///
+/// ### Arguments
+/// * `pool` - The pool drawing from its backstop
+pub fn get_draw_data(e: &Env, pool: &Address) -> DrawData {
+    let key = BackstopDataKey::DrawData(pool.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || DrawData {
+            window_start: 0,
+            drawn: 0,
+            emergency: 0,
+        },
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the draws made by a pool during the current draw window
+///
+/// ### Arguments
+/// * `pool` - The pool drawing from its backstop
+/// * `draw_data` - The draw data
+pub fn set_draw_data(e: &Env, pool: &Address, draw_data: &DrawData) {
+    let key = BackstopDataKey::DrawData(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, DrawData>(&key, draw_data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /********** Distribution / Reward Zone **********/
 
 /// Get the timestamp of when the next emission cycle begins
@@ -310,6 +577,68 @@ pub fn set_last_distribution_time(e: &Env, timestamp: &u64) {
     );
 }
 
+/// Get the keeper reward configuration, or the default configuration if none is set
+///
+/// Keeper rewards are disabled by default, until the admin sets a reward
+pub fn get_keeper_config(e: &Env) -> KeeperConfig {
+    get_persistent_default(
+        e,
+        &Symbol::new(e, KEEPER_CONFIG_KEY),
+        || KeeperConfig {
+            reward: 0,
+            min_interval: KEEPER_MIN_INTERVAL,
+            period: KEEPER_PERIOD,
+            max_per_period: KEEPER_MAX_PER_PERIOD,
+        },
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the keeper reward configuration
+///
+/// ### Arguments
+/// * `config` - The keeper reward configuration
+pub fn set_keeper_config(e: &Env, config: &KeeperConfig) {
+    e.storage()
+        .persistent()
+        .set::<Symbol, KeeperConfig>(&Symbol::new(e, KEEPER_CONFIG_KEY), config);
+    e.storage().persistent().extend_ttl(
+        &Symbol::new(e, KEEPER_CONFIG_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+}
+
+/// Get the keeper rewards paid during the current keeper period
+pub fn get_keeper_data(e: &Env) -> KeeperData {
+    get_persistent_default(
+        e,
+        &Symbol::new(e, KEEPER_DATA_KEY),
+        || KeeperData {
+            period_start: 0,
+            paid: 0,
+        },
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the keeper rewards paid during the current keeper period
+///
+/// ### Arguments
+/// * `data` - The keeper reward data
+pub fn set_keeper_data(e: &Env, data: &KeeperData) {
+    e.storage()
+        .persistent()
+        .set::<Symbol, KeeperData>(&Symbol::new(e, KEEPER_DATA_KEY), data);
+    e.storage().persistent().extend_ttl(
+        &Symbol::new(e, KEEPER_DATA_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+}
+
 /// Get the current pool addresses that are in the reward zone
 pub fn get_reward_zone(e: &Env) -> Vec<Address> {
     get_persistent_default(
@@ -492,13 +821,90 @@ pub fn set_user_emis_data(
         .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
 }
 
+/********** Share Locks **********/
+
+/// Get the user's locked shares in a pool's backstop, if any
+///
+/// ### Arguments
+/// * `pool` - The pool the shares are locked in
+/// * `user` - The user's address
+pub fn get_user_lock(e: &Env, pool: &Address, user: &Address) -> Option<UserLock> {
+    let key = BackstopDataKey::UserLock(PoolUserKey {
+        pool: pool.clone(),
+        user: user.clone(),
+    });
+    get_persistent_default(e, &key, || None, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set the user's locked shares in a pool's backstop
+///
+/// ### Arguments
+/// * `pool` - The pool the shares are locked in
+/// * `user` - The user's address
+/// * `lock` - The user's lock
+pub fn set_user_lock(e: &Env, pool: &Address, user: &Address, lock: &UserLock) {
+    let key = BackstopDataKey::UserLock(PoolUserKey {
+        pool: pool.clone(),
+        user: user.clone(),
+    });
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, UserLock>(&key, lock);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Remove the user's locked shares in a pool's backstop
+///
+/// ### Arguments
+/// * `pool` - The pool the shares are locked in
+/// * `user` - The user's address
+pub fn del_user_lock(e: &Env, pool: &Address, user: &Address) {
+    let key = BackstopDataKey::UserLock(PoolUserKey {
+        pool: pool.clone(),
+        user: user.clone(),
+    });
+    e.storage().persistent().remove(&key);
+}
+
+/// Get the emission weight added to a pool's backstop shares by locked shares
+///
+/// ### Arguments
+/// * `pool` - The pool the shares are locked in
+pub fn get_pool_boost(e: &Env, pool: &Address) -> i128 {
+    let key = BackstopDataKey::PoolBoost(pool.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || 0i128,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the emission weight added to a pool's backstop shares by locked shares
+///
+/// ### Arguments
+/// * `pool` - The pool the shares are locked in
+/// * `boost` - The emission weight
+pub fn set_pool_boost(e: &Env, pool: &Address, boost: i128) {
+    let key = BackstopDataKey::PoolBoost(pool.clone());
+    e.storage()
+        .persistent()
+        .set::<BackstopDataKey, i128>(&key, &boost);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /********** Drop Emissions **********/
 
 /// Get the current pool addresses that are in the drop list and the amount of the initial distribution they receive
 pub fn get_drop_list(e: &Env) -> Vec<(Address, i128)> {
     e.storage()
         .persistent()
-        .get::<Symbol, Vec<(Address, i128)>>(&Symbol::new(&e, DROP_LIST_KEY))
+        .get::<Symbol, Vec<(Address, i128)>>(&Symbol::new(e, DROP_LIST_KEY))
         .unwrap_optimized()
 }
 
@@ -509,10 +915,46 @@ pub fn get_drop_list(e: &Env) -> Vec<(Address, i128)> {
 pub fn set_drop_list(e: &Env, drop_list: &Vec<(Address, i128)>) {
     e.storage()
         .persistent()
-        .set::<Symbol, Vec<(Address, i128)>>(&Symbol::new(&e, DROP_LIST_KEY), drop_list);
+        .set::<Symbol, Vec<(Address, i128)>>(&Symbol::new(e, DROP_LIST_KEY), drop_list);
     e.storage().persistent().extend_ttl(
-        &Symbol::new(&e, DROP_LIST_KEY),
+        &Symbol::new(e, DROP_LIST_KEY),
         LEDGER_THRESHOLD_USER,
         LEDGER_BUMP_USER,
     );
 }
+
+/********** TTL Maintenance **********/
+
+/// Fetch the keys of every entry stored for a user's deposit in a pool. Only keys with an
+/// existing entry are returned.
+///
+/// ### Arguments
+/// * `pool` - The pool the deposit is associated with
+/// * `user` - The owner of the deposit
+pub fn get_user_storage_keys(e: &Env, pool: &Address, user: &Address) -> Vec<BackstopDataKey> {
+    let pool_user_key = PoolUserKey {
+        pool: pool.clone(),
+        user: user.clone(),
+    };
+    let mut keys: Vec<BackstopDataKey> = vec![e];
+    for key in [
+        BackstopDataKey::UserBalance(pool_user_key.clone()),
+        BackstopDataKey::UEmisData(pool_user_key.clone()),
+        BackstopDataKey::UserLock(pool_user_key),
+    ] {
+        if e.storage().persistent().has(&key) {
+            keys.push_back(key);
+        }
+    }
+    keys
+}
+
+/// Extend the TTL of a user's entry. The entry must exist.
+///
+/// ### Arguments
+/// * `key` - The key of the entry
+pub fn bump_user_storage_key(e: &Env, key: &BackstopDataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
@@ -33,6 +33,7 @@ pub(crate) fn create_backstop(e: &Env) -> Address {
             Address::generate(e),
             Address::generate(e),
             Vec::<(Address, i128)>::new(e),
+            Address::generate(e),
         ),
     )
 }
@@ -41,7 +42,7 @@ pub(crate) fn create_token<'a>(e: &Env, admin: &Address) -> (Address, MockTokenC
     let contract_address = Address::generate(e);
     e.register_at(&contract_address, MockTokenWASM, ());
     let client = MockTokenClient::new(e, &contract_address);
-    client.initialize(&admin, &7, &"unit".into_val(e), &"test".into_val(e));
+    client.initialize(admin, &7, &"unit".into_val(e), &"test".into_val(e));
     (contract_address, client)
 }
 
@@ -91,7 +92,7 @@ pub(crate) fn create_mock_pool_factory<'a>(
 ) -> (Address, MockPoolFactoryClient<'a>) {
     let pool_init_meta = PoolInitMeta {
         backstop: backstop.clone(),
-        pool_hash: BytesN::<32>::from_array(&e, &[0u8; 32]),
+        pool_hash: BytesN::<32>::from_array(e, &[0u8; 32]),
         blnd_id: Address::generate(e),
     };
     let contract_address = e.register(MockPoolFactory {}, (pool_init_meta,));
@@ -128,7 +129,7 @@ pub(crate) fn create_emitter<'a>(
         storage::set_emitter(e, &contract_address);
     });
     let client = EmitterClient::new(e, &contract_address);
-    client.initialize(&blnd_token, &backstop, &backstop_token);
+    client.initialize(blnd_token, backstop, backstop_token);
     e.ledger().set(LedgerInfo {
         timestamp: prev_timestamp,
         protocol_version: 22,
@@ -161,8 +162,8 @@ pub(crate) fn create_comet_lp_pool<'a>(
 
     let blnd_client = MockTokenClient::new(e, blnd_token);
     let usdc_client = MockTokenClient::new(e, usdc_token);
-    blnd_client.mint(&admin, &1_000_0000000);
-    usdc_client.mint(&admin, &25_0000000);
+    blnd_client.mint(admin, &1_000_0000000);
+    usdc_client.mint(admin, &25_0000000);
 
     client.init(
         admin,
@@ -199,8 +200,8 @@ pub(crate) fn create_comet_lp_pool_with_tokens_per_share<'a>(
     let usdc_client = MockTokenClient::new(e, usdc_token);
     let blnd_total = 100 * blnd_per_share;
     let usdc_total = 100 * usdc_per_share;
-    blnd_client.mint(&admin, &blnd_total);
-    usdc_client.mint(&admin, &usdc_total);
+    blnd_client.mint(admin, &blnd_total);
+    usdc_client.mint(admin, &usdc_total);
 
     // init seeds pool with 100 shares
     client.init(
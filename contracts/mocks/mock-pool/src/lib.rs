@@ -16,6 +16,7 @@ pub struct Positions {
 #[contracttype]
 pub enum DataKey {
     Positions(Address),
+    AuctionCount(u32),
 }
 
 #[contract]
@@ -56,4 +57,32 @@ pub fn get_positions(e: Env, address: Address) -> Positions {
             },
         }
     }
+
+    /// Set the number of auctions of a type
+    ///
+    /// # Arguments
+    /// * 'auction_type' - The type of auction
+    /// * 'count' - The number of auctions
+    pub fn set_auction_count(e: Env, auction_type: u32, count: u32) {
+        e.storage()
+            .instance()
+            .extend_ttl(LEDGER_THRESHOLD, LEDGER_BUMP);
+        let key = DataKey::AuctionCount(auction_type);
+        e.storage().persistent().set::<DataKey, u32>(&key, &count);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD, LEDGER_BUMP);
+    }
+
+    /// Fetch the number of auctions of a type
+    ///
+    /// # Arguments
+    /// * 'auction_type' - The type of auction
+    pub fn get_auction_count(e: Env, auction_type: u32) -> u32 {
+        let key = DataKey::AuctionCount(auction_type);
+        e.storage()
+            .persistent()
+            .get::<DataKey, u32>(&key)
+            .unwrap_or(0)
+    }
 }
@@ -22,4 +22,13 @@ pub enum OracleError {
     
     /// Contract is not initialized
     NotInitialized = 6,
+
+    /// No contract upgrade is queued, or one is already queued
+    InvalidUpgrade = 7,
+
+    /// The queued contract upgrade is still timelocked
+    UpgradeLocked = 8,
+
+    /// Storage has already been migrated to the current version
+    AlreadyMigrated = 9,
 } 
\ No newline at end of file
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
 use crate::Asset;
 
 /// Events emitted by the TrustBridge Oracle contract
@@ -28,4 +28,36 @@ pub fn admin_changed(e: &Env, old_admin: Address, new_admin: Address) {
             (old_admin, new_admin)
         );
     }
-} 
\ No newline at end of file
+
+    /// Emitted when a contract upgrade is queued
+    pub fn upgrade_queued(e: &Env, wasm_hash: BytesN<32>, unlock_time: u64) {
+        e.events().publish(
+            (Symbol::new(e, "upgrade_queued"),),
+            (wasm_hash, unlock_time)
+        );
+    }
+
+    /// Emitted when a queued contract upgrade is cancelled
+    pub fn upgrade_cancelled(e: &Env, wasm_hash: BytesN<32>) {
+        e.events().publish(
+            (Symbol::new(e, "upgrade_cancelled"),),
+            wasm_hash
+        );
+    }
+
+    /// Emitted when the contract is upgraded
+    pub fn upgraded(e: &Env, wasm_hash: BytesN<32>) {
+        e.events().publish(
+            (Symbol::new(e, "upgraded"),),
+            wasm_hash
+        );
+    }
+
+    /// Emitted when storage is migrated after an upgrade
+    pub fn storage_migrated(e: &Env, from_version: u32, to_version: u32) {
+        e.events().publish(
+            (Symbol::new(e, "storage_migrated"),),
+            (from_version, to_version)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, panic_with_error, Address, Env, Symbol,
+    contract, contractimpl, contracttype, panic_with_error, Address, BytesN, Env, Symbol,
 };
 
 mod storage;
@@ -11,6 +11,12 @@
 pub use error::OracleError;
 pub use events::OracleEvents;
 
+/// The time in seconds a queued upgrade is locked for (7 days)
+pub const UPGRADE_LOCK_TIME: u64 = 7 * 24 * 60 * 60;
+
+/// The version of the oracle's storage layout. Bump when an upgrade requires a storage migration.
+pub const STORAGE_VERSION: u32 = 1;
+
 // SEP-40 PriceData structure
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,6 +33,14 @@ pub enum Asset {
     Other(Symbol),        // Other asset identifier
 }
 
+// A contract upgrade waiting for its timelock to expire
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedUpgrade {
+    pub wasm_hash: BytesN<32>, // Hash of the uploaded wasm to upgrade to
+    pub unlock_time: u64,      // Earliest time the upgrade can be applied
+}
+
 /// TrustBridge Oracle Contract
 /// 
 /// Implements SEP-40 Oracle interface for providing price feeds
@@ -82,6 +96,31 @@ pub trait OracleTrait {
     /// ### Arguments
     /// * `new_admin` - The new admin address
     fn set_admin(e: Env, new_admin: Address);
+
+    /// Queue an upgrade of the oracle's wasm (admin only). The upgrade unlocks after
+    /// `UPGRADE_LOCK_TIME` so users can react before it is applied.
+    /// 
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the uploaded wasm to upgrade to
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>);
+
+    /// Cancel the queued upgrade (admin only)
+    fn cancel_upgrade(e: Env);
+
+    /// Apply the queued upgrade once its timelock has expired
+    /// 
+    /// ### Returns
+    /// * `BytesN<32>` - The hash of the new wasm
+    fn upgrade(e: Env) -> BytesN<32>;
+
+    /// Migrate storage to `STORAGE_VERSION` after an upgrade
+    fn migrate(e: Env);
+
+    /// Get the queued upgrade, if any
+    /// 
+    /// ### Returns
+    /// * `Option<QueuedUpgrade>` - The queued upgrade or None
+    fn queued_upgrade(e: Env) -> Option<QueuedUpgrade>;
 }
 
 #[contractimpl]
@@ -162,6 +201,73 @@ fn set_admin(e: Env, new_admin: Address) {
         
         OracleEvents::admin_changed(&e, current_admin, new_admin);
     }
+
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>) {
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        if storage::get_queued_upgrade(&e).is_some() {
+            panic_with_error!(&e, OracleError::InvalidUpgrade);
+        }
+
+        let unlock_time = e.ledger().timestamp() + UPGRADE_LOCK_TIME;
+        storage::set_queued_upgrade(
+            &e,
+            &QueuedUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                unlock_time,
+            },
+        );
+
+        OracleEvents::upgrade_queued(&e, wasm_hash, unlock_time);
+    }
+
+    fn cancel_upgrade(e: Env) {
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        let queued_upgrade = match storage::get_queued_upgrade(&e) {
+            Some(queued_upgrade) => queued_upgrade,
+            None => panic_with_error!(&e, OracleError::InvalidUpgrade),
+        };
+        storage::remove_queued_upgrade(&e);
+
+        OracleEvents::upgrade_cancelled(&e, queued_upgrade.wasm_hash);
+    }
+
+    fn upgrade(e: Env) -> BytesN<32> {
+        let queued_upgrade = match storage::get_queued_upgrade(&e) {
+            Some(queued_upgrade) => queued_upgrade,
+            None => panic_with_error!(&e, OracleError::InvalidUpgrade),
+        };
+        if queued_upgrade.unlock_time > e.ledger().timestamp() {
+            panic_with_error!(&e, OracleError::UpgradeLocked);
+        }
+
+        storage::remove_queued_upgrade(&e);
+        e.deployer()
+            .update_current_contract_wasm(queued_upgrade.wasm_hash.clone());
+
+        OracleEvents::upgraded(&e, queued_upgrade.wasm_hash.clone());
+        queued_upgrade.wasm_hash
+    }
+
+    fn migrate(e: Env) {
+        let version = storage::get_storage_version(&e);
+        if version >= STORAGE_VERSION {
+            panic_with_error!(&e, OracleError::AlreadyMigrated);
+        }
+
+        // storage migrations for each version after `version` are applied here, in order
+
+        storage::set_storage_version(&e, STORAGE_VERSION);
+
+        OracleEvents::storage_migrated(&e, version, STORAGE_VERSION);
+    }
+
+    fn queued_upgrade(e: Env) -> Option<QueuedUpgrade> {
+        storage::get_queued_upgrade(&e)
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 use soroban_sdk::{Address, Env, Symbol};
-use crate::{Asset, PriceData};
+use crate::{Asset, PriceData, QueuedUpgrade};
 
 // Storage key constants
 const ADMIN_KEY: &str = "admin";
 const PRICE_KEY: &str = "price";
+const UPGRADE_KEY: &str = "upgrade";
+const VERSION_KEY: &str = "version";
 
 // TTL constants (in ledgers)
 const ONE_DAY_LEDGERS: u32 = 17280; // Assuming 5 seconds per ledger
@@ -74,4 +76,40 @@ pub fn get_price(e: &Env, asset: &Asset) -> Option<PriceData> {
     } else {
         None
     }
-} 
\ No newline at end of file
+}
+
+/// Get the queued contract upgrade, if any
+pub fn get_queued_upgrade(e: &Env) -> Option<QueuedUpgrade> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, UPGRADE_KEY))
+}
+
+/// Set the queued contract upgrade
+pub fn set_queued_upgrade(e: &Env, upgrade: &QueuedUpgrade) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, UPGRADE_KEY), upgrade);
+}
+
+/// Remove the queued contract upgrade
+pub fn remove_queued_upgrade(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, UPGRADE_KEY));
+}
+
+/// Get the version of the oracle's storage layout
+pub fn get_storage_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, VERSION_KEY))
+        .unwrap_or(1)
+}
+
+/// Set the version of the oracle's storage layout
+pub fn set_storage_version(e: &Env, version: u32) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, VERSION_KEY), &version);
+}
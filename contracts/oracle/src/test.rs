@@ -3,7 +3,7 @@
 use super::*;
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
-    Address, Env, Vec,
+    Address, BytesN, Env, Vec,
 };
 
 fn create_test_env() -> (Env, Address, Address) {
@@ -177,4 +177,66 @@ fn test_non_admin_cannot_set_price() {
     // This should work since we're mocking all auths
     // In real scenario, this would fail without proper authorization
     client.set_price(&asset, &10_000_000);
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_queue_and_cancel_upgrade() {
+    let (e, admin, contract_id) = create_test_env();
+    let client = TrustBridgeOracleClient::new(&e, &contract_id);
+
+    client.init(&admin);
+
+    let wasm_hash = BytesN::from_array(&e, &[1; 32]);
+    client.queue_upgrade(&wasm_hash);
+
+    let queued_upgrade = client.queued_upgrade().unwrap();
+    assert_eq!(queued_upgrade.wasm_hash, wasm_hash);
+    assert_eq!(queued_upgrade.unlock_time, 1234567890 + UPGRADE_LOCK_TIME);
+
+    client.cancel_upgrade();
+    assert_eq!(client.queued_upgrade(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_queue_upgrade_twice_fails() {
+    let (e, admin, contract_id) = create_test_env();
+    let client = TrustBridgeOracleClient::new(&e, &contract_id);
+
+    client.init(&admin);
+
+    client.queue_upgrade(&BytesN::from_array(&e, &[1; 32]));
+    client.queue_upgrade(&BytesN::from_array(&e, &[2; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_upgrade_before_unlock_fails() {
+    let (e, admin, contract_id) = create_test_env();
+    let client = TrustBridgeOracleClient::new(&e, &contract_id);
+
+    client.init(&admin);
+    client.queue_upgrade(&BytesN::from_array(&e, &[1; 32]));
+
+    e.ledger().set_timestamp(1234567890 + UPGRADE_LOCK_TIME - 1);
+    client.upgrade();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_upgrade_without_queue_fails() {
+    let (e, admin, contract_id) = create_test_env();
+    let client = TrustBridgeOracleClient::new(&e, &contract_id);
+
+    client.init(&admin);
+    client.upgrade();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_migrate_current_version_fails() {
+    let (e, admin, contract_id) = create_test_env();
+    let client = TrustBridgeOracleClient::new(&e, &contract_id);
+
+    client.init(&admin);
+    client.migrate();
+}
@@ -4,8 +4,8 @@
     storage::{self, PoolInitMeta},
 };
 use soroban_sdk::{
-    contract, contractclient, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, IntoVal,
-    String, symbol_short, vec, Vec,
+    contract, contractclient, contractimpl, panic_with_error, vec, Address, Bytes, BytesN, Env,
+    IntoVal, String, Val, Vec,
 };
 
 const SCALAR_7: u32 = 1_0000000;
@@ -77,9 +77,9 @@ fn deploy(
             panic_with_error!(&e, PoolFactoryError::InvalidPoolInitArgs);
         }
 
-        // verify max positions is at least 2 and less than 64
-        // pools have a max of 30 reserves, so 60 is the max number of positions
-        if max_positions < 2 || max_positions > 60 {
+        // verify max positions is at least 2 and at most 100
+        // pools have a max of 50 reserves, so 100 is the max number of positions
+        if !(2..=100).contains(&max_positions) {
             panic_with_error!(&e, PoolFactoryError::InvalidPoolInitArgs);
         }
 
@@ -95,23 +95,19 @@ fn deploy(
         salt_as_bytes.extend_from_array(&as_u8s);
         let new_salt = e.crypto().keccak256(&salt_as_bytes);
 
-        let pool_address = e.deployer().with_current_contract(new_salt).deploy(
-            pool_init_meta.pool_hash
-        );
-
-        // Initialize the pool after deployment
-        let mut init_args = vec![&e];
-        init_args.push_back(admin.clone().into_val(&e));
-        init_args.push_back(name.clone().into_val(&e));
-        init_args.push_back(oracle.clone().into_val(&e));
+        let mut init_args: Vec<Val> = vec![&e];
+        init_args.push_back(admin.into_val(&e));
+        init_args.push_back(name.into_val(&e));
+        init_args.push_back(oracle.into_val(&e));
         init_args.push_back(backstop_take_rate.into_val(&e));
         init_args.push_back(max_positions.into_val(&e));
         init_args.push_back(min_collateral.into_val(&e));
-        init_args.push_back(pool_init_meta.backstop.clone().into_val(&e));
-        init_args.push_back(pool_init_meta.blnd_id.clone().into_val(&e));
-        
-        // Call init function on the deployed pool
-        e.invoke_contract::<()>(&pool_address, &symbol_short!("init"), init_args);
+        init_args.push_back(pool_init_meta.backstop.into_val(&e));
+        init_args.push_back(pool_init_meta.blnd_id.into_val(&e));
+        let pool_address = e
+            .deployer()
+            .with_current_contract(new_salt)
+            .deploy_v2(pool_init_meta.pool_hash, init_args);
 
         storage::set_deployed(&e, &pool_address);
 
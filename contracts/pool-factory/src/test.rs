@@ -8,7 +8,7 @@
 use crate::{PoolFactoryClient, PoolFactoryContract, PoolInitMeta};
 
 mod pool {
-    soroban_sdk::contractimport!(file = "../target/wasm32-unknown-unknown/optimized/pool.wasm");
+    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/optimized/pool.wasm");
 }
 
 #[test]
@@ -95,8 +95,8 @@ fn test_pool_factory() {
                 .get::<_, pool::PoolConfig>(&Symbol::new(&e, "Config"))
                 .unwrap(),
             pool::PoolConfig {
-                oracle: oracle,
-                min_collateral: min_collateral,
+                oracle,
+                min_collateral,
                 bstop_rate: backstop_rate,
                 status: 6,
                 max_positions: 6
@@ -216,7 +216,7 @@ fn test_pool_factory_invalid_pool_init_args_max_positions_large() {
     let bombadil = Address::generate(&e);
     let oracle = Address::generate(&e);
     let backstop_rate: u32 = 0_1000000;
-    let max_positions: u32 = 61;
+    let max_positions: u32 = 101;
     let min_collateral: i128 = 1_0000000;
 
     let name1 = String::from_str(&e, "pool1");
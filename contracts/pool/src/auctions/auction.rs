@@ -1,12 +1,14 @@
 use crate::{
-    constants::SCALAR_7,
+    constants::{AUCTION_PAGE_SIZE, SCALAR_7},
+    emissions,
     errors::PoolError,
+    events::PoolEvents,
     pool::{Pool, User},
     storage,
 };
 use cast::i128;
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{contracttype, map, panic_with_error, Address, Env, Map, Vec};
+use soroban_sdk::{contracttype, map, panic_with_error, vec, Address, Env, Map, Vec};
 
 use super::{
     backstop_interest_auction::{create_interest_auction_data, fill_interest_auction},
@@ -42,7 +44,7 @@ pub struct AuctionData {
     /// The bid is different based on each auction type:
     /// - UserLiquidation: dTokens
     /// - BadDebtAuction: dTokens
-    /// - InterestAuction: Underlying assets (backstop token)
+    /// - InterestAuction: Underlying assets (backstop token or USDC)
     pub bid: Map<Address, i128>,
     /// A map of the assets being auctioned off and the amount being auctioned. These are tokens
     /// received by the filler of the auction.
@@ -57,6 +59,18 @@ pub struct AuctionData {
     pub block: u32,
 }
 
+/// An active auction, scaled to the current block
+#[derive(Clone)]
+#[contracttype]
+pub struct ActiveAuction {
+    /// The user involved in the auction
+    pub user: Address,
+    /// The auction data scaled to the current block, as if the auction was fully filled
+    pub scaled: AuctionData,
+    /// If the auction is stale and can be deleted
+    pub is_stale: bool,
+}
+
 /// Create a new auction. Stores the resulting auction to the ledger to begin on the next block.
 ///
 /// Returns the AuctionData object created
@@ -90,6 +104,7 @@ pub fn create_auction(
         AuctionType::InterestAuction => create_interest_auction_data(e, user, bid, lot, percent),
     };
     storage::set_auction(e, &auction_type, user, &auction_data);
+    add_to_auction_list(e, auction_type, user);
     auction_data
 }
 
@@ -101,11 +116,109 @@ pub fn delete_stale_auction(e: &Env, auction_type: u32, user: &Address) {
 
     let auction = storage::get_auction(e, &auction_type, user);
     // require auction is stale (older than 500 blocks)
-    if auction.block + 500 > e.ledger().sequence() {
+    if !is_stale(e, &auction) {
         panic_with_error!(e, PoolError::BadRequest);
     }
 
     storage::del_auction(e, &auction_type, user);
+    remove_from_auction_list(e, auction_type, user);
+}
+
+/// Delete the stale auctions of a type, checking at most `count` users in the index of users
+/// with an auction of the type, starting at `start`. The keeper is rewarded if any auctions
+/// were deleted.
+///
+/// Users removed from the index are replaced by the last user in the index, so the same
+/// position is checked again.
+///
+/// Returns the number of auctions deleted
+///
+/// ### Arguments
+/// * `keeper` - The address of the keeper deleting the auctions
+/// * `auction_type` - The type of auction
+/// * `start` - The position in the index to start at
+/// * `count` - The maximum number of users to check
+///
+/// ### Panics
+/// If the auction type is invalid
+pub fn execute_delete_stale_auctions(
+    e: &Env,
+    keeper: &Address,
+    auction_type: u32,
+    start: u32,
+    count: u32,
+) -> u32 {
+    AuctionType::from_u32(e, auction_type);
+    let mut deleted: u32 = 0;
+    let mut index = start;
+    let mut checked: u32 = 0;
+    while checked < count && index < storage::get_auction_count(e, &auction_type) {
+        let user = storage::get_auction_page(e, &auction_type, index / AUCTION_PAGE_SIZE)
+            .get_unchecked(index % AUCTION_PAGE_SIZE);
+        checked += 1;
+        // auctions that expired from the ledger are dropped from the index
+        if !storage::has_auction(e, &auction_type, &user) {
+            remove_from_auction_list(e, auction_type, &user);
+        } else if is_stale(e, &storage::get_auction(e, &auction_type, &user)) {
+            storage::del_auction(e, &auction_type, &user);
+            remove_from_auction_list(e, auction_type, &user);
+            PoolEvents::delete_auction(e, auction_type, user);
+            deleted += 1;
+        } else {
+            index += 1;
+        }
+    }
+
+    if deleted > 0 {
+        emissions::pay_keeper_reward(e, keeper);
+    }
+    deleted
+}
+
+/// Load the active auctions of a type, scaled to the current block, starting at `start` in
+/// the index of users with an auction of the type and returning at most `count` auctions
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `start` - The position in the index to start at
+/// * `count` - The maximum number of auctions to load
+///
+/// ### Panics
+/// If the auction type is invalid
+pub fn load_active_auctions(
+    e: &Env,
+    auction_type: u32,
+    start: u32,
+    count: u32,
+) -> Vec<ActiveAuction> {
+    AuctionType::from_u32(e, auction_type);
+    let end = storage::get_auction_count(e, &auction_type).min(start.saturating_add(count));
+    let mut active_auctions: Vec<ActiveAuction> = vec![e];
+    let mut page_index = u32::MAX;
+    let mut page: Vec<Address> = vec![e];
+    for index in start..end {
+        if index / AUCTION_PAGE_SIZE != page_index {
+            page_index = index / AUCTION_PAGE_SIZE;
+            page = storage::get_auction_page(e, &auction_type, page_index);
+        }
+        let user = page.get_unchecked(index % AUCTION_PAGE_SIZE);
+        if !storage::has_auction(e, &auction_type, &user) {
+            continue;
+        }
+        let auction_data = storage::get_auction(e, &auction_type, &user);
+        // auctions don't begin until the block after they are created
+        let scaled = if e.ledger().sequence() < auction_data.block {
+            auction_data.clone()
+        } else {
+            scale_auction(e, &auction_data, 100).0
+        };
+        active_auctions.push_back(ActiveAuction {
+            user,
+            scaled,
+            is_stale: is_stale(e, &auction_data),
+        });
+    }
+    active_auctions
 }
 
 /// Delete a liquidation auction if the user being liquidated
@@ -123,6 +236,7 @@ pub fn delete_liquidation(e: &Env, user: &Address) {
         panic_with_error!(e, PoolError::BadRequest);
     }
     storage::del_auction(e, &(AuctionType::UserLiquidation as u32), user);
+    remove_from_auction_list(e, AuctionType::UserLiquidation as u32, user);
 }
 
 /// Fills the auction from the invoker.
@@ -167,8 +281,8 @@ pub fn fill(
         storage::set_auction(e, &auction_type, user, &auction_to_store);
     } else {
         storage::del_auction(e, &auction_type, user);
+        remove_from_auction_list(e, auction_type, user);
     }
-
     to_fill_auction
 }
 
@@ -263,6 +377,53 @@ fn scale_auction(
     }
 }
 
+/// Check if an auction is stale (older than 500 blocks)
+fn is_stale(e: &Env, auction_data: &AuctionData) -> bool {
+    auction_data.block + 500 <= e.ledger().sequence()
+}
+
+/// Add a user to the back of the index of users with an auction of the given type
+fn add_to_auction_list(e: &Env, auction_type: u32, user: &Address) {
+    if storage::get_auction_index(e, &auction_type, user).is_some() {
+        return;
+    }
+    let index = storage::get_auction_count(e, &auction_type);
+    let page_index = index / AUCTION_PAGE_SIZE;
+    let mut page = storage::get_auction_page(e, &auction_type, page_index);
+    page.push_back(user.clone());
+    storage::set_auction_page(e, &auction_type, page_index, &page);
+    storage::set_auction_index(e, &auction_type, user, index);
+    storage::set_auction_count(e, &auction_type, index + 1);
+}
+
+/// Remove a user from the index of users with an auction of the given type. The last user
+/// in the index is moved into the removed user's position.
+fn remove_from_auction_list(e: &Env, auction_type: u32, user: &Address) {
+    let index = match storage::get_auction_index(e, &auction_type, user) {
+        Some(index) => index,
+        None => return,
+    };
+    let last_index = storage::get_auction_count(e, &auction_type) - 1;
+    let last_page_index = last_index / AUCTION_PAGE_SIZE;
+    let mut last_page = storage::get_auction_page(e, &auction_type, last_page_index);
+    let last_user = last_page.pop_back_unchecked();
+
+    if index != last_index {
+        let page_index = index / AUCTION_PAGE_SIZE;
+        if page_index == last_page_index {
+            last_page.set(index % AUCTION_PAGE_SIZE, last_user.clone());
+        } else {
+            let mut page = storage::get_auction_page(e, &auction_type, page_index);
+            page.set(index % AUCTION_PAGE_SIZE, last_user.clone());
+            storage::set_auction_page(e, &auction_type, page_index, &page);
+        }
+        storage::set_auction_index(e, &auction_type, &last_user, index);
+    }
+    storage::set_auction_page(e, &auction_type, last_page_index, &last_page);
+    storage::del_auction_index(e, &auction_type, user);
+    storage::set_auction_count(e, &auction_type, last_index);
+}
+
 /// Require that all addresses in the list are unique
 ///
 /// ### Panics
@@ -535,6 +696,11 @@ fn test_create_interest_auction() {
                 100,
             );
             assert!(storage::has_auction(&e, &2, &backstop_address));
+            assert_eq!(storage::get_auction_count(&e, &2), 1);
+            assert_eq!(
+                storage::get_auction_page(&e, &2, 0),
+                vec![&e, backstop_address.clone()]
+            );
         });
     }
 
@@ -1351,7 +1517,7 @@ fn test_fill() {
             let mut frodo_state = User::load(&e, &frodo);
             fill(&e, &mut pool, 0, &samwise, &mut frodo_state, 100);
             let has_auction = storage::has_auction(&e, &0, &samwise);
-            assert_eq!(has_auction, false);
+            assert!(!has_auction);
         });
     }
 
@@ -1641,7 +1807,7 @@ fn test_partial_partial_full_fill() {
             let mut frodo_state = User::load(&e, &frodo);
             fill(&e, &mut pool, 0, &samwise, &mut frodo_state, 100);
             let new_auction = storage::has_auction(&e, &0, &samwise);
-            assert_eq!(new_auction, false);
+            assert!(!new_auction);
             let samwise_positions = storage::get_user_positions(&e, &samwise);
             assert_eq!(
                 samwise_positions
@@ -1667,6 +1833,128 @@ fn test_partial_partial_full_fill() {
         });
     }
 
+    #[test]
+    fn test_fill_slices_by_many_fillers() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 176 + 250,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let fillers = vec![
+            &e,
+            Address::generate(&e),
+            Address::generate(&e),
+            Address::generate(&e),
+        ];
+
+        let pool_address = create_pool(&e);
+
+        let (oracle_address, _) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, reserve_data_0) = testutils::default_reserve_meta();
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, reserve_data_1) = testutils::default_reserve_meta();
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let auction_data = AuctionData {
+            bid: map![&e, (underlying_1.clone(), 100_000_0003)],
+            lot: map![&e, (underlying_0.clone(), 10_000_0007)],
+            block: 176,
+        };
+        let pool_config = PoolConfig {
+            oracle: oracle_address,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        let positions: Positions = Positions {
+            collateral: map![&e, (reserve_config_0.index, 30_000_0000)],
+            liabilities: map![&e, (reserve_config_1.index, 200_000_0000)],
+            supply: map![&e],
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_user_positions(&e, &samwise, &positions);
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_auction(&e, &0, &samwise, &auction_data);
+
+            // 100% lot mod and 75% bid mod
+            let (full_fill, _) = scale_auction(&e, &auction_data, 100);
+
+            // each filler takes a slice of the remaining auction
+            let mut filled_bid = 0;
+            let mut filled_lot = 0;
+            for (filler, percent) in fillers.iter().zip([20, 50, 100]) {
+                let mut pool = Pool::load(&e);
+                let mut filler_state = User::load(&e, &filler);
+                let filled = fill(&e, &mut pool, 0, &samwise, &mut filler_state, percent);
+                filler_state.store(&e);
+                pool.store_cached_reserves(&e);
+
+                let filler_positions = storage::get_user_positions(&e, &filler);
+                assert_eq!(
+                    filler_positions.collateral.get_unchecked(0),
+                    filled.lot.get_unchecked(underlying_0.clone())
+                );
+                assert_eq!(
+                    filler_positions.liabilities.get_unchecked(1),
+                    filled.bid.get_unchecked(underlying_1.clone())
+                );
+                filled_bid += filled.bid.get_unchecked(underlying_1.clone());
+                filled_lot += filled.lot.get_unchecked(underlying_0.clone());
+                assert_eq!(storage::has_auction(&e, &0, &samwise), percent != 100);
+            }
+
+            // the slices sum to a full fill, with bids rounded up for each slice
+            let full_fill_bid = full_fill.bid.get_unchecked(underlying_1.clone());
+            assert_eq!(
+                filled_lot,
+                full_fill.lot.get_unchecked(underlying_0.clone())
+            );
+            assert!(filled_bid >= full_fill_bid);
+            assert!(filled_bid - full_fill_bid <= 2);
+
+            let samwise_positions = storage::get_user_positions(&e, &samwise);
+            assert_eq!(
+                samwise_positions.collateral.get_unchecked(0),
+                30_000_0000 - filled_lot
+            );
+            assert_eq!(
+                samwise_positions.liabilities.get_unchecked(1),
+                200_000_0000 - filled_bid
+            );
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #1200)")]
     fn test_fill_fails_pct_too_large() {
@@ -2048,11 +2336,226 @@ fn test_delete_stale_auction() {
         e.as_contract(&pool_address, || {
             storage::set_auction(&e, &auction_type, &user, &auction_data);
             let has_auction = storage::has_auction(&e, &auction_type, &user);
-            assert_eq!(has_auction, true);
+            assert!(has_auction);
+
+            add_to_auction_list(&e, auction_type, &user);
 
             delete_stale_auction(&e, auction_type, &user);
             let has_auction = storage::has_auction(&e, &auction_type, &user);
-            assert_eq!(has_auction, false);
+            assert!(!has_auction);
+            assert_eq!(storage::get_auction_count(&e, &auction_type), 0);
+            assert_eq!(storage::get_auction_index(&e, &auction_type, &user), None);
+        });
+    }
+
+    #[test]
+    fn test_load_active_auctions() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 1300,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let pool_address = create_pool(&e);
+        let backstop = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let underlying_0 = Address::generate(&e);
+        let underlying_1 = Address::generate(&e);
+
+        let interest_auction = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 100_0000000)],
+            lot: map![&e, (underlying_1.clone(), 100_0000000)],
+            block: 1000,
+        };
+        let liquidation_auction = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 100_0000000)],
+            lot: map![&e, (underlying_1.clone(), 100_0000000)],
+            block: 1250,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_auction(&e, &2, &backstop, &interest_auction);
+            add_to_auction_list(&e, 2, &backstop);
+            storage::set_auction(&e, &0, &samwise, &liquidation_auction);
+            add_to_auction_list(&e, 0, &samwise);
+
+            // 50 blocks - 25% lot and 100% bid
+            let active_auctions = load_active_auctions(&e, 0, 0, 10);
+            assert_eq!(active_auctions.len(), 1);
+            let active_auction = active_auctions.get_unchecked(0);
+            assert_eq!(active_auction.user, samwise);
+            assert_eq!(
+                active_auction
+                    .scaled
+                    .bid
+                    .get_unchecked(underlying_0.clone()),
+                100_0000000
+            );
+            assert_eq!(
+                active_auction
+                    .scaled
+                    .lot
+                    .get_unchecked(underlying_1.clone()),
+                25_0000000
+            );
+            assert_eq!(active_auction.scaled.block, 1250);
+            assert!(!active_auction.is_stale);
+
+            // 300 blocks - 100% lot and 50% bid
+            let active_auctions = load_active_auctions(&e, 2, 0, 10);
+            assert_eq!(active_auctions.len(), 1);
+            let active_auction = active_auctions.get_unchecked(0);
+            assert_eq!(active_auction.user, backstop);
+            assert_eq!(
+                active_auction
+                    .scaled
+                    .bid
+                    .get_unchecked(underlying_0.clone()),
+                50_0000000
+            );
+            assert_eq!(
+                active_auction
+                    .scaled
+                    .lot
+                    .get_unchecked(underlying_1.clone()),
+                100_0000000
+            );
+            assert!(!active_auction.is_stale);
+
+            assert_eq!(load_active_auctions(&e, 1, 0, 10).len(), 0);
+        });
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 1500,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+        e.as_contract(&pool_address, || {
+            let active_auction = load_active_auctions(&e, 2, 0, 10).get_unchecked(0);
+            assert_eq!(active_auction.scaled.bid.len(), 0);
+            assert!(active_auction.is_stale);
+        });
+    }
+
+    #[test]
+    fn test_execute_delete_stale_auctions() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 1600,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let pool_address = create_pool(&e);
+        let backstop = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let keeper = Address::generate(&e);
+        let underlying_0 = Address::generate(&e);
+        let underlying_1 = Address::generate(&e);
+
+        let stale_auction = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 100_0000000)],
+            lot: map![&e, (underlying_1.clone(), 100_0000000)],
+            block: 1000,
+        };
+        let active_auction = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 100_0000000)],
+            lot: map![&e, (underlying_1.clone(), 100_0000000)],
+            block: 1500,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_auction(&e, &2, &backstop, &stale_auction);
+            add_to_auction_list(&e, 2, &backstop);
+            storage::set_auction(&e, &0, &samwise, &active_auction);
+            storage::set_auction(&e, &0, &merry, &stale_auction);
+            add_to_auction_list(&e, 0, &samwise);
+            // frodo's auction expired from the ledger
+            add_to_auction_list(&e, 0, &frodo);
+            add_to_auction_list(&e, 0, &merry);
+
+            let deleted = execute_delete_stale_auctions(&e, &keeper, 2, 0, 10);
+            assert_eq!(deleted, 1);
+            assert!(!storage::has_auction(&e, &2, &backstop));
+            assert_eq!(storage::get_auction_count(&e, &2), 0);
+
+            // only check frodo, who is dropped and replaced by merry
+            let deleted = execute_delete_stale_auctions(&e, &keeper, 0, 1, 1);
+            assert_eq!(deleted, 0);
+            assert_eq!(storage::get_auction_count(&e, &0), 2);
+            assert_eq!(
+                storage::get_auction_page(&e, &0, 0),
+                vec![&e, samwise.clone(), merry.clone()]
+            );
+            assert_eq!(storage::get_auction_index(&e, &0, &merry), Some(1));
+            assert_eq!(storage::get_auction_index(&e, &0, &frodo), None);
+
+            let deleted = execute_delete_stale_auctions(&e, &keeper, 0, 0, 10);
+            assert_eq!(deleted, 1);
+            assert!(!storage::has_auction(&e, &0, &merry));
+            assert!(storage::has_auction(&e, &0, &samwise));
+            assert_eq!(storage::get_auction_count(&e, &0), 1);
+            assert_eq!(
+                storage::get_auction_page(&e, &0, 0),
+                vec![&e, samwise.clone()]
+            );
+
+            // nothing left to delete
+            assert_eq!(execute_delete_stale_auctions(&e, &keeper, 0, 0, 10), 0);
+        });
+    }
+
+    #[test]
+    fn test_auction_list_pages() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let pool_address = create_pool(&e);
+        let mut users: Vec<Address> = vec![&e];
+        e.as_contract(&pool_address, || {
+            for _ in 0..(AUCTION_PAGE_SIZE + 5) {
+                let user = Address::generate(&e);
+                add_to_auction_list(&e, 0, &user);
+                users.push_back(user);
+            }
+            assert_eq!(storage::get_auction_count(&e, &0), AUCTION_PAGE_SIZE + 5);
+            assert_eq!(storage::get_auction_page(&e, &0, 1).len(), 5);
+
+            // adding a user twice does not index them twice
+            add_to_auction_list(&e, 0, &users.get_unchecked(0));
+            assert_eq!(storage::get_auction_count(&e, &0), AUCTION_PAGE_SIZE + 5);
+
+            // removing from the first page moves the last user into its position
+            let last = users.get_unchecked(AUCTION_PAGE_SIZE + 4);
+            remove_from_auction_list(&e, 0, &users.get_unchecked(1));
+            assert_eq!(storage::get_auction_count(&e, &0), AUCTION_PAGE_SIZE + 4);
+            assert_eq!(storage::get_auction_page(&e, &0, 0).get_unchecked(1), last);
+            assert_eq!(storage::get_auction_page(&e, &0, 1).len(), 4);
+            assert_eq!(storage::get_auction_index(&e, &0, &last), Some(1));
+            assert_eq!(
+                storage::get_auction_index(&e, &0, &users.get_unchecked(1)),
+                None
+            );
         });
     }
 
@@ -2517,7 +3020,7 @@ fn test_delete_stale_auction_not_stale() {
         e.as_contract(&pool_address, || {
             storage::set_auction(&e, &auction_type, &user, &auction_data);
             let has_auction = storage::has_auction(&e, &auction_type, &user);
-            assert_eq!(has_auction, true);
+            assert!(has_auction);
 
             delete_stale_auction(&e, auction_type, &user);
         });
@@ -2555,7 +3058,7 @@ fn test_delete_stale_auction_does_not_exist() {
         e.as_contract(&pool_address, || {
             storage::set_auction(&e, &auction_type, &user, &auction_data);
             let has_auction = storage::has_auction(&e, &auction_type, &user);
-            assert_eq!(has_auction, true);
+            assert!(has_auction);
 
             delete_stale_auction(&e, 0, &user);
         });
@@ -2810,6 +3313,54 @@ fn test_scale_auction_not_100_fill_pct() {
         );
     }
 
+    #[test]
+    fn test_scale_auction_slices_sum_to_full_fill() {
+        let e = Env::default();
+        let underlying_0 = Address::generate(&e);
+        let underlying_1 = Address::generate(&e);
+
+        let base_auction_data = AuctionData {
+            bid: map![&e, (underlying_0.clone(), 1234_5678901)],
+            lot: map![&e, (underlying_1.clone(), 987_6543217)],
+            block: 1000,
+        };
+
+        let slices = [10, 25, 33, 50, 100];
+        for block_dif in [100, 250] {
+            e.ledger().set(LedgerInfo {
+                timestamp: 12345,
+                protocol_version: 22,
+                sequence_number: 1000 + block_dif,
+                network_id: Default::default(),
+                base_reserve: 10,
+                min_temp_entry_ttl: 172800,
+                min_persistent_entry_ttl: 172800,
+                max_entry_ttl: 9999999,
+            });
+            let (full_fill, _) = scale_auction(&e, &base_auction_data, 100);
+
+            let mut remaining_auction = base_auction_data.clone();
+            let mut sum_bid = 0;
+            let mut sum_lot = 0;
+            for percent in slices {
+                let (scaled_auction, remaining_auction_option) =
+                    scale_auction(&e, &remaining_auction, percent);
+                sum_bid += scaled_auction.bid.get(underlying_0.clone()).unwrap_or(0);
+                sum_lot += scaled_auction.lot.get(underlying_1.clone()).unwrap_or(0);
+                match remaining_auction_option {
+                    Some(remaining) => remaining_auction = remaining,
+                    None => assert_eq!(percent, 100),
+                }
+            }
+
+            // bids round up and lots round down by at most 1 per slice
+            let full_bid = full_fill.bid.get_unchecked(underlying_0.clone());
+            let full_lot = full_fill.lot.get_unchecked(underlying_1.clone());
+            assert!(sum_bid >= full_bid && sum_bid - full_bid <= slices.len() as i128);
+            assert!(sum_lot <= full_lot && full_lot - sum_lot <= slices.len() as i128);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #1200)")]
     fn test_scale_auction_fill_percentage_zero() {
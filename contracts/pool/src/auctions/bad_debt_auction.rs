@@ -44,6 +44,7 @@ pub fn create_bad_debt_auction_data(
 
     let oracle_scalar = 10i128.pow(pool.load_price_decimals(e));
     let backstop_positions = storage::get_user_positions(e, &backstop);
+    // expressed in the oracle's base asset and decimals, as the lot is valued in USDC
     let mut debt_value = 0;
     for bid_asset in bid {
         let reserve = pool.load_reserve(e, &bid_asset, false);
@@ -52,9 +53,9 @@ pub fn create_bad_debt_auction_data(
             .get(reserve.config.index)
             .unwrap_or(0);
         if liability_balance > 0 {
-            let asset_to_base = pool.load_price(e, &reserve.asset);
+            let asset_to_usd = pool.load_oracle_price(e, &reserve.asset);
             let asset_balance = reserve.to_asset_from_d_token(e, liability_balance);
-            debt_value += i128(asset_to_base).fixed_mul_floor(e, &asset_balance, &reserve.scalar);
+            debt_value += i128(asset_to_usd).fixed_mul_floor(e, &asset_balance, &reserve.scalar);
             auction_data.bid.set(reserve.asset, liability_balance);
         } else {
             panic_with_error!(e, PoolError::InvalidBid);
@@ -72,21 +73,22 @@ pub fn create_bad_debt_auction_data(
         panic_with_error!(e, PoolError::InvalidLot);
     }
 
-    // get value of backstop_token (BLND-USDC LP token) to base
+    // get value of backstop_token (BLND-USDC LP token) to USDC
     let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
 
-    if pool_backstop_data.tokens <= 0 {
-        // no tokens left in backstop to auction off
+    if pool_backstop_data.draw_capacity <= 0 {
+        // no tokens left in backstop, or no draw capacity left this window, to auction off
         panic_with_error!(e, PoolError::InvalidLot);
     }
 
-    // determine lot amount of backstop tokens needed to safely cover bad debt, or post
-    // all backstop tokens if there isn't enough to cover the bad debt. backstop tokens use 7 decimals
+    // determine lot amount of backstop tokens needed to safely cover bad debt, or post all
+    // backstop tokens the pool can currently draw if there isn't enough to cover the bad debt.
+    // draw_capacity never exceeds the backstop's tokens. backstop tokens use 7 decimals
     let mut lot_amount =
         debt_value // oracle_scalar
             .fixed_mul_floor(e, &1_2000000, &oracle_scalar) // denom of oracle_scalar means result is SCALAR_7
             .fixed_div_floor(e, &pool_backstop_data.token_spot_price, &SCALAR_7); // token_spot_price is SCALAR_7
-    lot_amount = pool_backstop_data.tokens.min(lot_amount);
+    lot_amount = pool_backstop_data.draw_capacity.min(lot_amount);
     auction_data.lot.set(backstop_token, lot_amount);
 
     auction_data
@@ -112,7 +114,13 @@ pub fn fill_bad_debt_auction(
 
     let backstop_client = BackstopClient::new(e, &backstop_address);
     let backstop_token_id = backstop_client.backstop_token();
-    let lot_amount = auction_data.lot.get(backstop_token_id).unwrap_or(0);
+    let mut lot_amount = auction_data.lot.get(backstop_token_id).unwrap_or(0);
+    if lot_amount > 0 {
+        // the pool's draw capacity can shrink after the auction is created, so only pay out
+        // what can still be drawn to keep the auction fillable
+        let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
+        lot_amount = pool_backstop_data.draw_capacity.min(lot_amount);
+    }
     if lot_amount > 0 {
         backstop_client.draw(
             &e.current_contract_address(),
@@ -137,6 +145,7 @@ mod tests {
         storage::PoolConfig,
         testutils::{self, create_pool},
     };
+    use backstop::DrawLimit;
 
     use super::*;
     use sep_40_oracle::testutils::Asset;
@@ -1073,6 +1082,141 @@ fn test_create_bad_debt_auction() {
         });
     }
 
+    #[test]
+    fn test_create_bad_debt_auction_non_usd_base_asset() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 50,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
+        reserve_data_2.b_rate = 1_100_000_000_000;
+        reserve_data_2.last_time = 12345;
+        reserve_config_2.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_2,
+            &reserve_config_2,
+            &reserve_data_2,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(underlying_2.clone()),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![
+                &e,
+                (reserve_config_0.index, 10_0000000),
+                (reserve_config_1.index, 2_5000000)
+            ],
+            supply: map![&e],
+        };
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 3,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            // the lot is valued in USDC, so the pool's base asset must not change the auction
+            storage::set_base_asset(&e, &underlying_2);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            let result = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
+                &vec![&e, lp_token.clone()],
+                100,
+            );
+
+            assert_eq!(result.block, 51);
+            assert_eq!(result.bid.get_unchecked(underlying_0), 10_0000000);
+            assert_eq!(result.bid.get_unchecked(underlying_1), 2_5000000);
+            assert_eq!(result.bid.len(), 2);
+            assert_eq!(result.lot.get_unchecked(lp_token), 32_6400000);
+            assert_eq!(result.lot.len(), 1);
+        });
+    }
+
     #[test]
     fn test_create_bad_debt_auction_oracle_14_decimals() {
         let e = Env::default();
@@ -1830,7 +1974,7 @@ fn test_fill_bad_debt_auction() {
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e, (underlying_0, 10_0000000), (underlying_1, 2_5000000)],
             lot: map![&e, (lp_token.clone(), 47_6000000)],
             block: 51,
@@ -1857,7 +2001,7 @@ fn test_fill_bad_debt_auction() {
 
             let mut pool = Pool::load(&e);
             let mut samwise_state = User::load(&e, &samwise);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state, true);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, true);
             assert_eq!(
                 lp_token_client.balance(&backstop_address),
                 50_000_0000000 - 47_6000000
@@ -1883,6 +2027,177 @@ fn test_fill_bad_debt_auction() {
         });
     }
 
+    #[test]
+    fn test_create_and_fill_bad_debt_auction_under_draw_limit() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited(); // setup exhausts budget
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 50,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool_address = create_pool(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc, usdc_client) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) =
+            testutils::create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &lp_token, &usdc, &blnd);
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_address, &50_000_0000000);
+        backstop_client.set_draw_limit(
+            &pool_address,
+            &Some(DrawLimit {
+                max_draw: 20_0000000,
+                window: 24 * 60 * 60,
+            }),
+        );
+
+        let (oracle_id, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
+        reserve_data_0.d_rate = 1_100_000_000_000;
+        reserve_data_0.last_time = 12345;
+        reserve_config_0.index = 0;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_0,
+            &reserve_config_0,
+            &reserve_data_0,
+        );
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_1, mut reserve_data_1) = testutils::default_reserve_meta();
+        reserve_data_1.d_rate = 1_200_000_000_000;
+        reserve_data_1.last_time = 12345;
+        reserve_config_1.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_1,
+            &reserve_config_1,
+            &reserve_data_1,
+        );
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config_2, mut reserve_data_2) = testutils::default_reserve_meta();
+        reserve_data_2.b_rate = 1_100_000_000_000;
+        reserve_data_2.last_time = 12345;
+        reserve_config_2.index = 1;
+        testutils::create_reserve(
+            &e,
+            &pool_address,
+            &underlying_2,
+            &reserve_config_2,
+            &reserve_data_2,
+        );
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+                Asset::Stellar(underlying_2),
+                Asset::Stellar(usdc),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 4_0000000, 100_0000000, 1_0000000]);
+
+        let positions: Positions = Positions {
+            collateral: map![&e],
+            liabilities: map![
+                &e,
+                (reserve_config_0.index, 10_0000000),
+                (reserve_config_1.index, 2_5000000)
+            ],
+            supply: map![&e],
+        };
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 3,
+        };
+        e.as_contract(&pool_address, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &backstop_address, &positions);
+
+            // the debt is worth 32.64 backstop tokens, but only 20 can be drawn this window
+            let auction_data = create_bad_debt_auction_data(
+                &e,
+                &backstop_address,
+                &vec![&e, underlying_0.clone(), underlying_1.clone()],
+                &vec![&e, lp_token.clone()],
+                100,
+            );
+            assert_eq!(auction_data.lot.get_unchecked(lp_token.clone()), 20_0000000);
+            storage::set_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop_address,
+                &auction_data,
+            );
+        });
+
+        // the pool draws from the backstop before the auction is filled
+        backstop_client.draw(&pool_address, &5_0000000, &bombadil);
+
+        e.as_contract(&pool_address, || {
+            let auction_data =
+                storage::get_auction(&e, &(AuctionType::BadDebtAuction as u32), &backstop_address);
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, true);
+            assert_eq!(
+                lp_token_client.balance(&backstop_address),
+                50_000_0000000 - 20_0000000
+            );
+            assert_eq!(lp_token_client.balance(&samwise), 15_0000000);
+            assert_eq!(
+                samwise_state
+                    .positions
+                    .liabilities
+                    .get_unchecked(reserve_config_0.index),
+                10_0000000
+            );
+            assert_eq!(
+                samwise_state
+                    .positions
+                    .liabilities
+                    .get_unchecked(reserve_config_1.index),
+                2_5000000
+            );
+        });
+    }
+
     #[test]
     fn test_fill_bad_debt_auction_leftover_debt_small_backstop_burns() {
         let e = Env::default();
@@ -1967,7 +2282,7 @@ fn test_fill_bad_debt_auction_leftover_debt_small_backstop_burns() {
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_0.clone(), 10_0000000 - 2_5000000),
@@ -2002,7 +2317,7 @@ fn test_fill_bad_debt_auction_leftover_debt_small_backstop_burns() {
             let pre_fill_b_rate_1 = reserve_data_1.b_rate;
             let mut pool = Pool::load(&e);
             let mut samwise_state = User::load(&e, &samwise);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state, true);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, true);
             assert_eq!(
                 lp_token_client.balance(&backstop_address),
                 1_000_0000000 - 47_6000000
@@ -2126,7 +2441,7 @@ fn test_fill_bad_debt_auction_leftover_debt_small_backstop_does_not_burn_if_not_
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_0.clone(), 10_0000000 - 2_5000000),
@@ -2157,7 +2472,7 @@ fn test_fill_bad_debt_auction_leftover_debt_small_backstop_does_not_burn_if_not_
 
             let mut pool = Pool::load(&e);
             let mut samwise_state = User::load(&e, &samwise);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state, false);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, false);
             assert_eq!(
                 lp_token_client.balance(&backstop_address),
                 1_000_0000000 - 47_6000000
@@ -2287,7 +2602,7 @@ fn test_fill_bad_debt_auction_leftover_debt_sufficient_balance() {
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_0.clone(), 10_0000000 - 2_5000000),
@@ -2321,7 +2636,7 @@ fn test_fill_bad_debt_auction_leftover_debt_sufficient_balance() {
             let pre_fill_b_rate_1 = reserve_data_1.b_rate;
             let mut pool = Pool::load(&e);
             let mut samwise_state = User::load(&e, &samwise);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state, true);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, true);
             assert_eq!(
                 lp_token_client.balance(&backstop_address),
                 2_500_0000000 - 47_6000000
@@ -2457,7 +2772,7 @@ fn test_fill_bad_debt_auction_empty_bid() {
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e],
             lot: map![&e, (lp_token.clone(), 47_6000000)],
             block: 51,
@@ -2484,7 +2799,7 @@ fn test_fill_bad_debt_auction_empty_bid() {
 
             let mut pool = Pool::load(&e);
             let mut samwise_state = User::load(&e, &samwise);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut samwise_state, true);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut samwise_state, true);
             assert_eq!(
                 lp_token_client.balance(&backstop_address),
                 50_000_0000000 - 47_6000000
@@ -2596,7 +2911,7 @@ fn test_fill_bad_debt_auction_with_backstop() {
             status: 0,
             max_positions: 4,
         };
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e, (underlying_0, 10_0000000), (underlying_1, 2_5000000)],
             lot: map![&e, (lp_token.clone(), 47_6000000)],
             block: 51,
@@ -2623,7 +2938,7 @@ fn test_fill_bad_debt_auction_with_backstop() {
 
             let mut pool = Pool::load(&e);
             let mut backstop_state = User::load(&e, &backstop_address);
-            fill_bad_debt_auction(&e, &mut pool, &mut auction_data, &mut backstop_state, true);
+            fill_bad_debt_auction(&e, &mut pool, &auction_data, &mut backstop_state, true);
         });
     }
 }
@@ -1,6 +1,10 @@
 mod auction;
 mod backstop_interest_auction;
 mod bad_debt_auction;
+mod soft_liquidation;
 mod user_liquidation_auction;
 
 pub use auction::*;
+pub use soft_liquidation::{
+    execute_set_soft_liquidation, execute_set_soft_liquidation_config, soft_liquidate,
+};
@@ -0,0 +1,393 @@
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{map, panic_with_error, Address, Env};
+
+use crate::{
+    constants::SCALAR_7,
+    errors::PoolError,
+    events::PoolEvents,
+    pool::{Pool, PositionData, User},
+    storage::{self, SoftLiquidationConfig},
+};
+
+use super::{user_liquidation_auction::fill_user_liq_auction, AuctionData, AuctionType};
+
+/// Update the pool's soft liquidation configuration
+///
+/// ### Arguments
+/// * `config` - The new soft liquidation configuration
+///
+/// ### Panics
+/// If the min health factor is not under 1, the discount is over 10%, or the max pct
+/// is over 100%
+pub fn execute_set_soft_liquidation_config(e: &Env, config: &SoftLiquidationConfig) {
+    if config.min_hf < 0
+        || config.min_hf >= SCALAR_7
+        || config.discount < 0
+        || config.discount > 0_1000000
+        || config.max_pct < 0
+        || config.max_pct > SCALAR_7
+    {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+    storage::set_soft_liquidation_config(e, config);
+
+    PoolEvents::set_soft_liquidation_config(e, config.min_hf, config.discount, config.max_pct);
+}
+
+/// Opt in or out of soft liquidations for `user`
+///
+/// `user` MUST be authenticated before calling
+///
+/// ### Arguments
+/// * `user` - The user
+/// * `enabled` - If soft liquidations are enabled for the user
+pub fn execute_set_soft_liquidation(e: &Env, user: &Address, enabled: bool) {
+    storage::set_soft_liquidation(e, user, enabled);
+
+    PoolEvents::set_soft_liquidation(e, user.clone(), enabled);
+}
+
+/// Soft liquidate a user whose health factor is within the pool's soft liquidation band. A
+/// portion of the user's largest liability is moved to the filler along with collateral
+/// worth the liability plus the soft liquidation discount, using the same position transfer
+/// as a user liquidation auction fill.
+///
+/// Returns the liabilities (bid) and collateral (lot) moved to the filler
+///
+/// ### Arguments
+/// * `pool` - The pool
+/// * `user` - The user being soft liquidated
+/// * `filler_state` - The user filling the soft liquidation
+///
+/// ### Panics
+/// * If soft liquidations are disabled for the pool or user
+/// * If the user's health factor is not within the soft liquidation band
+/// * If the user does not have enough collateral in their largest collateral position
+/// * If the soft liquidation does not bring the user's health factor back over 1
+pub fn soft_liquidate(
+    e: &Env,
+    pool: &mut Pool,
+    user: &Address,
+    filler_state: &mut User,
+) -> AuctionData {
+    let config = storage::get_soft_liquidation_config(e);
+    if config.max_pct == 0 || !storage::get_soft_liquidation(e, user) {
+        panic_with_error!(e, PoolError::InvalidLiquidation);
+    }
+    if user == &filler_state.address
+        || user == &e.current_contract_address()
+        || user == &storage::get_backstop(e)
+    {
+        panic_with_error!(e, PoolError::InvalidLiquidation);
+    }
+    if storage::has_auction(e, &(AuctionType::UserLiquidation as u32), user) {
+        panic_with_error!(e, PoolError::AuctionInProgress);
+    }
+
+    let user_state = User::load(e, user);
+    let position_data = PositionData::calculate_from_positions(e, pool, &user_state.positions);
+    if !position_data.is_hf_under(e, SCALAR_7) || position_data.is_hf_under(e, config.min_hf) {
+        panic_with_error!(e, PoolError::InvalidLiquidation);
+    }
+
+    // convert the user's largest liability into their largest collateral
+    let reserve_list = storage::get_res_list(e);
+    let mut liability: Option<(Address, i128, i128)> = None;
+    for (index, d_tokens) in user_state.positions.liabilities.iter() {
+        let asset = reserve_list.get_unchecked(index);
+        let reserve = pool.load_reserve(e, &asset, false);
+        let price = pool.load_price(e, &asset);
+        let base = price.fixed_mul_ceil(
+            e,
+            &reserve.to_asset_from_d_token(e, d_tokens),
+            &reserve.scalar,
+        );
+        if liability.as_ref().is_none_or(|(_, _, max)| base > *max) {
+            liability = Some((asset, d_tokens, base));
+        }
+    }
+    let mut collateral: Option<(Address, i128, i128)> = None;
+    for (index, b_tokens) in user_state.positions.collateral.iter() {
+        let asset = reserve_list.get_unchecked(index);
+        let reserve = pool.load_reserve(e, &asset, false);
+        let price = pool.load_price(e, &asset);
+        let base = price.fixed_mul_floor(
+            e,
+            &reserve.to_asset_from_b_token(e, b_tokens),
+            &reserve.scalar,
+        );
+        if collateral.as_ref().is_none_or(|(_, _, max)| base > *max) {
+            collateral = Some((asset, b_tokens, base));
+        }
+    }
+    let (liability_asset, d_tokens, _) =
+        liability.unwrap_or_else(|| panic_with_error!(e, PoolError::InvalidBid));
+    let (collateral_asset, b_tokens, _) =
+        collateral.unwrap_or_else(|| panic_with_error!(e, PoolError::InvalidLot));
+
+    let liability_reserve = pool.load_reserve(e, &liability_asset, false);
+    let d_tokens_removed = d_tokens.fixed_mul_ceil(e, &config.max_pct, &SCALAR_7);
+    let liability_base = pool.load_price(e, &liability_asset).fixed_mul_ceil(
+        e,
+        &liability_reserve.to_asset_from_d_token(e, d_tokens_removed),
+        &liability_reserve.scalar,
+    );
+    let lot_base = liability_base.fixed_mul_floor(e, &(SCALAR_7 + config.discount), &SCALAR_7);
+    let collateral_reserve = pool.load_reserve(e, &collateral_asset, false);
+    let b_tokens_removed = collateral_reserve.to_b_token_down(
+        e,
+        lot_base.fixed_div_floor(
+            e,
+            &pool.load_price(e, &collateral_asset),
+            &collateral_reserve.scalar,
+        ),
+    );
+    if b_tokens_removed > b_tokens {
+        panic_with_error!(e, PoolError::InvalidLiqTooLarge);
+    }
+
+    let auction_data = AuctionData {
+        bid: map![e, (liability_asset, d_tokens_removed)],
+        lot: map![e, (collateral_asset, b_tokens_removed)],
+        block: e.ledger().sequence(),
+    };
+    fill_user_liq_auction(e, pool, &auction_data, user, filler_state, false);
+
+    let new_positions = storage::get_user_positions(e, user);
+    let new_data = PositionData::calculate_from_positions(e, pool, &new_positions);
+    if !new_data.is_hf_over(e, SCALAR_7) {
+        panic_with_error!(e, PoolError::InvalidLiqTooSmall);
+    }
+    auction_data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pool::Positions, storage::PoolConfig, testutils};
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec, Symbol,
+    };
+
+    fn setup_soft_liquidation(
+        e: &Env,
+        samwise_collateral: i128,
+    ) -> (Address, Address, Address, Address) {
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let bombadil = Address::generate(e);
+        let samwise = Address::generate(e);
+        let pool = testutils::create_pool(e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(e);
+
+        let (underlying_0, _) = testutils::create_token_contract(e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 12345;
+        testutils::create_reserve(e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.index = 1;
+        reserve_data.last_time = 12345;
+        testutils::create_reserve(e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(e, "USD")),
+            &vec![
+                e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![e, 1_0000000, 2_0000000]);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                e,
+                &PoolConfig {
+                    oracle,
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 0,
+                    max_positions: 4,
+                },
+            );
+            storage::set_soft_liquidation_config(
+                e,
+                &SoftLiquidationConfig {
+                    min_hf: 0_9500000,
+                    discount: 0_0200000,
+                    max_pct: 0_2000000,
+                },
+            );
+            // 0.75 c_factor and 0.75 l_factor
+            storage::set_user_positions(
+                e,
+                &samwise,
+                &Positions {
+                    liabilities: map![e, (1, 50_0000000)],
+                    collateral: map![e, (0, samwise_collateral)],
+                    supply: map![e],
+                },
+            );
+        });
+        (pool, samwise, underlying_0, underlying_1)
+    }
+
+    #[test]
+    fn test_soft_liquidate() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        // hf = 170 * 0.75 / (100 / 0.75) = 0.95625
+        let (pool, samwise, underlying_0, underlying_1) = setup_soft_liquidation(&e, 170_0000000);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_soft_liquidation(&e, &samwise, true);
+
+            let mut pool_state = Pool::load(&e);
+            let mut frodo_state = User::load(&e, &frodo);
+            let filled = soft_liquidate(&e, &mut pool_state, &samwise, &mut frodo_state);
+            // 20% of the liability is worth 20, collateral is taken at a 2% discount
+            assert_eq!(filled.bid.get_unchecked(underlying_1.clone()), 10_0000000);
+            assert_eq!(filled.lot.get_unchecked(underlying_0.clone()), 20_4000000);
+            assert_eq!(
+                frodo_state.positions.liabilities.get_unchecked(1),
+                10_0000000
+            );
+            assert_eq!(
+                frodo_state.positions.collateral.get_unchecked(0),
+                20_4000000
+            );
+
+            let samwise_positions = storage::get_user_positions(&e, &samwise);
+            assert_eq!(samwise_positions.liabilities.get_unchecked(1), 40_0000000);
+            assert_eq!(samwise_positions.collateral.get_unchecked(0), 149_6000000);
+
+            // hf = 149.6 * 0.75 / (80 / 0.75) = 1.051875
+            let position_data =
+                PositionData::calculate_from_positions(&e, &mut pool_state, &samwise_positions);
+            assert!(position_data.is_hf_over(&e, 1_0500000));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1214)")]
+    fn test_soft_liquidate_still_unhealthy() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        // hf = 170 * 0.75 / (100 / 0.75) = 0.95625
+        let (pool, samwise, _, _) = setup_soft_liquidation(&e, 170_0000000);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            storage::set_soft_liquidation_config(
+                &e,
+                &SoftLiquidationConfig {
+                    min_hf: 0_9500000,
+                    discount: 0_0200000,
+                    max_pct: 0_0500000,
+                },
+            );
+            execute_set_soft_liquidation(&e, &samwise, true);
+
+            // hf improves to 164.9 * 0.75 / (95 / 0.75) = 0.9764 but stays under 1
+            let mut pool_state = Pool::load(&e);
+            let mut frodo_state = User::load(&e, &frodo);
+            soft_liquidate(&e, &mut pool_state, &samwise, &mut frodo_state);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1211)")]
+    fn test_soft_liquidate_not_opted_in() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let (pool, samwise, _, _) = setup_soft_liquidation(&e, 170_0000000);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            let mut pool_state = Pool::load(&e);
+            let mut frodo_state = User::load(&e, &frodo);
+            soft_liquidate(&e, &mut pool_state, &samwise, &mut frodo_state);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1211)")]
+    fn test_soft_liquidate_under_band() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        // hf = 160 * 0.75 / (100 / 0.75) = 0.9
+        let (pool, samwise, _, _) = setup_soft_liquidation(&e, 160_0000000);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_soft_liquidation(&e, &samwise, true);
+
+            let mut pool_state = Pool::load(&e);
+            let mut frodo_state = User::load(&e, &frodo);
+            soft_liquidate(&e, &mut pool_state, &samwise, &mut frodo_state);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1211)")]
+    fn test_soft_liquidate_healthy_user() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.cost_estimate().budget().reset_unlimited();
+
+        // hf = 180 * 0.75 / (100 / 0.75) = 1.0125
+        let (pool, samwise, _, _) = setup_soft_liquidation(&e, 180_0000000);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_soft_liquidation(&e, &samwise, true);
+
+            let mut pool_state = Pool::load(&e);
+            let mut frodo_state = User::load(&e, &frodo);
+            soft_liquidate(&e, &mut pool_state, &samwise, &mut frodo_state);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_set_soft_liquidation_config_min_hf_too_large() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_soft_liquidation_config(
+                &e,
+                &SoftLiquidationConfig {
+                    min_hf: 1_0000000,
+                    discount: 0_0200000,
+                    max_pct: 0_1000000,
+                },
+            );
+        });
+    }
+}
@@ -67,7 +67,7 @@ pub fn create_user_liq_auction_data(
             }
         }
     }
-    if positions_auctioned.liabilities.len() == 0 {
+    if positions_auctioned.liabilities.is_empty() {
         panic_with_error!(e, PoolError::InvalidBid);
     }
     for lot_asset in lot {
@@ -84,7 +84,7 @@ pub fn create_user_liq_auction_data(
             }
         }
     }
-    if positions_auctioned.collateral.len() == 0 {
+    if positions_auctioned.collateral.is_empty() {
         panic_with_error!(e, PoolError::InvalidLot);
     }
     let position_data_inc =
@@ -2594,7 +2594,7 @@ fn test_fill_user_liquidation_auction() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e, (underlying_2.clone(), 1_2375000)],
             lot: map![
                 &e,
@@ -2638,7 +2638,7 @@ fn test_fill_user_liquidation_auction() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 true,
@@ -2776,7 +2776,7 @@ fn test_fill_user_liquidation_auction_hits_target() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e, (underlying_2.clone(), 1_2375000)],
             lot: map![
                 &e,
@@ -2820,7 +2820,7 @@ fn test_fill_user_liquidation_auction_hits_target() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 true,
@@ -2919,7 +2919,7 @@ fn test_fill_user_liquidation_auction_empty_bid() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![&e],
             lot: map![
                 &e,
@@ -2963,7 +2963,7 @@ fn test_fill_user_liquidation_auction_empty_bid() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 true,
@@ -3004,7 +3004,7 @@ fn test_fill_user_liquidation_auction_empty_bid() {
                     .liabilities
                     .get(reserve_config_2.index)
                     .unwrap(),
-                02_7500000 - 0
+                02_7500000
             );
         });
     }
@@ -3095,7 +3095,7 @@ fn test_fill_user_liquidation_auction_assigns_bad_debt() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_1.clone(), 8_0000000),
@@ -3140,7 +3140,7 @@ fn test_fill_user_liquidation_auction_assigns_bad_debt() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 true,
@@ -3283,7 +3283,7 @@ fn test_fill_user_liquidation_auction_no_bad_debt_if_collateral_remaining() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_1.clone(), 8_0000000),
@@ -3332,7 +3332,7 @@ fn test_fill_user_liquidation_auction_no_bad_debt_if_collateral_remaining() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 true,
@@ -3482,7 +3482,7 @@ fn test_fill_user_liquidation_auction_no_bad_debt_if_not_100_fill() {
         reserve_2_asset.mint(&frodo, &0_8000000);
         reserve_2_asset.approve(&frodo, &pool_address, &i128::MAX, &1000000);
 
-        let mut auction_data = AuctionData {
+        let auction_data = AuctionData {
             bid: map![
                 &e,
                 (underlying_1.clone(), 8_0000000),
@@ -3530,7 +3530,7 @@ fn test_fill_user_liquidation_auction_no_bad_debt_if_not_100_fill() {
             fill_user_liq_auction(
                 &e,
                 &mut pool,
-                &mut auction_data,
+                &auction_data,
                 &samwise,
                 &mut frodo_state,
                 false,
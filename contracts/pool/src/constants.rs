@@ -12,5 +12,29 @@
 /// Seconds per week
 pub const SECONDS_PER_WEEK: u64 = 604800;
 
-/// Max amount of reserves that can be added to a pool
-pub const MAX_RESERVES: u32 = 30;
+/// Max amount of reserves that can be added to a pool. Must fit in the `u64` position bitmaps.
+pub const MAX_RESERVES: u32 = 50;
+
+/// The time it takes a sunset reserve's collateral factor to ramp down to zero
+pub const SUNSET_PERIOD: u64 = 4 * SECONDS_PER_WEEK;
+
+/// Max amount of users that can be queued for withdrawal against a single reserve
+pub const MAX_WITHDRAWAL_QUEUE: u32 = 25;
+
+/// The number of users stored in each page of the index of users with liabilities
+pub const BORROWER_PAGE_SIZE: u32 = 50;
+
+/// The number of users stored in each page of the index of users with an auction of a type
+pub const AUCTION_PAGE_SIZE: u32 = 50;
+
+/// Max amount of external reward programs that can be added to a single reserve token
+pub const MAX_REWARD_PROGRAMS: u32 = 5;
+
+/// The length of a keeper reward period
+pub const KEEPER_PERIOD: u64 = 24 * 60 * 60;
+
+/// The version of the pool's storage layout. Bump when an upgrade requires a storage migration.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// The max slippage accepted when depositing a USDC interest auction bid into the backstop token
+pub const USDC_BID_MAX_SLIPPAGE: i128 = 0_0500000;
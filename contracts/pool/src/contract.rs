@@ -1,429 +1,1192 @@
-#![no_std]
-
+use crate::{
+    auctions::{self, ActiveAuction, AuctionData},
+    emissions::{self, ReserveEmissionMetadata},
+    events::PoolEvents,
+    pool::{self, FlashLoan, PoolOverview, Positions, Request, Reserve, WithdrawalQueuePosition},
+    storage::{
+        self, KeeperConfig, PoolConfig, PoolDataKey, PoolLoanLimits, ReserveConfig,
+        ReserveEmissionData, SoftLiquidationConfig, StatusPolicy, UserEmissionData,
+    },
+    PoolError,
+};
 use soroban_sdk::{
-    contract, contractimpl, contracttype, panic_with_error, Address, Env, Symbol, Vec,
+    contract, contractclient, contractimpl, panic_with_error, Address, BytesN, Env, Map, String,
+    Vec,
 };
 
-mod storage;
-mod error;
-mod events;
-
-pub use error::OracleError;
-pub use events::OracleEvents;
-
-// SEP-40 PriceData structure with enhanced metadata
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PriceData {
-    pub price: i128,           // Price with decimals precision
-    pub timestamp: u64,        // Unix timestamp
-    pub source_count: u32,     // Number of sources used for this price
-    pub confidence: u32,       // Confidence score (0-100)
-}
+/// ### Pool
+///
+/// An isolated money market pool.
+#[contract]
+pub struct PoolContract;
+
+#[contractclient(name = "PoolClient")]
+pub trait Pool {
+    /********* Admin **********/
+
+    /// (Admin only) Propose a new admin for the pool. The proposed admin must call
+    /// `accept_admin` to become the admin.
+    ///
+    /// ### Arguments
+    /// * `new_admin` - The proposed admin
+    fn propose_admin(e: Env, new_admin: Address);
+
+    /// (Proposed admin only) Accept the admin role for the pool
+    ///
+    /// ### Panics
+    /// If no admin has been proposed
+    fn accept_admin(e: Env);
+
+    /// (Admin only) Update the pool
+    ///
+    /// ### Arguments
+    /// * `backstop_take_rate` - The new take rate for the backstop (7 decimals)
+    /// * `max_positions` - The new maximum number of allowed positions for a single user's account
+    /// * `min_collateral` - The new minimum collateral required to open a borrow position,
+    ///   in the pool's base asset
+    fn update_pool(e: Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128);
+
+    /// (Admin only) Update the pool's per-user loan limits
+    ///
+    /// ### Arguments
+    /// * `min_borrow` - The minimum size of a borrow in the pool's base asset, or 0 for no minimum
+    /// * `max_liability` - The maximum liability a single user can hold in the pool's base asset,
+    ///   or 0 for no maximum
+    fn set_loan_limits(e: Env, min_borrow: i128, max_liability: i128);
+
+    /// (Admin only) Queue a change to the asset the pool's prices are denominated in
+    ///
+    /// ### Arguments
+    /// * `base_asset` - The underlying asset of the reserve to denominate prices in, or None to
+    ///   use the oracle's base asset
+    fn queue_set_base_asset(e: Env, base_asset: Option<Address>);
+
+    /// (Admin only) Cancel the queued change to the pool's base asset
+    fn cancel_set_base_asset(e: Env);
+
+    /// Set the queued base asset once its timelock has expired
+    fn set_base_asset(e: Env);
+
+    /// (Admin only) Queues setting data for a reserve in the pool
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset to add as a reserve
+    /// * `config` - The ReserveConfig for the reserve
+    fn queue_set_reserve(e: Env, asset: Address, metadata: ReserveConfig);
+
+    /// (Admin only) Cancels the queued set of a reserve in the pool
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset to add as a reserve
+    fn cancel_set_reserve(e: Env, asset: Address);
+
+    /// Executes the queued set of a reserve in the pool
+    ///
+    /// Returns the index of the reserve
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset to add as a reserve
+    fn set_reserve(e: Env, asset: Address) -> u32;
+
+    /// (Admin only) Queue a reserve to be sunset
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn queue_sunset_reserve(e: Env, asset: Address);
+
+    /// (Admin only) Cancel a queued reserve sunset that has not begun
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn cancel_sunset_reserve(e: Env, asset: Address);
+
+    /// Begin a queued reserve sunset once its timelock has expired. New supply and borrows are
+    /// disabled, and the reserve's collateral factor ramps down to zero.
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn sunset_reserve(e: Env, asset: Address);
+
+    /// Retire a sunset reserve with no remaining supply or liabilities, so its index can be
+    /// reclaimed by the next new reserve
+    ///
+    /// Returns the index that can be reclaimed
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn retire_reserve(e: Env, asset: Address) -> u32;
+
+    /// (Admin only) Queue a change to the pool's status policy
+    ///
+    /// ### Arguments
+    /// * `policy` - The new status policy
+    fn queue_set_status_policy(e: Env, policy: StatusPolicy);
+
+    /// (Admin only) Cancel the queued change to the pool's status policy
+    fn cancel_set_status_policy(e: Env);
+
+    /// Set the queued status policy once its timelock has expired
+    fn set_status_policy(e: Env);
+
+    /// (Admin only) Set the pool's keeper reward configuration
+    ///
+    /// ### Arguments
+    /// * `config` - The new keeper reward configuration
+    fn set_keeper_config(e: Env, config: KeeperConfig);
+
+    /// (Admin only) Set the pool's soft liquidation configuration
+    ///
+    /// ### Arguments
+    /// * `config` - The new soft liquidation configuration
+    fn set_soft_liquidation_config(e: Env, config: SoftLiquidationConfig);
+
+    /// Fetch the pool configuration
+    fn get_config(e: Env) -> PoolConfig;
+
+    /// Fetch the admin address of the pool
+    fn get_admin(e: Env) -> Address;
+
+    /// Fetch the pool's per-user loan limits
+    fn get_loan_limits(e: Env) -> PoolLoanLimits;
+
+    /// Fetch the pool's status policy
+    fn get_status_policy(e: Env) -> StatusPolicy;
+
+    /// Fetch the asset the pool's prices are denominated in, or None if prices are denominated
+    /// in the oracle's base asset
+    fn get_base_asset(e: Env) -> Option<Address>;
+
+    /// Fetch the list of reserves in the pool
+    fn get_reserve_list(e: Env) -> Vec<Address>;
+
+    /// Fetch the reserve data for an asset
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    fn get_reserve(e: Env, asset: Address) -> Reserve;
+
+    /// Fetch a summary of every reserve in the pool and the pool's aggregate exposure
+    fn get_pool_overview(e: Env) -> PoolOverview;
+
+    /********* Upgrades **********/
+
+    /// (Admin only) Queue an upgrade of the pool's wasm. The upgrade unlocks after 7 days so
+    /// users can exit before it is applied.
+    ///
+    /// ### Arguments
+    /// * `wasm_hash` - The hash of the uploaded wasm to upgrade to
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>);
+
+    /// (Admin only) Cancel the queued upgrade of the pool's wasm
+    fn cancel_upgrade(e: Env);
+
+    /// Upgrade the pool's wasm to the queued wasm once its timelock has expired
+    ///
+    /// Returns the hash of the new wasm
+    fn upgrade(e: Env) -> BytesN<32>;
+
+    /// Migrate the pool's storage to the current storage version after an upgrade
+    ///
+    /// Returns the new storage version
+    fn migrate_storage(e: Env) -> u32;
+
+    /// Move the positions of `users` out of the legacy positions storage
+    ///
+    /// Returns the number of users whose positions were migrated
+    ///
+    /// ### Arguments
+    /// * `users` - The addresses of the users to migrate
+    fn migrate_positions(e: Env, users: Vec<Address>) -> u32;
+
+    /********* User **********/
+
+    /// Fetch the positions for an address
+    ///
+    /// ### Arguments
+    /// * `address` - The address to fetch positions for
+    fn get_positions(e: Env, address: Address) -> Positions;
+
+    /// Submit a set of requests to the pool where `from` takes on the position, `spender` sends
+    /// any required tokens to the pool and `to` receives any tokens sent from the pool
+    ///
+    /// Returns the new positions for `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being modified
+    /// * `spender` - The address of the user who is sending tokens to the pool
+    /// * `to` - The address of the user who is receiving tokens from the pool
+    /// * `requests` - A vec of requests to be processed
+    ///
+    /// ### Panics
+    /// If the request is unable to be fully executed
+    fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions;
+
+    /// Submit a set of requests to the pool where `from` takes on the position, `spender` sends
+    /// any required tokens to the pool using transfer_from and `to` receives any tokens sent from
+    /// the pool
+    ///
+    /// Returns the new positions for `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being modified
+    /// * `spender` - The address of the user who is sending tokens to the pool
+    /// * `to` - The address of the user who is receiving tokens from the pool
+    /// * `requests` - A vec of requests to be processed
+    ///
+    /// ### Panics
+    /// If the request is unable to be fully executed
+    fn submit_with_allowance(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions;
+
+    /// Submit flash loan and a set of requests to the pool where `from` takes on the position.
+    /// The flash loan is invoked before the requests are processed.
+    ///
+    /// Returns the new positions for `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being modified
+    /// * `flash_loan` - The flash loan to execute
+    /// * `requests` - A vec of requests to be processed
+    ///
+    /// ### Panics
+    /// If the request is unable to be fully executed
+    fn flash_loan(
+        e: Env,
+        from: Address,
+        flash_loan: FlashLoan,
+        requests: Vec<Request>,
+    ) -> Positions;
+
+    /// Transfer every position held by `from` to `to`. Both users must authorize the transfer.
+    ///
+    /// Returns the new positions for `to`
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being transferred
+    /// * `to` - The address of the user receiving the positions
+    ///
+    /// ### Panics
+    /// If `to` exceeds the max positions or is unhealthy after the transfer
+    fn transfer_positions(e: Env, from: Address, to: Address) -> Positions;
+
+    /// Migrate positions held by `from` in `source_pool` into this pool. The `repay` amounts
+    /// are borrowed from this pool to repay `from`s liabilities in `source_pool`, and the
+    /// `withdraw` collateral is withdrawn from `source_pool` and supplied as collateral in this
+    /// pool. `from` must authorize the submit made against `source_pool`.
+    ///
+    /// Returns the new positions for `from` in this pool
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being migrated
+    /// * `source_pool` - The address of the pool the positions are migrated out of
+    /// * `repay` - A map of asset to the amount of underlying to repay in `source_pool`
+    /// * `withdraw` - A map of asset to the amount of underlying collateral to withdraw from `source_pool`
+    ///
+    /// ### Panics
+    /// If the migration fails or `from` is unhealthy in this pool after the migration
+    fn migrate(
+        e: Env,
+        from: Address,
+        source_pool: Address,
+        repay: Map<Address, i128>,
+        withdraw: Map<Address, i128>,
+    ) -> Positions;
+
+    /// Opt in or out of soft liquidations for `user`
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    /// * `enabled` - If soft liquidations are enabled for the user
+    fn set_soft_liquidation(e: Env, user: Address, enabled: bool);
+
+    /// Fetch the position of a user in a reserve's withdrawal queue
+    ///
+    /// ### Arguments
+    /// * `asset` - The underlying asset of the reserve
+    /// * `user` - The address of the user
+    fn get_withdrawal_queue_position(
+        e: Env,
+        asset: Address,
+        user: Address,
+    ) -> Option<WithdrawalQueuePosition>;
+
+    /// Claim the tokens from `from`'s filled queued withdrawals for a reserve
+    ///
+    /// Returns the amount of tokens claimed
+    ///
+    /// ### Arguments
+    /// * `from` - The address whose queued withdrawals were filled
+    /// * `asset` - The underlying asset of the reserve
+    /// * `to` - The address to send the tokens to
+    ///
+    /// ### Panics
+    /// If `from` has no filled withdrawals to claim
+    fn claim_withdrawal(e: Env, from: Address, asset: Address, to: Address) -> i128;
+
+    /// Fetch the health factor of up to `count` borrowers, starting at the borrower index `start`
+    ///
+    /// ### Arguments
+    /// * `start` - The index of the first borrower to fetch
+    /// * `count` - The max number of borrowers to fetch
+    fn get_borrower_health_factors(e: Env, start: u32, count: u32) -> Vec<(Address, i128)>;
+
+    /// Add any users with liabilities that are missing from the borrower index. Users are
+    /// otherwise only indexed the next time their positions change.
+    ///
+    /// Returns the number of users added to the index
+    ///
+    /// ### Arguments
+    /// * `users` - The addresses of the users
+    fn index_borrowers(e: Env, users: Vec<Address>) -> u32;
+
+    /// Fetch the ledger keys of every entry stored for a user
+    ///
+    /// Reporting the remaining TTL of each entry is not feasible on-chain, as the host does not
+    /// let a contract read the TTL of its entries. Keepers look up each key's live until ledger
+    /// with an RPC node (e.g. `getLedgerEntries`) to decide who to pass to `bump_users`.
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    fn user_ledger_keys(e: Env, user: Address) -> Vec<PoolDataKey>;
+
+    /// Extend the TTL of every entry stored for each user
+    ///
+    /// Returns the number of entries bumped
+    ///
+    /// ### Arguments
+    /// * `users` - The addresses of the users
+    fn bump_users(e: Env, users: Vec<Address>) -> u32;
+
+    /********** Keepers **********/
+
+    /// Update the pool status based on the backstop state
+    ///
+    /// Returns the new pool status
+    ///
+    /// ### Panics
+    /// If the pool is currently on status 4, "admin-freeze", where only the admin
+    /// can perform a status update via `set_status`
+    fn update_status(e: Env) -> u32;
+
+    /// Update the pool status based on the backstop state, rewarding the keeper if the
+    /// status changes
+    ///
+    /// Returns the new pool status
+    ///
+    /// ### Arguments
+    /// * `keeper` - The address of the keeper to reward
+    ///
+    /// ### Panics
+    /// If the pool is currently on status 4, "admin-freeze", where only the admin
+    /// can perform a status update via `set_status`
+    fn keeper_update_status(e: Env, keeper: Address) -> u32;
+
+    /// (Admin only) Pool status is changed to "pool_status"
+    /// * 0 = active - requires that the backstop threshold is met
+    ///   and less than 50% of backstop deposits are queued for withdrawal
+    /// * 2 = admin on-ice - requires that less than 75% of backstop deposits are queued for withdrawal
+    /// * 3 = on-ice - requires that less than 75% of backstop deposits are queued for withdrawal
+    /// * 4 = admin frozen - can always be set
+    ///
+    /// ### Arguments
+    /// * `pool_status` - The pool status to be set
+    fn set_status(e: Env, pool_status: u32);
+
+    /// Gulps unaccounted for tokens to the backstop credit so they aren't lost. This is most relevant
+    /// for rebasing tokens where the token balance of the pool can increase without any corresponding
+    /// transfer.
+    ///
+    /// Returns the amount of tokens gulped
+    ///
+    /// ### Arguments
+    /// * `asset` - The address of the asset to gulp
+    fn gulp(e: Env, asset: Address) -> i128;
+
+    /// Gulps unaccounted for tokens to the backstop credit, rewarding the keeper if the gulped
+    /// amount is at least the keeper gulp threshold
+    ///
+    /// Returns the amount of tokens gulped
+    ///
+    /// ### Arguments
+    /// * `asset` - The address of the asset to gulp
+    /// * `keeper` - The address of the keeper to reward
+    fn keeper_gulp(e: Env, asset: Address, keeper: Address) -> i128;
+
+    /// Delete the stale auctions of a type, checking up to `count` auctions starting at the
+    /// auction index `start`. The keeper is rewarded if any auctions are deleted.
+    ///
+    /// Returns the number of auctions deleted
+    ///
+    /// ### Arguments
+    /// * `keeper` - The address of the keeper to reward
+    /// * `auction_type` - The type of auction, 0 for liquidation auction, 1 for bad debt auction, and 2 for interest auction
+    /// * `start` - The index of the first auction to check
+    /// * `count` - The max number of auctions to check
+    fn del_stale_auctions(
+        e: Env,
+        keeper: Address,
+        auction_type: u32,
+        start: u32,
+        count: u32,
+    ) -> u32;
+
+    /********* Emission Functions **********/
+
+    /// Consume emissions from the backstop and distribute to the reserves based
+    /// on the reserve emission configuration.
+    ///
+    /// Returns amount of new tokens emitted
+    fn gulp_emissions(e: Env) -> i128;
+
+    /// Consume emissions from the backstop and distribute to the reserves, rewarding the keeper
+    /// from the keeper budget
+    ///
+    /// Returns amount of new tokens emitted
+    ///
+    /// ### Arguments
+    /// * `keeper` - The address of the keeper to reward
+    fn keeper_gulp_emissions(e: Env, keeper: Address) -> i128;
+
+    /// (Admin only) Set the emission configuration for the pool
+    ///
+    /// Changes will be applied in the next pool `gulp_emissions`, and affect the next emission cycle
+    ///
+    /// ### Arguments
+    /// * `res_emission_metadata` - A vector of ReserveEmissionMetadata to update metadata to
+    ///
+    /// ### Panics
+    /// * If the caller is not the admin
+    /// * If the sum of ReserveEmissionMetadata shares is greater than 1
+    fn set_emissions_config(e: Env, res_emission_metadata: Vec<ReserveEmissionMetadata>);
+
+    /// Claims outstanding emissions for the caller for the given reserve's
+    ///
+    /// Returns the number of tokens claimed
+    ///
+    /// ### Arguments
+    /// * `from` - The address claiming
+    /// * `reserve_token_ids` - Vector of reserve token ids
+    /// * `to` - The Address to send the claimed tokens to
+    fn claim(e: Env, from: Address, reserve_token_ids: Vec<u32>, to: Address) -> i128;
+
+    /// Claims outstanding emissions for `from` and supplies them to the pool for `from`.
+    /// Emissions are swapped through the backstop's LP pool if `asset` is not BLND.
+    ///
+    /// Returns the new positions for `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The address claiming
+    /// * `reserve_token_ids` - Vector of reserve token ids
+    /// * `asset` - The reserve asset to supply the claimed emissions to
+    /// * `collateral` - If the claimed emissions are supplied as collateral
+    /// * `min_tokens_out` - The minimum amount of `asset` to receive from the swap
+    fn claim_and_supply(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        asset: Address,
+        collateral: bool,
+        min_tokens_out: i128,
+    ) -> Positions;
+
+    /// Fund an external reward program for a reserve token
+    ///
+    /// ### Arguments
+    /// * `funder` - The address funding the program
+    /// * `res_token_id` - The reserve token id to reward
+    /// * `token` - The reward token
+    /// * `amount` - The amount of `token` to add to the program
+    /// * `expiration` - The time the program ends
+    fn fund_reward_program(
+        e: Env,
+        funder: Address,
+        res_token_id: u32,
+        token: Address,
+        amount: i128,
+        expiration: u64,
+    );
 
-// Price source for multi-oracle aggregation
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PriceSource {
-    pub source_id: Symbol,     // Source identifier
-    pub price: i128,           // Price from this source
-    pub timestamp: u64,        // When this source was updated
-    pub weight: u32,           // Weight in aggregation (0-100)
+    /// Claims outstanding external rewards for the caller for the given reserve's
+    ///
+    /// Returns the amount claimed of each reward token
+    ///
+    /// ### Arguments
+    /// * `from` - The address claiming
+    /// * `reserve_token_ids` - Vector of reserve token ids
+    /// * `to` - The Address to send the claimed tokens to
+    fn claim_rewards(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        to: Address,
+    ) -> Map<Address, i128>;
+
+    /// Get the emissions data for a reserve token
+    ///
+    /// ### Arguments
+    /// * `reserve_token_index` - The index of the reserve token
+    fn get_reserve_emissions(e: Env, reserve_token_index: u32) -> Option<ReserveEmissionData>;
+
+    /// Get the emissions data for a user
+    ///
+    /// ### Arguments
+    /// * `user` - The address of the user
+    /// * `reserve_token_index` - The index of the reserve token
+    fn get_user_emissions(
+        e: Env,
+        user: Address,
+        reserve_token_index: u32,
+    ) -> Option<UserEmissionData>;
+
+    /***** Auction / Liquidation Functions *****/
+
+    /// Create a new auction. Auctions are used to process liquidations, bad debt, and interest.
+    ///
+    /// Returns the AuctionData object created.
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction, 0 for liquidation auction, 1 for bad debt auction, and 2 for interest auction
+    /// * `user` - The Address involved in the auction. This is generally the source of the assets being auctioned.
+    /// * `bid` - The set of assets to include in the auction bid, or what the auctioneer spends to fill the auction.
+    /// * `lot` - The set of assets to include in the auction lot, or what the auctioneer receives for filling the auction.
+    /// * `percent` - The percent of the assets to be auctioned off as a percentage (15 => 15%).
+    ///
+    /// ### Panics
+    /// If the auction is unable to be created
+    fn new_auction(
+        e: Env,
+        auction_type: u32,
+        user: Address,
+        bid: Vec<Address>,
+        lot: Vec<Address>,
+        percent: u32,
+    ) -> AuctionData;
+
+    /// Fetch an auction from the ledger. Returns a quote based on the current block.
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction, 0 for liquidation auction, 1 for bad debt auction, and 2 for interest auction
+    /// * `user` - The Address involved in the auction
+    ///
+    /// ### Panics
+    /// If the auction does not exist
+    fn get_auction(e: Env, auction_type: u32, user: Address) -> AuctionData;
+
+    /// Delete a stale auction. A stale auction is one that has been running for 500 blocks
+    /// without being filled.
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction, 0 for liquidation auction, 1 for bad debt auction, and 2 for interest auction
+    /// * `user` - The Address involved in the auction
+    ///
+    /// ### Panics
+    /// If the auction does not exist or is not stale
+    fn del_auction(e: Env, auction_type: u32, user: Address);
+
+    /// Fetch up to `count` active auctions of a type, scaled to the current block, starting at
+    /// the auction index `start`
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction
+    /// * `start` - The index of the first auction to fetch
+    /// * `count` - The max number of auctions to fetch
+    fn get_active_auctions(e: Env, auction_type: u32, start: u32, count: u32)
+        -> Vec<ActiveAuction>;
+
+    /// Fetch the number of auctions of a type in the auction index. Auctions stay in the index
+    /// until they are filled or deleted, even if they are stale.
+    ///
+    /// ### Arguments
+    /// * `auction_type` - The type of auction
+    fn get_auction_count(e: Env, auction_type: u32) -> u32;
+
+    /// Transfers bad debt from a user to the backstop. Only works if the user has no collateral.
+    ///
+    /// ### Arguments
+    /// * `user` - The user who currently possesses bad debt
+    ///
+    /// ### Panics
+    /// If the user has collateral posted
+    fn bad_debt(e: Env, user: Address);
 }
 
-// Circuit breaker state
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CircuitBreaker {
-    pub is_paused: bool,
-    pub pause_timestamp: u64,
-    pub reason: Symbol,
+#[contractimpl]
+impl PoolContract {
+    /// Construct the pool contract
+    ///
+    /// ### Arguments
+    /// * `admin` - The Address for the admin
+    /// * `name` - The name of the pool
+    /// * `oracle` - The contract address of the oracle
+    /// * `backstop_take_rate` - The take rate for the backstop (7 decimals)
+    /// * `max_positions` - The maximum number of positions a user is permitted to have
+    /// * `min_collateral` - The minimum collateral required to open a borrow position,
+    ///   in the oracle's base asset
+    /// * `backstop_id` - The contract address of the pool's backstop module
+    /// * `blnd_id` - The contract ID of the BLND token
+    #[allow(clippy::too_many_arguments)]
+    pub fn __constructor(
+        e: Env,
+        admin: Address,
+        name: String,
+        oracle: Address,
+        bstop_rate: u32,
+        max_positions: u32,
+        min_collateral: i128,
+        backstop_id: Address,
+        blnd_id: Address,
+    ) {
+        pool::execute_initialize(
+            &e,
+            &admin,
+            &name,
+            &oracle,
+            &bstop_rate,
+            &max_positions,
+            &min_collateral,
+            &backstop_id,
+            &blnd_id,
+        );
+    }
 }
 
-// Oracle configuration
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct OracleConfig {
-    pub max_price_deviation_bps: u32,  // Max deviation in basis points (e.g., 1000 = 10%)
-    pub max_staleness_seconds: u64,     // Max time before price is stale
-    pub min_sources_required: u32,      // Minimum sources needed for valid price
-    pub heartbeat_interval: u64,        // Required update frequency
-}
+/// @dev
+/// The contract implementation only manages the authorization / authentication required from the caller(s), and
+/// utilizes other modules to carry out contract functionality.
+#[contractimpl]
+impl Pool for PoolContract {
+    /********* Admin **********/
 
-// Asset representation for SEP-40 compatibility
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum Asset {
-    Stellar(Address),     // Stellar asset contract address
-    Other(Symbol),        // Other asset identifier
-}
+    fn propose_admin(e: Env, new_admin: Address) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-/// Secure TrustBridge Oracle Contract
-/// 
-/// Enhanced implementation with:
-/// - Multi-source price aggregation
-/// - Price deviation protection
-/// - Staleness checks
-/// - Circuit breakers
-/// - Multi-sig admin capabilities
-#[contract]
-pub struct TrustBridgeOracle;
+        storage::set_proposed_admin(&e, &new_admin);
+    }
 
-pub trait OracleTrait {
-    /// Initialize the oracle with admins and configuration
-    fn init(
-        e: Env,
-        admins: Vec<Address>,
-        min_signatures: u32,
-        config: OracleConfig,
-    );
+    fn accept_admin(e: Env) {
+        storage::extend_instance(&e);
+        let new_admin = match storage::get_proposed_admin(&e) {
+            Some(new_admin) => new_admin,
+            None => panic_with_error!(&e, PoolError::BadRequest),
+        };
+        new_admin.require_auth();
 
-    /// Submit price from a trusted source (multi-sig required)
-    fn submit_price(
-        e: Env,
-        asset: Asset,
-        price: i128,
-        source_id: Symbol,
-    );
+        let admin = storage::get_admin(&e);
+        storage::set_admin(&e, &new_admin);
 
-    /// Get the aggregated price for an asset (with staleness check)
-    fn lastprice(e: Env, asset: Asset) -> Option<PriceData>;
+        PoolEvents::set_admin(&e, admin, new_admin);
+    }
 
-    /// Get decimals
-    fn decimals(e: Env) -> u32;
+    fn update_pool(e: Env, backstop_take_rate: u32, max_positions: u32, min_collateral: i128) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
 
-    /// Emergency pause (multi-sig required)
-    fn pause(e: Env, reason: Symbol);
+        pool::execute_update_pool(&e, backstop_take_rate, max_positions, min_collateral);
 
-    /// Resume operations (multi-sig required)
-    fn resume(e: Env);
+        PoolEvents::update_pool(&e, admin, backstop_take_rate, max_positions, min_collateral);
+    }
 
-    /// Update oracle configuration (multi-sig required)
-    fn update_config(e: Env, config: OracleConfig);
+    fn set_loan_limits(e: Env, min_borrow: i128, max_liability: i128) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-    /// Add trusted price source (multi-sig required)
-    fn add_source(e: Env, source_id: Symbol, weight: u32);
+        pool::execute_set_loan_limits(&e, min_borrow, max_liability);
+    }
 
-    /// Remove trusted price source (multi-sig required)
-    fn remove_source(e: Env, source_id: Symbol);
+    fn queue_set_base_asset(e: Env, base_asset: Option<Address>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-    /// Get circuit breaker status
-    fn get_circuit_breaker(e: Env) -> CircuitBreaker;
+        pool::execute_queue_set_base_asset(&e, &base_asset);
+    }
 
-    /// Get oracle configuration
-    fn get_config(e: Env) -> OracleConfig;
+    fn cancel_set_base_asset(e: Env) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-    /// Add admin (multi-sig required)
-    fn add_admin(e: Env, new_admin: Address);
+        pool::execute_cancel_queued_set_base_asset(&e);
+    }
 
-    /// Remove admin (multi-sig required)
-    fn remove_admin(e: Env, admin: Address);
+    fn set_base_asset(e: Env) {
+        storage::extend_instance(&e);
+        pool::execute_set_base_asset(&e);
+    }
 
-    /// Get all admins
-    fn get_admins(e: Env) -> Vec<Address>;
+    fn queue_set_reserve(e: Env, asset: Address, metadata: ReserveConfig) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
 
-    /// Get price with all source data (for transparency)
-    fn get_price_sources(e: Env, asset: Asset) -> Vec<PriceSource>;
-}
+        pool::execute_queue_set_reserve(&e, &asset, &metadata);
 
-#[contractimpl]
-impl OracleTrait for TrustBridgeOracle {
-    fn init(
-        e: Env,
-        admins: Vec<Address>,
-        min_signatures: u32,
-        config: OracleConfig,
-    ) {
-        if storage::has_admins(&e) {
-            panic_with_error!(&e, OracleError::AlreadyInitialized);
-        }
+        PoolEvents::queue_set_reserve(&e, admin, asset, metadata);
+    }
 
-        if admins.is_empty() || min_signatures == 0 || min_signatures > admins.len() {
-            panic_with_error!(&e, OracleError::InvalidInput);
-        }
+    fn cancel_set_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
 
-        // Validate config
-        if config.max_price_deviation_bps > 10000 {  // Max 100%
-            panic_with_error!(&e, OracleError::InvalidInput);
-        }
+        pool::execute_cancel_queued_set_reserve(&e, &asset);
 
-        storage::set_admins(&e, &admins);
-        storage::set_min_signatures(&e, min_signatures);
-        storage::set_config(&e, &config);
-        
-        // Initialize circuit breaker as active
-        let cb = CircuitBreaker {
-            is_paused: false,
-            pause_timestamp: 0,
-            reason: Symbol::new(&e, ""),
-        };
-        storage::set_circuit_breaker(&e, &cb);
+        PoolEvents::cancel_set_reserve(&e, admin, asset);
+    }
+
+    fn set_reserve(e: Env, asset: Address) -> u32 {
+        storage::extend_instance(&e);
+        let index = pool::execute_set_reserve(&e, &asset);
 
-        OracleEvents::initialized(&e, admins.get(0).unwrap());
+        PoolEvents::set_reserve(&e, asset, index);
+        index
     }
 
-    fn submit_price(
-        e: Env,
-        asset: Asset,
-        price: i128,
-        source_id: Symbol,
-    ) {
-        // Check circuit breaker
-        let cb = storage::get_circuit_breaker(&e);
-        if cb.is_paused {
-            panic_with_error!(&e, OracleError::CircuitBreakerActive);
-        }
+    fn queue_sunset_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        // Verify caller is authorized source
-        storage::require_authorized_source(&e, &source_id);
+        pool::execute_queue_sunset_reserve(&e, &asset);
+    }
 
-        if price <= 0 {
-            panic_with_error!(&e, OracleError::InvalidPrice);
-        }
+    fn cancel_sunset_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        let config = storage::get_config(&e);
-        let timestamp = e.ledger().timestamp();
-
-        // Check if we have a previous aggregated price for deviation check
-        if let Some(prev_price_data) = storage::get_aggregated_price(&e, &asset) {
-            // Check price deviation
-            let deviation_bps = calculate_deviation_bps(price, prev_price_data.price);
-            if deviation_bps > config.max_price_deviation_bps {
-                // Price change too large - auto-pause
-                Self::auto_pause(&e, Symbol::new(&e, "deviation"));
-                panic_with_error!(&e, OracleError::PriceDeviationExceeded);
-            }
-
-            // Check heartbeat
-            let time_since_update = timestamp - prev_price_data.timestamp;
-            if time_since_update > config.heartbeat_interval * 2 {
-                // Missed heartbeat - flag warning
-                OracleEvents::heartbeat_missed(&e, asset.clone(), time_since_update);
-            }
-        }
+        pool::execute_cancel_sunset_reserve(&e, &asset);
+    }
 
-        // Store price from this source
-        let source_weight = storage::get_source_weight(&e, &source_id);
-        let price_source = PriceSource {
-            source_id: source_id.clone(),
-            price,
-            timestamp,
-            weight: source_weight,
-        };
+    fn sunset_reserve(e: Env, asset: Address) {
+        storage::extend_instance(&e);
+        pool::execute_sunset_reserve(&e, &asset);
+    }
 
-        storage::set_price_source(&e, &asset, &source_id, &price_source);
+    fn retire_reserve(e: Env, asset: Address) -> u32 {
+        storage::extend_instance(&e);
+        pool::execute_retire_reserve(&e, &asset)
+    }
 
-        // Aggregate prices from all sources
-        Self::aggregate_prices(&e, &asset, &config);
+    fn queue_set_status_policy(e: Env, policy: StatusPolicy) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        OracleEvents::price_submitted(&e, asset, source_id, price, timestamp);
+        pool::execute_queue_set_status_policy(&e, &policy);
     }
 
-    fn lastprice(e: Env, asset: Asset) -> Option<PriceData> {
-        let config = storage::get_config(&e);
-        let price_data = storage::get_aggregated_price(&e, &asset)?;
-        
-        // Check staleness
-        let current_time = e.ledger().timestamp();
-        let age = current_time - price_data.timestamp;
-        
-        if age > config.max_staleness_seconds {
-            OracleEvents::stale_price_detected(&e, asset, age);
-            return None;  // Price too old
-        }
+    fn cancel_set_status_policy(e: Env) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        // Check minimum sources
-        if price_data.source_count < config.min_sources_required {
-            return None;  // Not enough sources
-        }
+        pool::execute_cancel_queued_set_status_policy(&e);
+    }
 
-        Some(price_data)
+    fn set_status_policy(e: Env) {
+        storage::extend_instance(&e);
+        pool::execute_set_status_policy(&e);
     }
 
-    fn decimals(_e: Env) -> u32 {
-        7  // TrustBridge Oracle uses 7 decimals
+    fn set_keeper_config(e: Env, config: KeeperConfig) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        emissions::execute_set_keeper_config(&e, &config);
     }
 
-    fn pause(e: Env, reason: Symbol) {
-        storage::require_multi_sig(&e);
+    fn set_soft_liquidation_config(e: Env, config: SoftLiquidationConfig) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        let cb = CircuitBreaker {
-            is_paused: true,
-            pause_timestamp: e.ledger().timestamp(),
-            reason: reason.clone(),
-        };
+        auctions::execute_set_soft_liquidation_config(&e, &config);
+    }
 
-        storage::set_circuit_breaker(&e, &cb);
-        OracleEvents::circuit_breaker_triggered(&e, reason);
+    fn get_config(e: Env) -> PoolConfig {
+        storage::get_pool_config(&e)
     }
 
-    fn resume(e: Env) {
-        storage::require_multi_sig(&e);
+    fn get_admin(e: Env) -> Address {
+        storage::get_admin(&e)
+    }
 
-        let cb = CircuitBreaker {
-            is_paused: false,
-            pause_timestamp: 0,
-            reason: Symbol::new(&e, ""),
-        };
+    fn get_loan_limits(e: Env) -> PoolLoanLimits {
+        storage::get_loan_limits(&e)
+    }
 
-        storage::set_circuit_breaker(&e, &cb);
-        OracleEvents::circuit_breaker_reset(&e);
+    fn get_status_policy(e: Env) -> StatusPolicy {
+        storage::get_status_policy(&e)
     }
 
-    fn update_config(e: Env, config: OracleConfig) {
-        storage::require_multi_sig(&e);
+    fn get_base_asset(e: Env) -> Option<Address> {
+        storage::get_base_asset(&e)
+    }
 
-        if config.max_price_deviation_bps > 10000 {
-            panic_with_error!(&e, OracleError::InvalidInput);
-        }
+    fn get_reserve_list(e: Env) -> Vec<Address> {
+        storage::get_res_list(&e)
+    }
 
-        storage::set_config(&e, &config);
-        OracleEvents::config_updated(&e);
+    fn get_reserve(e: Env, asset: Address) -> Reserve {
+        let pool_config = storage::get_pool_config(&e);
+        Reserve::load(&e, &pool_config, &asset)
     }
 
-    fn add_source(e: Env, source_id: Symbol, weight: u32) {
-        storage::require_multi_sig(&e);
+    fn get_pool_overview(e: Env) -> PoolOverview {
+        pool::load_pool_overview(&e)
+    }
 
-        if weight > 100 {
-            panic_with_error!(&e, OracleError::InvalidInput);
-        }
+    /********* Upgrades **********/
+
+    fn queue_upgrade(e: Env, wasm_hash: BytesN<32>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
 
-        storage::add_trusted_source(&e, &source_id, weight);
-        OracleEvents::source_added(&e, source_id, weight);
+        pool::execute_queue_upgrade(&e, &wasm_hash);
     }
 
-    fn remove_source(e: Env, source_id: Symbol) {
-        storage::require_multi_sig(&e);
+    fn cancel_upgrade(e: Env) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        pool::execute_cancel_upgrade(&e);
+    }
 
-        storage::remove_trusted_source(&e, &source_id);
-        OracleEvents::source_removed(&e, source_id);
+    fn upgrade(e: Env) -> BytesN<32> {
+        storage::extend_instance(&e);
+        pool::execute_upgrade(&e)
     }
 
-    fn get_circuit_breaker(e: Env) -> CircuitBreaker {
-        storage::get_circuit_breaker(&e)
+    fn migrate_storage(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        pool::execute_migrate_storage(&e)
     }
 
-    fn get_config(e: Env) -> OracleConfig {
-        storage::get_config(&e)
+    fn migrate_positions(e: Env, users: Vec<Address>) -> u32 {
+        storage::extend_instance(&e);
+        pool::execute_migrate_positions(&e, &users)
     }
 
-    fn add_admin(e: Env, new_admin: Address) {
-        storage::require_multi_sig(&e);
+    /********* User **********/
 
-        storage::add_admin(&e, &new_admin);
-        OracleEvents::admin_added(&e, new_admin);
+    fn get_positions(e: Env, address: Address) -> Positions {
+        storage::get_user_positions(&e, &address)
     }
 
-    fn remove_admin(e: Env, admin: Address) {
-        storage::require_multi_sig(&e);
+    fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        if from != spender {
+            from.require_auth();
+        }
 
-        let admins = storage::get_admins(&e);
-        let min_sigs = storage::get_min_signatures(&e);
+        pool::execute_submit(&e, &from, &spender, &to, requests, false)
+    }
 
-        if admins.len() <= min_sigs {
-            panic_with_error!(&e, OracleError::InsufficientAdmins);
+    fn submit_with_allowance(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        spender.require_auth();
+        if from != spender {
+            from.require_auth();
         }
 
-        storage::remove_admin(&e, &admin);
-        OracleEvents::admin_removed(&e, admin);
+        pool::execute_submit(&e, &from, &spender, &to, requests, true)
     }
 
-    fn get_admins(e: Env) -> Vec<Address> {
-        storage::get_admins(&e)
+    fn flash_loan(
+        e: Env,
+        from: Address,
+        flash_loan: FlashLoan,
+        requests: Vec<Request>,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        pool::execute_submit_with_flash_loan(&e, &from, flash_loan, requests)
     }
 
-    fn get_price_sources(e: Env, asset: Asset) -> Vec<PriceSource> {
-        storage::get_all_price_sources(&e, &asset)
+    fn transfer_positions(e: Env, from: Address, to: Address) -> Positions {
+        storage::extend_instance(&e);
+        from.require_auth();
+        to.require_auth();
+
+        pool::execute_transfer_positions(&e, &from, &to)
     }
-}
 
-// Internal helper functions
-impl TrustBridgeOracle {
-    fn aggregate_prices(e: &Env, asset: &Asset, config: &OracleConfig) {
-        let sources = storage::get_all_price_sources(e, asset);
-        
-        if sources.is_empty() {
-            return;
-        }
+    fn migrate(
+        e: Env,
+        from: Address,
+        source_pool: Address,
+        repay: Map<Address, i128>,
+        withdraw: Map<Address, i128>,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        pool::execute_migrate(&e, &from, &source_pool, repay, withdraw)
+    }
 
-        let current_time = e.ledger().timestamp();
-        let mut total_weighted_price: i128 = 0;
-        let mut total_weight: u32 = 0;
-        let mut valid_sources: u32 = 0;
-
-        // Calculate weighted average
-        for i in 0..sources.len() {
-            let source = sources.get(i).unwrap();
-            
-            // Skip stale sources
-            if current_time - source.timestamp > config.max_staleness_seconds {
-                continue;
-            }
-
-            total_weighted_price += source.price * (source.weight as i128);
-            total_weight += source.weight;
-            valid_sources += 1;
-        }
+    fn set_soft_liquidation(e: Env, user: Address, enabled: bool) {
+        storage::extend_instance(&e);
+        user.require_auth();
 
-        if valid_sources == 0 || total_weight == 0 {
-            return;
-        }
+        auctions::execute_set_soft_liquidation(&e, &user, enabled);
+    }
+
+    fn get_withdrawal_queue_position(
+        e: Env,
+        asset: Address,
+        user: Address,
+    ) -> Option<WithdrawalQueuePosition> {
+        pool::load_withdrawal_queue_position(&e, &asset, &user)
+    }
 
-        let aggregated_price = total_weighted_price / (total_weight as i128);
+    fn claim_withdrawal(e: Env, from: Address, asset: Address, to: Address) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
 
-        // Calculate confidence based on source count and weight distribution
-        let confidence = calculate_confidence(valid_sources, sources.len());
+        let amount = pool::execute_claim_withdrawal(&e, &from, &asset, &to);
 
-        let price_data = PriceData {
-            price: aggregated_price,
-            timestamp: current_time,
-            source_count: valid_sources,
-            confidence,
-        };
+        PoolEvents::claim_withdrawal(&e, asset, from, to, amount);
+        amount
+    }
 
-        storage::set_aggregated_price(e, asset, &price_data);
+    fn get_borrower_health_factors(e: Env, start: u32, count: u32) -> Vec<(Address, i128)> {
+        pool::load_borrower_health_factors(&e, start, count)
     }
 
-    fn auto_pause(e: &Env, reason: Symbol) {
-        let cb = CircuitBreaker {
-            is_paused: true,
-            pause_timestamp: e.ledger().timestamp(),
-            reason: reason.clone(),
-        };
+    fn index_borrowers(e: Env, users: Vec<Address>) -> u32 {
+        storage::extend_instance(&e);
+        pool::execute_index_borrowers(&e, &users)
+    }
 
-        storage::set_circuit_breaker(e, &cb);
-        OracleEvents::circuit_breaker_triggered(e, reason);
+    fn user_ledger_keys(e: Env, user: Address) -> Vec<PoolDataKey> {
+        pool::load_user_ledger_keys(&e, &user)
     }
-}
 
-// Helper functions
-fn calculate_deviation_bps(new_price: i128, old_price: i128) -> u32 {
-    if old_price == 0 {
-        return 10000;  // 100% deviation
+    fn bump_users(e: Env, users: Vec<Address>) -> u32 {
+        storage::extend_instance(&e);
+        pool::execute_bump_users(&e, &users)
     }
 
-    let diff = if new_price > old_price {
-        new_price - old_price
-    } else {
-        old_price - new_price
-    };
+    /********** Keepers **********/
 
-    ((diff * 10000) / old_price) as u32
-}
+    fn update_status(e: Env) -> u32 {
+        storage::extend_instance(&e);
+        let new_status = pool::execute_update_pool_status(&e, None);
 
-fn calculate_confidence(valid_sources: u32, total_sources: u32) -> u32 {
-    if total_sources == 0 {
-        return 0;
+        PoolEvents::set_status(&e, new_status);
+        new_status
     }
 
-    // Confidence based on percentage of sources reporting
-    let base_confidence = (valid_sources * 100) / total_sources;
+    fn keeper_update_status(e: Env, keeper: Address) -> u32 {
+        storage::extend_instance(&e);
+        let new_status = pool::execute_update_pool_status(&e, Some(keeper));
 
-    // Bonus for having multiple sources
-    let source_bonus = if valid_sources >= 3 { 10 } else { 0 };
+        PoolEvents::set_status(&e, new_status);
+        new_status
+    }
 
-    u32::min(100, base_confidence + source_bonus)
-}
+    fn set_status(e: Env, pool_status: u32) {
+        storage::extend_instance(&e);
+        let admin = storage::get_admin(&e);
+        admin.require_auth();
+
+        pool::execute_set_pool_status(&e, pool_status);
+
+        PoolEvents::set_status_admin(&e, admin, pool_status);
+    }
+
+    fn gulp(e: Env, asset: Address) -> i128 {
+        storage::extend_instance(&e);
+        let token_delta = pool::execute_gulp(&e, &asset, None);
+
+        PoolEvents::gulp(&e, asset, token_delta);
+        token_delta
+    }
+
+    fn keeper_gulp(e: Env, asset: Address, keeper: Address) -> i128 {
+        storage::extend_instance(&e);
+        let token_delta = pool::execute_gulp(&e, &asset, Some(keeper));
+
+        PoolEvents::gulp(&e, asset, token_delta);
+        token_delta
+    }
+
+    fn del_stale_auctions(
+        e: Env,
+        keeper: Address,
+        auction_type: u32,
+        start: u32,
+        count: u32,
+    ) -> u32 {
+        storage::extend_instance(&e);
+        auctions::execute_delete_stale_auctions(&e, &keeper, auction_type, start, count)
+    }
+
+    /********* Emission Functions **********/
+
+    fn gulp_emissions(e: Env) -> i128 {
+        storage::extend_instance(&e);
+        let emissions = emissions::gulp_emissions(&e, None);
+
+        PoolEvents::gulp_emissions(&e, emissions);
+        emissions
+    }
+
+    fn keeper_gulp_emissions(e: Env, keeper: Address) -> i128 {
+        storage::extend_instance(&e);
+        let emissions = emissions::gulp_emissions(&e, Some(keeper));
+
+        PoolEvents::gulp_emissions(&e, emissions);
+        emissions
+    }
 
-#[cfg(test)]
-mod test;
\ No newline at end of file
+    fn set_emissions_config(e: Env, res_emission_metadata: Vec<ReserveEmissionMetadata>) {
+        storage::extend_instance(&e);
+        storage::get_admin(&e).require_auth();
+
+        emissions::set_pool_emissions(&e, res_emission_metadata);
+    }
+
+    fn claim(e: Env, from: Address, reserve_token_ids: Vec<u32>, to: Address) -> i128 {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        let amount_claimed = emissions::execute_claim(&e, &from, &reserve_token_ids, &to);
+
+        PoolEvents::claim(&e, from, reserve_token_ids, amount_claimed);
+        amount_claimed
+    }
+
+    fn claim_and_supply(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        asset: Address,
+        collateral: bool,
+        min_tokens_out: i128,
+    ) -> Positions {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        pool::execute_claim_and_supply(
+            &e,
+            &from,
+            &reserve_token_ids,
+            &asset,
+            collateral,
+            min_tokens_out,
+        )
+    }
+
+    fn fund_reward_program(
+        e: Env,
+        funder: Address,
+        res_token_id: u32,
+        token: Address,
+        amount: i128,
+        expiration: u64,
+    ) {
+        storage::extend_instance(&e);
+        funder.require_auth();
+
+        emissions::execute_fund_reward_program(
+            &e,
+            &funder,
+            res_token_id,
+            &token,
+            amount,
+            expiration,
+        );
+    }
+
+    fn claim_rewards(
+        e: Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        to: Address,
+    ) -> Map<Address, i128> {
+        storage::extend_instance(&e);
+        from.require_auth();
+
+        emissions::execute_claim_rewards(&e, &from, &reserve_token_ids, &to)
+    }
+
+    fn get_reserve_emissions(e: Env, reserve_token_index: u32) -> Option<ReserveEmissionData> {
+        storage::get_res_emis_data(&e, &reserve_token_index)
+    }
+
+    fn get_user_emissions(
+        e: Env,
+        user: Address,
+        reserve_token_index: u32,
+    ) -> Option<UserEmissionData> {
+        emissions::load_user_emissions(&e, &user, reserve_token_index)
+    }
+
+    /***** Auction / Liquidation Functions *****/
+
+    fn new_auction(
+        e: Env,
+        auction_type: u32,
+        user: Address,
+        bid: Vec<Address>,
+        lot: Vec<Address>,
+        percent: u32,
+    ) -> AuctionData {
+        storage::extend_instance(&e);
+        let auction_data = auctions::create_auction(&e, auction_type, &user, &bid, &lot, percent);
+
+        PoolEvents::new_auction(&e, auction_type, user, percent, auction_data.clone());
+        auction_data
+    }
+
+    fn get_auction(e: Env, auction_type: u32, user: Address) -> AuctionData {
+        storage::get_auction(&e, &auction_type, &user)
+    }
+
+    fn del_auction(e: Env, auction_type: u32, user: Address) {
+        storage::extend_instance(&e);
+        auctions::delete_stale_auction(&e, auction_type, &user);
+
+        PoolEvents::delete_auction(&e, auction_type, user);
+    }
+
+    fn get_active_auctions(
+        e: Env,
+        auction_type: u32,
+        start: u32,
+        count: u32,
+    ) -> Vec<ActiveAuction> {
+        auctions::load_active_auctions(&e, auction_type, start, count)
+    }
+
+    fn get_auction_count(e: Env, auction_type: u32) -> u32 {
+        storage::get_auction_count(&e, &auction_type)
+    }
+
+    fn bad_debt(e: Env, user: Address) {
+        storage::extend_instance(&e);
+        pool::bad_debt(&e, &user);
+    }
+}
@@ -0,0 +1,29 @@
+/**
+ * Partial client for the backstop's Comet LP pool
+ */
+use soroban_sdk::{contractclient, Address, Env};
+
+#[allow(dead_code)]
+#[contractclient(name = "CometClient")]
+pub trait Comet {
+    /// Swap an exact amount of `token_in` for at least `min_amount_out` of `token_out`
+    ///
+    /// Returns the amount of `token_out` received and the spot price after the swap
+    ///
+    /// ### Arguments
+    /// * `token_in` - The address of the token being sold
+    /// * `token_amount_in` - The amount of `token_in` being sold
+    /// * `token_out` - The address of the token being bought
+    /// * `min_amount_out` - The minimum amount of `token_out` to receive
+    /// * `max_price` - The maximum spot price after the swap
+    /// * `user` - The address of the user performing the swap
+    fn swap_exact_amount_in(
+        e: Env,
+        token_in: Address,
+        token_amount_in: i128,
+        token_out: Address,
+        min_amount_out: i128,
+        max_price: i128,
+        user: Address,
+    ) -> (i128, i128);
+}
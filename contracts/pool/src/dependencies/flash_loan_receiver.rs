@@ -0,0 +1,17 @@
+/**
+ * Client for a flash loan receiver contract
+ */
+use soroban_sdk::{contractclient, Address, Env};
+
+#[allow(dead_code)]
+#[contractclient(name = "FlashLoanClient")]
+pub trait FlashLoanReceiver {
+    /// Execute an operation with the flash loaned tokens
+    ///
+    /// ### Arguments
+    /// * `caller` - The address of the user who took the flash loan
+    /// * `token` - The address of the flash loaned token
+    /// * `amount` - The amount of tokens flash loaned
+    /// * `fee` - The fee owed for the flash loan
+    fn exec_op(e: Env, caller: Address, token: Address, amount: i128, fee: i128);
+}
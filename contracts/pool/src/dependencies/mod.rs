@@ -1,2 +1,11 @@
 mod backstop;
 pub use backstop::{Client as BackstopClient, PoolBackstopData};
+
+mod comet;
+pub use comet::CometClient;
+
+mod flash_loan_receiver;
+pub use flash_loan_receiver::FlashLoanClient;
+
+mod pool;
+pub use pool::PoolClient;
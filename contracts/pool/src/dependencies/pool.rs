@@ -0,0 +1,28 @@
+/**
+ * Partial client for another lending pool
+ */
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+use crate::{Positions, Request};
+
+#[allow(dead_code)]
+#[contractclient(name = "PoolClient")]
+pub trait Pool {
+    /// Submit a set of requests to the pool where `from` takes on the position, `spender` sends
+    /// any required tokens to the pool and `to` receives any tokens sent from the pool
+    ///
+    /// Returns the new positions for `from`
+    ///
+    /// ### Arguments
+    /// * `from` - The address of the user whose positions are being modified
+    /// * `spender` - The address of the user who is sending tokens to the pool
+    /// * `to` - The address of the user who is receiving tokens from the pool
+    /// * `requests` - A vec of requests to be processed
+    fn submit(
+        e: Env,
+        from: Address,
+        spender: Address,
+        to: Address,
+        requests: Vec<Request>,
+    ) -> Positions;
+}
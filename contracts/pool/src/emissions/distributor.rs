@@ -6,47 +6,29 @@
 use crate::{
     constants::SCALAR_7,
     errors::PoolError,
-    pool::User,
+    pool::{Reserve, User},
     storage::{self, ReserveEmissionData, UserEmissionData},
     validator::require_nonnegative,
 };
 
+use super::rewards;
+
 /// Performs a claim against the given "reserve_token_ids" for "from"
 pub fn execute_claim(e: &Env, from: &Address, reserve_token_ids: &Vec<u32>, to: &Address) -> i128 {
     let from_state = User::load(e, from);
     let reserve_list = storage::get_res_list(e);
     let mut to_claim = 0;
     for reserve_token_id in reserve_token_ids.clone() {
-        let reserve_index = reserve_token_id / 2;
-        let reserve_addr = reserve_list.get(reserve_index);
-        match reserve_addr {
-            Some(res_address) => {
-                let reserve_config = storage::get_res_config(e, &res_address);
-                let reserve_data = storage::get_res_data(e, &res_address);
-                let (user_balance, supply) = match reserve_token_id % 2 {
-                    0 => (
-                        from_state.get_liabilities(reserve_index),
-                        reserve_data.d_supply,
-                    ),
-                    1 => (
-                        from_state.get_total_supply(reserve_index),
-                        reserve_data.b_supply,
-                    ),
-                    _ => panic_with_error!(e, PoolError::BadRequest),
-                };
-                to_claim += claim_emissions(
-                    e,
-                    reserve_token_id,
-                    supply,
-                    10i128.pow(reserve_config.decimals),
-                    from,
-                    user_balance,
-                );
-            }
-            None => {
-                panic_with_error!(e, PoolError::BadRequest)
-            }
-        }
+        let (supply, supply_scalar, user_balance) =
+            load_reserve_token_balance(e, &reserve_list, &from_state, reserve_token_id);
+        to_claim += claim_emissions(
+            e,
+            reserve_token_id,
+            supply,
+            supply_scalar,
+            from,
+            user_balance,
+        );
     }
 
     if to_claim > 0 {
@@ -62,6 +44,77 @@ pub fn execute_claim(e: &Env, from: &Address, reserve_token_ids: &Vec<u32>, to:
     to_claim
 }
 
+/// Load the supply, scalar and user balance of a reserve token
+///
+/// Returns (supply, supply_scalar, user_balance)
+///
+/// ### Arguments
+/// * `reserve_list` - The pool's reserve list
+/// * `user` - The user to load the balance for
+/// * `res_token_id` - The reserve token id
+///
+/// ### Panics
+/// If the reserve token does not exist
+pub(super) fn load_reserve_token_balance(
+    e: &Env,
+    reserve_list: &Vec<Address>,
+    user: &User,
+    res_token_id: u32,
+) -> (i128, i128, i128) {
+    let reserve_index = res_token_id / 2;
+    match reserve_list.get(reserve_index) {
+        Some(res_address) => {
+            let reserve_config = storage::get_res_config(e, &res_address);
+            let reserve_data = storage::get_res_data(e, &res_address);
+            let (user_balance, supply) = match res_token_id % 2 {
+                0 => (user.get_liabilities(reserve_index), reserve_data.d_supply),
+                1 => (
+                    user.get_total_supply(reserve_index),
+                    reserve_data.b_supply - storage::get_withdrawal_queued(e, &res_address),
+                ),
+                _ => panic_with_error!(e, PoolError::BadRequest),
+            };
+            (supply, 10i128.pow(reserve_config.decimals), user_balance)
+        }
+        None => {
+            panic_with_error!(e, PoolError::BadRequest)
+        }
+    }
+}
+
+/// Claim the emissions for a single reserve token for "user" based on their current balance
+///
+/// Returns the amount of tokens to claim
+///
+/// ### Arguments
+/// * `reserve` - The reserve the reserve token belongs to
+/// * `res_token_id` - The reserve token being claimed => (reserve index * 2 + (0 for debtToken or 1 for blendToken))
+/// * `user` - The user claiming for the reserve
+///
+/// ### Panics
+/// If the reserve token does not belong to the reserve
+pub fn claim_reserve_token(e: &Env, reserve: &Reserve, res_token_id: u32, user: &User) -> i128 {
+    let reserve_index = res_token_id / 2;
+    if reserve_index != reserve.config.index {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let (balance, supply) = match res_token_id % 2 {
+        0 => (user.get_liabilities(reserve_index), reserve.data.d_supply),
+        _ => (
+            user.get_total_supply(reserve_index),
+            reserve.emission_b_supply(e),
+        ),
+    };
+    claim_emissions(
+        e,
+        res_token_id,
+        supply,
+        reserve.scalar,
+        &user.address,
+        balance,
+    )
+}
+
 /// Update the emissions information about a reserve token. Must be called before any update
 /// is made to the supply of debtTokens or blendTokens.
 ///
@@ -88,6 +141,7 @@ pub fn update_emissions(
     user: &Address,
     balance: i128,
 ) {
+    rewards::update_reward_programs(e, res_token_id, supply, supply_scalar, user, balance);
     if let Some(res_emis_data) = update_emission_data(e, res_token_id, supply, supply_scalar) {
         update_user_emissions(
             e,
@@ -122,6 +176,7 @@ fn claim_emissions(
     user: &Address,
     balance: i128,
 ) -> i128 {
+    rewards::update_reward_programs(e, res_token_id, supply, supply_scalar, user, balance);
     if let Some(res_emis_data) = update_emission_data(e, res_token_id, supply, supply_scalar) {
         update_user_emissions(
             e,
@@ -155,35 +210,51 @@ pub(super) fn update_emission_data(
     supply: i128,
     supply_scalar: i128,
 ) -> Option<ReserveEmissionData> {
-    match storage::get_res_emis_data(e, &res_token_id) {
-        Some(mut res_emission_data) => {
-            if res_emission_data.last_time >= res_emission_data.expiration
-                || e.ledger().timestamp() == res_emission_data.last_time
-                || res_emission_data.eps == 0
-                || supply == 0
-            {
-                return Some(res_emission_data);
-            }
-
-            let ledger_timestamp = if e.ledger().timestamp() > res_emission_data.expiration {
-                res_emission_data.expiration
-            } else {
-                e.ledger().timestamp()
-            };
-
-            let additional_idx = (i128(ledger_timestamp - res_emission_data.last_time)
-                * i128(res_emission_data.eps))
-            .fixed_div_floor(&e, &supply, &supply_scalar);
-
-            res_emission_data.index += additional_idx;
-            res_emission_data.last_time = ledger_timestamp;
+    {
+        let mut res_emission_data = storage::get_res_emis_data(e, &res_token_id)?;
+        if accrue_emission_index(e, &mut res_emission_data, supply, supply_scalar) {
             storage::set_res_emis_data(e, &res_token_id, &res_emission_data);
-            Some(res_emission_data)
         }
-        None => return None, // no emission exist, no update is required
+        Some(res_emission_data)
     }
 }
 
+/// Accrue the emission index of a reserve token up to the current ledger timestamp
+///
+/// Returns true if the index was updated
+///
+/// ### Arguments
+/// * `emis_data` - The emission data of the reserve token
+/// * `supply` - The current supply of the reserve token
+/// * `supply_scalar` - The scalar of the reserve token
+pub(super) fn accrue_emission_index(
+    e: &Env,
+    emis_data: &mut ReserveEmissionData,
+    supply: i128,
+    supply_scalar: i128,
+) -> bool {
+    if emis_data.last_time >= emis_data.expiration
+        || e.ledger().timestamp() == emis_data.last_time
+        || emis_data.eps == 0
+        || supply == 0
+    {
+        return false;
+    }
+
+    let ledger_timestamp = if e.ledger().timestamp() > emis_data.expiration {
+        emis_data.expiration
+    } else {
+        e.ledger().timestamp()
+    };
+
+    let additional_idx = (i128(ledger_timestamp - emis_data.last_time) * i128(emis_data.eps))
+        .fixed_div_floor(e, &supply, &supply_scalar);
+
+    emis_data.index += additional_idx;
+    emis_data.last_time = ledger_timestamp;
+    true
+}
+
 fn update_user_emissions(
     e: &Env,
     res_emis_data: &ReserveEmissionData,
@@ -193,30 +264,77 @@ fn update_user_emissions(
     balance: i128,
     claim: bool,
 ) -> i128 {
-    if let Some(user_data) = storage::get_user_emissions(e, user, &res_token_id) {
-        if user_data.index != res_emis_data.index || claim {
+    let user_data = load_user_emissions(e, user, res_token_id);
+    match calc_user_accrual(e, res_emis_data, user_data, supply_scalar, balance, claim) {
+        Some(accrual) => {
+            set_user_emissions(e, user, res_token_id, res_emis_data.index, accrual, claim)
+        }
+        None => 0,
+    }
+}
+
+/// Load the user's emission data for the reserve token
+///
+/// Data recorded against a retired reserve that previously held the reserve token's index is
+/// moved to the index floor. The retired reserve had no supply or liabilities when it was
+/// retired, so the user's accrued emissions are final and carry over to remain claimable.
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_id` - The reserve token being acted against
+pub fn load_user_emissions(e: &Env, user: &Address, res_token_id: u32) -> Option<UserEmissionData> {
+    let user_data = storage::get_user_emissions(e, user, &res_token_id)?;
+    let floor = storage::get_res_emis_floor(e, &res_token_id);
+    if user_data.index < floor {
+        Some(UserEmissionData {
+            index: floor,
+            accrued: user_data.accrued,
+        })
+    } else {
+        Some(user_data)
+    }
+}
+
+/// Calculate the total emissions accrued by a user against the reserve token's emission index
+///
+/// Returns None if the user's emission data is already up to date and `force` is false
+///
+/// ### Arguments
+/// * `emis_data` - The emission data of the reserve token
+/// * `user_data` - The user's emission data for the reserve token, if any
+/// * `supply_scalar` - The scalar of the reserve token
+/// * `balance` - The current balance of the user
+/// * `force` - If the accrual should be returned even if the user is up to date
+pub(super) fn calc_user_accrual(
+    e: &Env,
+    emis_data: &ReserveEmissionData,
+    user_data: Option<UserEmissionData>,
+    supply_scalar: i128,
+    balance: i128,
+    force: bool,
+) -> Option<i128> {
+    if let Some(user_data) = user_data {
+        if user_data.index != emis_data.index || force {
             let mut accrual = user_data.accrued;
             if balance != 0 {
-                let delta_index = res_emis_data.index - user_data.index;
+                let delta_index = emis_data.index - user_data.index;
                 require_nonnegative(e, &delta_index);
                 let to_accrue = balance.fixed_mul_floor(
                     e,
-                    &(res_emis_data.index - user_data.index),
+                    &(emis_data.index - user_data.index),
                     &(supply_scalar * SCALAR_7),
                 );
                 accrual += to_accrue;
             }
-            return set_user_emissions(e, user, res_token_id, res_emis_data.index, accrual, claim);
+            return Some(accrual);
         }
-        0
+        None
     } else if balance == 0 {
         // first time the user registered an action with the asset since emissions were added
-        return set_user_emissions(e, user, res_token_id, res_emis_data.index, 0, claim);
+        Some(0)
     } else {
         // user had tokens before emissions began, they are due any historical emissions
-        let to_accrue =
-            balance.fixed_mul_floor(e, &res_emis_data.index, &(supply_scalar * SCALAR_7));
-        return set_user_emissions(e, user, res_token_id, res_emis_data.index, to_accrue, claim);
+        Some(balance.fixed_mul_floor(e, &emis_data.index, &(supply_scalar * SCALAR_7)))
     }
 }
 
@@ -289,7 +407,7 @@ fn test_update_emissions() {
                 accrued: 0_1000000,
             };
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
@@ -339,7 +457,7 @@ fn test_update_emissions_no_data_ignores() {
         let user_position: i128 = 2_0000000;
         e.as_contract(&pool, || {
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             update_emissions(
                 &e,
@@ -389,7 +507,7 @@ fn test_update_emissions_negative_time_diff() {
                 accrued: 0_1000000,
             };
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
@@ -438,7 +556,7 @@ fn test_update_emission_no_overflow() {
                 accrued: 0_1000000,
             };
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
@@ -502,7 +620,7 @@ fn test_claim_emissions() {
                 accrued: 0_1000000,
             };
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
@@ -553,7 +671,7 @@ fn test_claim_emissions_no_config_ignores() {
         let user_position: i128 = 2_0000000;
         e.as_contract(&pool, || {
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             claim_emissions(
                 &e,
@@ -593,7 +711,7 @@ fn test_update_emission_data_no_config_returns_none() {
         let supply_scalar = 1_0000000;
         e.as_contract(&pool, || {
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             // no emission information stored
 
@@ -638,7 +756,7 @@ fn test_update_emission_data_expired_returns_old() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -687,7 +805,7 @@ fn test_update_emission_data_updated_this_block_returns_old() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -736,7 +854,7 @@ fn test_update_emission_data_no_eps_returns_old() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -785,7 +903,7 @@ fn test_update_emission_data_no_supply_returns_old() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -834,7 +952,7 @@ fn test_update_emission_data_past_exp() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -880,7 +998,7 @@ fn test_update_emission_data_rounds_down() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
 
             storage::set_res_emis_data(&e, &res_token_index, &reserve_emission_data);
 
@@ -929,7 +1047,7 @@ fn test_update_user_emissions_first_time() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             update_user_emissions(
                 &e,
                 &reserve_emission_data,
@@ -977,7 +1095,7 @@ fn test_update_user_emissions_first_time_had_tokens() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             update_user_emissions(
                 &e,
                 &reserve_emission_data,
@@ -1029,7 +1147,7 @@ fn test_update_user_emissions_no_bal_no_accrual() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
 
             update_user_emissions(
@@ -1084,7 +1202,7 @@ fn test_update_user_emissions_if_accrued_skips() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
 
             update_user_emissions(
@@ -1138,7 +1256,7 @@ fn test_update_user_emissions_accrues() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
 
             update_user_emissions(
@@ -1193,7 +1311,7 @@ fn test_update_user_emissions_claim_returns_accrual() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
 
             let result = update_user_emissions(
@@ -1245,7 +1363,7 @@ fn test_update_user_emissions_claim_first_time_claims_tokens() {
             };
 
             let res_token_type = 0;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             let result = update_user_emissions(
                 &e,
                 &reserve_emission_data,
@@ -1300,7 +1418,7 @@ fn test_update_user_emissions_negative_index() {
             };
 
             let res_token_type = 1;
-            let res_token_index = 1 * 2 + res_token_type;
+            let res_token_index = 2 + res_token_type;
             storage::set_user_emissions(&e, &samwise, &res_token_index, &user_emission_data);
 
             update_user_emissions(
@@ -1387,7 +1505,7 @@ fn test_execute_claim() {
                 index: 12345670000000,
                 accrued: 0_1000000,
             };
-            let res_token_index_0 = 0 * 2 + 0; // d_token for reserve 0
+            let res_token_index_0 = 0; // d_token for reserve 0
 
             let reserve_emission_data_1 = ReserveEmissionData {
                 expiration: 1600000000,
@@ -1399,7 +1517,7 @@ fn test_execute_claim() {
                 index: 12345670000000,
                 accrued: 1_0000000,
             };
-            let res_token_index_1 = 1 * 2 + 1; // b_token for reserve 1
+            let res_token_index_1 = 2 + 1; // b_token for reserve 1
 
             storage::set_res_emis_data(&e, &res_token_index_0, &reserve_emission_data_0);
             storage::set_user_emissions(&e, &samwise, &res_token_index_0, &user_emission_data_0);
@@ -1512,7 +1630,7 @@ fn test_execute_claim_with_already_claimed_reserve() {
                 index: 12345670000000,
                 accrued: 0_1000000,
             };
-            let res_token_index_0 = 0 * 2 + 0; // d_token for reserve 0
+            let res_token_index_0 = 0; // d_token for reserve 0
 
             let reserve_emission_data_1 = ReserveEmissionData {
                 expiration: 1600000000,
@@ -1524,7 +1642,7 @@ fn test_execute_claim_with_already_claimed_reserve() {
                 index: 13456780000000,
                 accrued: 0,
             };
-            let res_token_index_1 = 1 * 2 + 1; // b_token for reserve 1
+            let res_token_index_1 = 2 + 1; // b_token for reserve 1
 
             storage::set_res_emis_data(&e, &res_token_index_0, &reserve_emission_data_0);
             storage::set_user_emissions(&e, &samwise, &res_token_index_0, &user_emission_data_0);
@@ -1638,7 +1756,7 @@ fn test_calc_claim_with_invalid_reserve_panics() {
                 index: 1234567,
                 accrued: 0_1000000,
             };
-            let res_token_index_0 = 0 * 2 + 0; // d_token for reserve 0
+            let res_token_index_0 = 0; // d_token for reserve 0
 
             let reserve_emission_data_1 = ReserveEmissionData {
                 expiration: 1600000000,
@@ -1650,7 +1768,7 @@ fn test_calc_claim_with_invalid_reserve_panics() {
                 index: 1234567,
                 accrued: 1_0000000,
             };
-            let res_token_index_1 = 1 * 2 + 1; // b_token for reserve 1
+            let res_token_index_1 = 2 + 1; // b_token for reserve 1
 
             storage::set_res_emis_data(&e, &res_token_index_0, &reserve_emission_data_0);
             storage::set_user_emissions(&e, &samwise, &res_token_index_0, &user_emission_data_0);
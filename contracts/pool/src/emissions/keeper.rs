@@ -0,0 +1,238 @@
+use crate::{
+    constants::{KEEPER_PERIOD, SCALAR_7},
+    errors::PoolError,
+    events::PoolEvents,
+    storage::{self, KeeperConfig},
+};
+use sep_41_token::TokenClient;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// Update the pool's keeper reward configuration
+///
+/// ### Arguments
+/// * `config` - The new keeper reward configuration
+///
+/// ### Panics
+/// If the reward is negative, the reward exceeds the max reward per period, or the
+/// gulp threshold is zero or over 100%. A zero threshold would reward gulping dust.
+pub fn execute_set_keeper_config(e: &Env, config: &KeeperConfig) {
+    if config.reward < 0
+        || config.max_per_period < config.reward
+        || config.gulp_threshold == 0
+        || i128::from(config.gulp_threshold) > SCALAR_7
+    {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+    storage::set_keeper_config(e, config);
+
+    PoolEvents::set_keeper_config(
+        e,
+        config.reward,
+        config.max_per_period,
+        config.gulp_threshold,
+    );
+}
+
+/// Set aside BLND from newly gulped emissions to top the keeper budget up to the max
+/// reward per period
+///
+/// Returns the amount of emissions set aside for keepers
+///
+/// ### Arguments
+/// * `new_emissions` - The amount of new emissions gulped by the pool
+pub(super) fn fund_keeper_budget(e: &Env, new_emissions: i128) -> i128 {
+    let config = storage::get_keeper_config(e);
+    let mut data = storage::get_keeper_data(e);
+    let to_fund = (config.max_per_period - data.budget)
+        .max(0)
+        .min(new_emissions);
+    if to_fund > 0 {
+        data.budget += to_fund;
+        storage::set_keeper_data(e, &data);
+    }
+    to_fund
+}
+
+/// Pay the keeper reward for a maintenance call that changed the pool's state
+///
+/// The reward is paid in BLND from the keeper budget, and is capped by the max reward
+/// per keeper period.
+///
+/// Returns the amount of BLND paid to the keeper
+///
+/// ### Arguments
+/// * `keeper` - The address of the keeper
+pub fn pay_keeper_reward(e: &Env, keeper: &Address) -> i128 {
+    let config = storage::get_keeper_config(e);
+    if config.reward == 0 {
+        return 0;
+    }
+
+    let mut data = storage::get_keeper_data(e);
+    let timestamp = e.ledger().timestamp();
+    if timestamp >= data.period_start + KEEPER_PERIOD {
+        data.period_start = timestamp;
+        data.paid = 0;
+    }
+    let reward = config
+        .reward
+        .min(config.max_per_period - data.paid)
+        .min(data.budget);
+    if reward <= 0 {
+        return 0;
+    }
+    data.paid += reward;
+    data.budget -= reward;
+    storage::set_keeper_data(e, &data);
+
+    // the keeper budget is held as part of the pool's BLND allowance from the backstop
+    TokenClient::new(e, &storage::get_blnd_token(e)).transfer_from(
+        &e.current_contract_address(),
+        &storage::get_backstop(e),
+        keeper,
+        &reward,
+    );
+
+    PoolEvents::keeper_reward(e, keeper.clone(), reward);
+    reward
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{storage::KeeperData, testutils};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[test]
+    fn test_fund_keeper_budget() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            storage::set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 1_0000000,
+                    max_per_period: 10_0000000,
+                    gulp_threshold: 0_0100000,
+                },
+            );
+
+            let funded = fund_keeper_budget(&e, 4_0000000);
+            assert_eq!(funded, 4_0000000);
+            assert_eq!(storage::get_keeper_data(&e).budget, 4_0000000);
+
+            // only tops up to the max reward per period
+            let funded = fund_keeper_budget(&e, 100_0000000);
+            assert_eq!(funded, 6_0000000);
+            assert_eq!(storage::get_keeper_data(&e).budget, 10_0000000);
+
+            let funded = fund_keeper_budget(&e, 100_0000000);
+            assert_eq!(funded, 0);
+        });
+    }
+
+    #[test]
+    fn test_pay_keeper_reward() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1500000000);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let keeper = Address::generate(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (backstop, _) = testutils::create_backstop(
+            &e,
+            &pool,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            &blnd,
+        );
+        e.as_contract(&backstop, || {
+            blnd_client.approve(&backstop, &pool, &100_0000000, &1000000);
+        });
+        blnd_client.mint(&backstop, &100_0000000);
+
+        e.as_contract(&pool, || {
+            storage::set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 1_0000000,
+                    max_per_period: 2_5000000,
+                    gulp_threshold: 0,
+                },
+            );
+            storage::set_keeper_data(
+                &e,
+                &KeeperData {
+                    budget: 5_0000000,
+                    period_start: 0,
+                    paid: 0,
+                },
+            );
+
+            assert_eq!(pay_keeper_reward(&e, &keeper), 1_0000000);
+            assert_eq!(pay_keeper_reward(&e, &keeper), 1_0000000);
+            // capped by the max reward per period
+            assert_eq!(pay_keeper_reward(&e, &keeper), 0_5000000);
+            assert_eq!(pay_keeper_reward(&e, &keeper), 0);
+
+            let data = storage::get_keeper_data(&e);
+            assert_eq!(data.period_start, 1500000000);
+            assert_eq!(data.paid, 2_5000000);
+            assert_eq!(data.budget, 2_5000000);
+        });
+        assert_eq!(blnd_client.balance(&keeper), 2_5000000);
+
+        // the cap resets for the next period, but is limited by the remaining budget
+        e.ledger().set_timestamp(1500000000 + KEEPER_PERIOD);
+        e.as_contract(&pool, || {
+            let mut data = storage::get_keeper_data(&e);
+            data.budget = 0_2000000;
+            storage::set_keeper_data(&e, &data);
+
+            assert_eq!(pay_keeper_reward(&e, &keeper), 0_2000000);
+            assert_eq!(pay_keeper_reward(&e, &keeper), 0);
+        });
+        assert_eq!(blnd_client.balance(&keeper), 2_7000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_set_keeper_config_reward_over_max() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 2_0000000,
+                    max_per_period: 1_0000000,
+                    gulp_threshold: 0_0100000,
+                },
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_set_keeper_config_zero_gulp_threshold() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 1_0000000,
+                    max_per_period: 10_0000000,
+                    gulp_threshold: 0,
+                },
+            );
+        });
+    }
+}
@@ -11,7 +11,7 @@
     contracttype, map, panic_with_error, unwrap::UnwrapOptimized, Address, Env, Map, Vec,
 };
 
-use super::distributor;
+use super::{distributor, keeper};
 
 // Types
 
@@ -29,7 +29,7 @@ pub struct ReserveEmissionMetadata {
 ///
 /// ### Arguments
 /// * `res_emission_metadata` - A vector of `ReserveEmissionMetadata` that details each reserve token's share
-///                             if the total pool eps
+///   if the total pool eps
 ///
 /// ### Panics
 /// If any res_emission_metadata is included where share is 0, the reserve index is invalid,
@@ -56,13 +56,20 @@ pub fn set_pool_emissions(e: &Env, res_emission_metadata: Vec<ReserveEmissionMet
 ///
 /// Returns the number of new tokens distributed for emissions
 ///
+/// ### Arguments
+/// * `keeper` - The address of the keeper performing the gulp, if any. The keeper is rewarded
+///   from the keeper budget.
+///
 /// ### Panics
 /// If the pool is not in the backstop reward zone
-pub fn gulp_emissions(e: &Env) -> i128 {
+pub fn gulp_emissions(e: &Env, keeper: Option<Address>) -> i128 {
     let backstop = storage::get_backstop(e);
     let new_emissions =
         BackstopClient::new(e, &backstop).gulp_emissions(&e.current_contract_address());
     do_gulp_emissions(e, new_emissions);
+    if let Some(keeper) = keeper {
+        keeper::pay_keeper_reward(e, &keeper);
+    }
     new_emissions
 }
 
@@ -71,6 +78,8 @@ fn do_gulp_emissions(e: &Env, new_emissions: i128) {
     if new_emissions < SCALAR_7 {
         panic_with_error!(e, PoolError::BadRequest)
     }
+    // hold back enough emissions to fund the keeper budget
+    let new_emissions = new_emissions - keeper::fund_keeper_budget(e, new_emissions);
     let pool_emissions = storage::get_pool_emissions(e);
     let reserve_list = storage::get_res_list(e);
     let mut pool_emis_enabled: Vec<(ReserveConfig, Address, u32, u64)> = Vec::new(e);
@@ -117,7 +126,7 @@ fn update_reserve_emission_eps(
     let reserve_data = storage::get_res_data(e, asset);
     let supply = match res_token_id % 2 {
         0 => reserve_data.d_supply,
-        1 => reserve_data.b_supply,
+        1 => reserve_data.b_supply - storage::get_withdrawal_queued(e, asset),
         _ => panic_with_error!(e, PoolError::BadRequest),
     };
     let expiration: u64 = e.ledger().timestamp() + 7 * 24 * 60 * 60;
@@ -2,4 +2,10 @@
 pub use manager::{gulp_emissions, set_pool_emissions, ReserveEmissionMetadata};
 
 mod distributor;
-pub use distributor::{execute_claim, update_emissions};
+pub use distributor::{claim_reserve_token, execute_claim, load_user_emissions, update_emissions};
+
+mod keeper;
+pub use keeper::{execute_set_keeper_config, pay_keeper_reward};
+
+mod rewards;
+pub use rewards::{execute_claim_rewards, execute_fund_reward_program};
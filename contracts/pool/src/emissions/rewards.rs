@@ -0,0 +1,324 @@
+use cast::{i128, u64};
+use sep_41_token::TokenClient;
+use soroban_sdk::{panic_with_error, Address, Env, Map, Vec};
+
+use crate::{
+    constants::{MAX_REWARD_PROGRAMS, SCALAR_7},
+    errors::PoolError,
+    events::PoolEvents,
+    pool::{Positions, User},
+    storage::{self, ReserveEmissionData, UserEmissionData},
+};
+
+use super::distributor::{
+    accrue_emission_index, calc_user_accrual, execute_claim, load_reserve_token_balance,
+};
+
+/// Fund an external reward program for a reserve token. The rewards are distributed to holders of
+/// the reserve token at a constant rate until `expiration`.
+///
+/// If a reward program for `token` already exists, any undistributed rewards are combined with
+/// `amount` and distributed until the new `expiration`.
+///
+/// `funder` MUST be authenticated before calling
+///
+/// ### Arguments
+/// * `funder` - The address funding the reward program
+/// * `res_token_id` - The reserve token id the rewards are for
+/// * `token` - The reward token
+/// * `amount` - The amount of reward tokens to add
+/// * `expiration` - The time the reward program ends
+///
+/// ### Panics
+/// * If the reserve token does not exist
+/// * If the reward token is BLND or a reserve asset of the pool
+/// * If the expiration is in the past or earlier than an ongoing program's expiration
+/// * If the reserve token has the max number of reward programs
+pub fn execute_fund_reward_program(
+    e: &Env,
+    funder: &Address,
+    res_token_id: u32,
+    token: &Address,
+    amount: i128,
+    expiration: u64,
+) {
+    // reward tokens are held by the pool, so they cannot share a balance with a reserve or BLND
+    if amount <= 0
+        || storage::has_res(e, token)
+        || token == &storage::get_blnd_token(e)
+        || expiration <= e.ledger().timestamp()
+    {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let reserve_list = storage::get_res_list(e);
+    let funder_state = User {
+        address: funder.clone(),
+        positions: Positions::env_default(e),
+    };
+    let (supply, supply_scalar, _) =
+        load_reserve_token_balance(e, &reserve_list, &funder_state, res_token_id);
+
+    let now = e.ledger().timestamp();
+    let mut reward_tokens = storage::get_reward_tokens(e, &res_token_id);
+    let mut undistributed: i128 = 0;
+    let mut index: i128 = 0;
+    match storage::get_reward_data(e, &res_token_id, token) {
+        Some(mut reward_data) => {
+            accrue_emission_index(e, &mut reward_data, supply, supply_scalar);
+            if reward_data.expiration > now {
+                if expiration < reward_data.expiration {
+                    panic_with_error!(e, PoolError::BadRequest);
+                }
+                undistributed =
+                    (i128(reward_data.expiration - now) * i128(reward_data.eps)) / SCALAR_7;
+            }
+            index = reward_data.index;
+        }
+        None => {
+            if reward_tokens.len() >= MAX_REWARD_PROGRAMS {
+                panic_with_error!(e, PoolError::BadRequest);
+            }
+            reward_tokens.push_back(token.clone());
+            storage::set_reward_tokens(e, &res_token_id, &reward_tokens);
+        }
+    }
+
+    let eps = u64((undistributed + amount) * SCALAR_7 / i128(expiration - now))
+        .unwrap_or_else(|_| panic_with_error!(e, PoolError::BadRequest));
+    storage::set_reward_data(
+        e,
+        &res_token_id,
+        token,
+        &ReserveEmissionData {
+            expiration,
+            eps,
+            index,
+            last_time: now,
+        },
+    );
+
+    TokenClient::new(e, token).transfer(funder, &e.current_contract_address(), &amount);
+
+    PoolEvents::fund_reward_program(
+        e,
+        funder.clone(),
+        res_token_id,
+        token.clone(),
+        amount,
+        eps,
+        expiration,
+    );
+}
+
+/// Performs a claim of the BLND emissions and all external rewards against the given
+/// "reserve_token_ids" for "from"
+///
+/// Returns a map of each reward token to the amount claimed
+///
+/// ### Arguments
+/// * `from` - The address claiming the rewards
+/// * `reserve_token_ids` - The reserve token ids to claim rewards for
+/// * `to` - The address receiving the rewards
+pub fn execute_claim_rewards(
+    e: &Env,
+    from: &Address,
+    reserve_token_ids: &Vec<u32>,
+    to: &Address,
+) -> Map<Address, i128> {
+    let mut claimed: Map<Address, i128> = Map::new(e);
+
+    // claiming BLND emissions also accrues all external rewards for the reserve tokens
+    let blnd_claimed = execute_claim(e, from, reserve_token_ids, to);
+    if blnd_claimed > 0 {
+        claimed.set(storage::get_blnd_token(e), blnd_claimed);
+    }
+
+    let mut external_claimed: Map<Address, i128> = Map::new(e);
+    for res_token_id in reserve_token_ids.iter() {
+        for token in storage::get_reward_tokens(e, &res_token_id).iter() {
+            if let Some(mut user_data) = storage::get_user_reward(e, from, &res_token_id, &token) {
+                if user_data.accrued > 0 {
+                    external_claimed.set(
+                        token.clone(),
+                        external_claimed.get(token.clone()).unwrap_or(0) + user_data.accrued,
+                    );
+                    user_data.accrued = 0;
+                    storage::set_user_reward(e, from, &res_token_id, &token, &user_data);
+                }
+            }
+        }
+    }
+    for (token, amount) in external_claimed.iter() {
+        TokenClient::new(e, &token).transfer(&e.current_contract_address(), to, &amount);
+        claimed.set(token, amount);
+    }
+
+    PoolEvents::claim_rewards(e, from.clone(), reserve_token_ids.clone(), claimed.clone());
+    claimed
+}
+
+/// Update the external reward programs of a reserve token and accrue the rewards due to "user".
+/// Must be called before any update is made to the supply of debtTokens or blendTokens.
+///
+/// ### Arguments
+/// * `res_token_id` - The reserve token being acted against
+/// * `supply` - The current supply of the reserve token
+/// * `supply_scalar` - The scalar of the reserve token
+/// * `user` - The user performing an action against the reserve
+/// * `balance` - The current balance of the user
+pub(super) fn update_reward_programs(
+    e: &Env,
+    res_token_id: u32,
+    supply: i128,
+    supply_scalar: i128,
+    user: &Address,
+    balance: i128,
+) {
+    for token in storage::get_reward_tokens(e, &res_token_id).iter() {
+        if let Some(mut reward_data) = storage::get_reward_data(e, &res_token_id, &token) {
+            if accrue_emission_index(e, &mut reward_data, supply, supply_scalar) {
+                storage::set_reward_data(e, &res_token_id, &token, &reward_data);
+            }
+            let user_data = storage::get_user_reward(e, user, &res_token_id, &token);
+            if let Some(accrued) =
+                calc_user_accrual(e, &reward_data, user_data, supply_scalar, balance, false)
+            {
+                storage::set_user_reward(
+                    e,
+                    user,
+                    &res_token_id,
+                    &token,
+                    &UserEmissionData {
+                        index: reward_data.index,
+                        accrued,
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{storage::PoolConfig, testutils};
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger},
+        vec,
+    };
+
+    #[test]
+    fn test_fund_reward_program_and_claim() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1500000000);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let partner = Address::generate(&e);
+
+        testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 100_0000000;
+        reserve_data.last_time = 1500000000;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let (reward_token, reward_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_client.mint(&partner, &1000_0000000);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                &e,
+                &PoolConfig {
+                    oracle: Address::generate(&e),
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 0,
+                    max_positions: 4,
+                },
+            );
+            // samwise holds 25% of the bTokens for the whole program
+            storage::set_user_positions(
+                &e,
+                &samwise,
+                &Positions {
+                    liabilities: map![&e],
+                    collateral: map![&e],
+                    supply: map![&e, (0, 25_0000000)],
+                },
+            );
+
+            // 100 tokens over 1000 seconds
+            execute_fund_reward_program(&e, &partner, 1, &reward_token, 100_0000000, 1500001000);
+            let reward_data = storage::get_reward_data(&e, &1, &reward_token).unwrap();
+            assert_eq!(reward_data.eps, 0_10000000000000);
+            assert_eq!(reward_data.index, 0);
+        });
+        assert_eq!(reward_client.balance(&pool), 100_0000000);
+
+        e.ledger().set_timestamp(1500000500);
+        e.as_contract(&pool, || {
+            let claimed = execute_claim_rewards(&e, &samwise, &vec![&e, 1], &frodo);
+            assert_eq!(claimed.len(), 1);
+            assert_eq!(claimed.get_unchecked(reward_token.clone()), 12_5000000);
+
+            // extend the program with 50 more tokens until 1500002000
+            execute_fund_reward_program(&e, &partner, 1, &reward_token, 50_0000000, 1500002000);
+            let reward_data = storage::get_reward_data(&e, &1, &reward_token).unwrap();
+            // (50 undistributed + 50 added) over 1500 seconds
+            assert_eq!(reward_data.eps, 0_06666666666666);
+            assert_eq!(reward_data.expiration, 1500002000);
+        });
+        assert_eq!(reward_client.balance(&frodo), 12_5000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_fund_reward_program_reserve_asset_panics() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.ledger().set_timestamp(1500000000);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (underlying, underlying_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+        underlying_client.mint(&bombadil, &100_0000000);
+
+        e.as_contract(&pool, || {
+            execute_fund_reward_program(&e, &bombadil, 1, &underlying, 100_0000000, 1500001000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_fund_reward_program_shorten_expiration_panics() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set_timestamp(1500000000);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+        let (reward_token, reward_client) = testutils::create_token_contract(&e, &bombadil);
+        reward_client.mint(&bombadil, &200_0000000);
+
+        e.as_contract(&pool, || {
+            execute_fund_reward_program(&e, &bombadil, 0, &reward_token, 100_0000000, 1500001000);
+            execute_fund_reward_program(&e, &bombadil, 0, &reward_token, 100_0000000, 1500000500);
+        });
+    }
+}
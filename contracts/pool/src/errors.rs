@@ -52,4 +52,6 @@ pub enum PoolError {
     InvalidLot = 1222,
     ReserveDisabled = 1223,
     MinCollateralNotMet = 1224,
+    MinBorrowNotMet = 1225,
+    MaxLiabilityExceeded = 1226,
 }
@@ -1,6 +1,6 @@
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Vec};
 
-use crate::{AuctionData, ReserveConfig};
+use crate::{storage::StatusPolicy, AuctionData, ReserveConfig};
 
 pub struct PoolEvents {}
 
@@ -14,7 +14,7 @@ impl PoolEvents {
     /// * admin - The current admin of the pool
     /// * new_admin - The new admin of the pool
     pub fn set_admin(e: &Env, admin: Address, new_admin: Address) {
-        let topics = (Symbol::new(&e, "set_admin"), admin);
+        let topics = (Symbol::new(e, "set_admin"), admin);
         e.events().publish(topics, new_admin);
     }
 
@@ -34,11 +34,58 @@ pub fn update_pool(
         max_positions: u32,
         min_collateral: i128,
     ) {
-        let topics = (Symbol::new(&e, "update_pool"), admin);
+        let topics = (Symbol::new(e, "update_pool"), admin);
         e.events()
             .publish(topics, (backstop_take_rate, max_positions, min_collateral));
     }
 
+    /// Emitted when the pool's loan limits are updated
+    ///
+    /// - topics - `["set_loan_limits"]`
+    /// - data - `[min_borrow: i128, max_liability: i128]`
+    ///
+    /// ### Arguments
+    /// * min_borrow - The minimum size of a single borrow request
+    /// * max_liability - The maximum liability a single user can hold
+    pub fn set_loan_limits(e: &Env, min_borrow: i128, max_liability: i128) {
+        let topics = (Symbol::new(e, "set_loan_limits"),);
+        e.events().publish(topics, (min_borrow, max_liability));
+    }
+
+    /// Emitted when the pool's base asset is updated
+    ///
+    /// - topics - `["set_base_asset"]`
+    /// - data - `base_asset: Option<Address>`
+    ///
+    /// ### Arguments
+    /// * base_asset - The asset prices are denominated in, or None for the oracle's base asset
+    pub fn set_base_asset(e: &Env, base_asset: Option<Address>) {
+        let topics = (Symbol::new(e, "set_base_asset"),);
+        e.events().publish(topics, base_asset);
+    }
+
+    /// Emitted when a base asset change is queued
+    ///
+    /// - topics - `["queue_set_base_asset"]`
+    /// - data - `[base_asset: Option<Address>, unlock_time: u64]`
+    ///
+    /// ### Arguments
+    /// * base_asset - The new base asset, or None for the oracle's base asset
+    /// * unlock_time - The earliest time the base asset can be set
+    pub fn queue_set_base_asset(e: &Env, base_asset: Option<Address>, unlock_time: u64) {
+        let topics = (Symbol::new(e, "queue_set_base_asset"),);
+        e.events().publish(topics, (base_asset, unlock_time));
+    }
+
+    /// Emitted when a queued base asset change is cancelled
+    ///
+    /// - topics - `["cancel_set_base_asset"]`
+    /// - data - `()`
+    pub fn cancel_set_base_asset(e: &Env) {
+        let topics = (Symbol::new(e, "cancel_set_base_asset"),);
+        e.events().publish(topics, ());
+    }
+
     /// Emitted when a new reserve configuration change is queued
     ///
     /// - topics - `["queue_set_reserve", admin: Address]`
@@ -49,7 +96,7 @@ pub fn update_pool(
     /// * asset - The asset to change the reserve configuration of
     /// * metadata - The new reserve configuration
     pub fn queue_set_reserve(e: &Env, admin: Address, asset: Address, metadata: ReserveConfig) {
-        let topics = (Symbol::new(&e, "queue_set_reserve"), admin);
+        let topics = (Symbol::new(e, "queue_set_reserve"), admin);
         e.events().publish(topics, (asset, metadata));
     }
 
@@ -62,7 +109,7 @@ pub fn queue_set_reserve(e: &Env, admin: Address, asset: Address, metadata: Rese
     /// * admin - The current admin of the pool
     /// * asset - The asset to cancel the reserve configuration change of
     pub fn cancel_set_reserve(e: &Env, admin: Address, asset: Address) {
-        let topics = (Symbol::new(&e, "cancel_set_reserve"), admin);
+        let topics = (Symbol::new(e, "cancel_set_reserve"), admin);
         e.events().publish(topics, asset);
     }
 
@@ -75,10 +122,60 @@ pub fn cancel_set_reserve(e: &Env, admin: Address, asset: Address) {
     /// * asset - The asset to change the reserve configuration of
     /// * index - The reserve index
     pub fn set_reserve(e: &Env, asset: Address, index: u32) {
-        let topics = (Symbol::new(&e, "set_reserve"),);
+        let topics = (Symbol::new(e, "set_reserve"),);
         e.events().publish(topics, (asset, index));
     }
 
+    /// Emitted when a reserve sunset is queued
+    ///
+    /// - topics - `["queue_sunset_reserve", asset: Address]`
+    /// - data - `unlock_time: u64`
+    ///
+    /// ### Arguments
+    /// * asset - The asset of the reserve being sunset
+    /// * unlock_time - The earliest time the sunset can begin
+    pub fn queue_sunset_reserve(e: &Env, asset: Address, unlock_time: u64) {
+        let topics = (Symbol::new(e, "queue_sunset_reserve"), asset);
+        e.events().publish(topics, unlock_time);
+    }
+
+    /// Emitted when a queued reserve sunset is cancelled
+    ///
+    /// - topics - `["cancel_sunset_reserve", asset: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * asset - The asset of the reserve
+    pub fn cancel_sunset_reserve(e: &Env, asset: Address) {
+        let topics = (Symbol::new(e, "cancel_sunset_reserve"), asset);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a reserve sunset begins
+    ///
+    /// - topics - `["sunset_reserve", asset: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * asset - The asset of the reserve being sunset
+    pub fn sunset_reserve(e: &Env, asset: Address) {
+        let topics = (Symbol::new(e, "sunset_reserve"), asset);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a sunset reserve is retired and its index can be reclaimed
+    ///
+    /// - topics - `["retire_reserve", asset: Address]`
+    /// - data - `index: u32`
+    ///
+    /// ### Arguments
+    /// * asset - The asset of the retired reserve
+    /// * index - The reserve index that can be reclaimed
+    pub fn retire_reserve(e: &Env, asset: Address, index: u32) {
+        let topics = (Symbol::new(e, "retire_reserve"), asset);
+        e.events().publish(topics, index);
+    }
+
     /// Emitted when pool status is updated (non-admin)
     ///
     /// - topics - `["set_status"]`
@@ -87,7 +184,7 @@ pub fn set_reserve(e: &Env, asset: Address, index: u32) {
     /// ### Arguments
     /// * new_status - The new pool status
     pub fn set_status(e: &Env, new_status: u32) {
-        let topics = (Symbol::new(&e, "set_status"),);
+        let topics = (Symbol::new(e, "set_status"),);
         e.events().publish(topics, new_status);
     }
 
@@ -100,7 +197,7 @@ pub fn set_status(e: &Env, new_status: u32) {
     /// * admin - The admin setting the pool status
     /// * pool_status - The new pool status
     pub fn set_status_admin(e: &Env, admin: Address, pool_status: u32) {
-        let topics = (Symbol::new(&e, "set_status"), admin);
+        let topics = (Symbol::new(e, "set_status"), admin);
         e.events().publish(topics, pool_status);
     }
 
@@ -126,10 +223,83 @@ pub fn reserve_emission_update(e: &Env, res_token_id: u32, eps: u64, expiration:
     /// ### Arguments
     /// * emissions - The amount of emissions gulped
     pub fn gulp_emissions(e: &Env, emissions: i128) {
-        let topics = (Symbol::new(&e, "gulp_emissions"),);
+        let topics = (Symbol::new(e, "gulp_emissions"),);
         e.events().publish(topics, emissions);
     }
 
+    /// Emitted when the pool's keeper reward configuration is updated
+    ///
+    /// - topics - `["set_keeper_config"]`
+    /// - data - `[reward: i128, max_per_period: i128, gulp_threshold: u32]`
+    ///
+    /// ### Arguments
+    /// * reward - The BLND paid to a keeper per rewarded maintenance call
+    /// * max_per_period - The max BLND paid to keepers per keeper period
+    /// * gulp_threshold - The min gulp as a pct of the reserve's total supply to reward
+    pub fn set_keeper_config(e: &Env, reward: i128, max_per_period: i128, gulp_threshold: u32) {
+        let topics = (Symbol::new(e, "set_keeper_config"),);
+        e.events()
+            .publish(topics, (reward, max_per_period, gulp_threshold));
+    }
+
+    /// Emitted when a keeper is rewarded for a maintenance call
+    ///
+    /// - topics - `["keeper_reward", keeper: Address]`
+    /// - data - `reward: i128`
+    ///
+    /// ### Arguments
+    /// * keeper - The address of the keeper
+    /// * reward - The amount of BLND paid to the keeper
+    pub fn keeper_reward(e: &Env, keeper: Address, reward: i128) {
+        let topics = (Symbol::new(e, "keeper_reward"), keeper);
+        e.events().publish(topics, reward);
+    }
+
+    /// Emitted when an external reward program is funded
+    ///
+    /// - topics - `["fund_reward_program", funder: Address]`
+    /// - data - `[res_token_id: u32, token: Address, amount: i128, eps: u64, expiration: u64]`
+    ///
+    /// ### Arguments
+    /// * funder - The address funding the reward program
+    /// * res_token_id - The reserve token ID the rewards are for
+    /// * token - The reward token
+    /// * amount - The amount of reward tokens added
+    /// * eps - The new emissions per second of the reward program
+    /// * expiration - The new expiration time of the reward program
+    pub fn fund_reward_program(
+        e: &Env,
+        funder: Address,
+        res_token_id: u32,
+        token: Address,
+        amount: i128,
+        eps: u64,
+        expiration: u64,
+    ) {
+        let topics = (Symbol::new(e, "fund_reward_program"), funder);
+        e.events()
+            .publish(topics, (res_token_id, token, amount, eps, expiration));
+    }
+
+    /// Emitted when external rewards are claimed
+    ///
+    /// - topics - `["claim_rewards", from: Address]`
+    /// - data - `[reserve_token_ids: Vec<u32>, claimed: Map<Address, i128>]`
+    ///
+    /// ### Arguments
+    /// * from - The address claiming the rewards
+    /// * reserve_token_ids - The reserve token IDs claimed
+    /// * claimed - The amount claimed of each reward token
+    pub fn claim_rewards(
+        e: &Env,
+        from: Address,
+        reserve_token_ids: Vec<u32>,
+        claimed: Map<Address, i128>,
+    ) {
+        let topics = (Symbol::new(e, "claim_rewards"), from);
+        e.events().publish(topics, (reserve_token_ids, claimed));
+    }
+
     /// Emitted when emissions are claimed
     ///
     /// - topics - `["claim", from: Address]`
@@ -140,7 +310,7 @@ pub fn gulp_emissions(e: &Env, emissions: i128) {
     /// * reserve_token_ids - The reserve token IDs claimed
     /// * amount_claimed - The amount claimed
     pub fn claim(e: &Env, from: Address, reserve_token_ids: Vec<u32>, amount_claimed: i128) {
-        let topics = (Symbol::new(&e, "claim"), from);
+        let topics = (Symbol::new(e, "claim"), from);
         e.events()
             .publish(topics, (reserve_token_ids, amount_claimed));
     }
@@ -303,6 +473,96 @@ pub fn flash_loan(
         e.events().publish(topics, (tokens_out, d_tokens_minted));
     }
 
+    /// Emitted when a user's positions are transferred to another user
+    ///
+    /// - topics - `["transfer_positions", from: Address, to: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * from - The address whose positions were transferred
+    /// * to - The address that received the positions
+    pub fn transfer_positions(e: &Env, from: Address, to: Address) {
+        let topics = (Symbol::new(e, "transfer_positions"), from, to);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when a user's positions are migrated from another pool
+    ///
+    /// - topics - `["migrate", source_pool: Address, from: Address]`
+    /// - data - `()`
+    ///
+    /// ### Arguments
+    /// * source_pool - The address of the pool the positions were migrated from
+    /// * from - The address whose positions were migrated
+    pub fn migrate(e: &Env, source_pool: Address, from: Address) {
+        let topics = (Symbol::new(e, "migrate"), source_pool, from);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when b_tokens are queued for withdrawal
+    ///
+    /// - topics - `["queue_withdrawal", asset: Address, from: Address]`
+    /// - data - `b_tokens_queued: i128`
+    ///
+    /// ### Arguments
+    /// * asset - The asset
+    /// * from - The address whose position is being modified
+    /// * b_tokens_queued - The amount of b_tokens queued
+    pub fn queue_withdrawal(e: &Env, asset: Address, from: Address, b_tokens_queued: i128) {
+        let topics = (Symbol::new(e, "queue_withdrawal"), asset, from);
+        e.events().publish(topics, b_tokens_queued);
+    }
+
+    /// Emitted when b_tokens are removed from the withdrawal queue
+    ///
+    /// - topics - `["dequeue_withdrawal", asset: Address, from: Address]`
+    /// - data - `b_tokens_dequeued: i128`
+    ///
+    /// ### Arguments
+    /// * asset - The asset
+    /// * from - The address whose position is being modified
+    /// * b_tokens_dequeued - The amount of b_tokens returned to the supply position
+    pub fn dequeue_withdrawal(e: &Env, asset: Address, from: Address, b_tokens_dequeued: i128) {
+        let topics = (Symbol::new(e, "dequeue_withdrawal"), asset, from);
+        e.events().publish(topics, b_tokens_dequeued);
+    }
+
+    /// Emitted when a queued withdrawal is fully or partially filled
+    ///
+    /// - topics - `["fill_withdrawal", asset: Address, user: Address]`
+    /// - data - `[tokens_out: i128, b_tokens_burnt: i128]`
+    ///
+    /// ### Arguments
+    /// * asset - The asset
+    /// * user - The address whose queued withdrawal was filled
+    /// * tokens_out - The amount of tokens credited to the user to claim
+    /// * b_tokens_burnt - The amount of queued b_tokens burnt
+    pub fn fill_withdrawal(
+        e: &Env,
+        asset: Address,
+        user: Address,
+        tokens_out: i128,
+        b_tokens_burnt: i128,
+    ) {
+        let topics = (Symbol::new(e, "fill_withdrawal"), asset, user);
+        e.events().publish(topics, (tokens_out, b_tokens_burnt));
+    }
+
+    /// Emitted when a user claims their filled withdrawals
+    ///
+    /// - topics - `["claim_withdrawal", asset: Address, from: Address]`
+    /// - data - `[to: Address, amount: i128]`
+    ///
+    /// ### Arguments
+    /// * asset - The asset
+    /// * from - The address whose filled withdrawals were claimed
+    /// * to - The address receiving the tokens
+    /// * amount - The amount of tokens claimed
+    pub fn claim_withdrawal(e: &Env, asset: Address, from: Address, to: Address, amount: i128) {
+        let topics = (Symbol::new(e, "claim_withdrawal"), asset, from);
+        e.events().publish(topics, (to, amount));
+    }
+
     /// Emitted when a reserve gulps excess tokens
     ///
     /// - topics - `["gulp", asset: Address]`
@@ -361,6 +621,136 @@ pub fn fill_auction(
             .publish(topics, (filler, fill_percent, filled_auction_data));
     }
 
+    /// Emitted when a status policy change is queued
+    ///
+    /// - topics - `["queue_set_status_policy"]`
+    /// - data - `[policy: StatusPolicy, unlock_time: u64]`
+    ///
+    /// ### Arguments
+    /// * policy - The new status policy
+    /// * unlock_time - The earliest time the policy can be set
+    pub fn queue_set_status_policy(e: &Env, policy: StatusPolicy, unlock_time: u64) {
+        let topics = (Symbol::new(e, "queue_set_status_policy"),);
+        e.events().publish(topics, (policy, unlock_time));
+    }
+
+    /// Emitted when a queued status policy change is cancelled
+    ///
+    /// - topics - `["cancel_set_status_policy"]`
+    /// - data - `()`
+    pub fn cancel_set_status_policy(e: &Env) {
+        let topics = (Symbol::new(e, "cancel_set_status_policy"),);
+        e.events().publish(topics, ());
+    }
+
+    /// Emitted when the status policy is updated
+    ///
+    /// - topics - `["set_status_policy"]`
+    /// - data - `policy: StatusPolicy`
+    ///
+    /// ### Arguments
+    /// * policy - The new status policy
+    pub fn set_status_policy(e: &Env, policy: StatusPolicy) {
+        let topics = (Symbol::new(e, "set_status_policy"),);
+        e.events().publish(topics, policy);
+    }
+
+    /// Emitted when a contract upgrade is queued
+    ///
+    /// - topics - `["queue_upgrade"]`
+    /// - data - `[wasm_hash: BytesN<32>, unlock_time: u64]`
+    ///
+    /// ### Arguments
+    /// * wasm_hash - The hash of the wasm the pool will be upgraded to
+    /// * unlock_time - The earliest time the upgrade can be applied
+    pub fn queue_upgrade(e: &Env, wasm_hash: BytesN<32>, unlock_time: u64) {
+        let topics = (Symbol::new(e, "queue_upgrade"),);
+        e.events().publish(topics, (wasm_hash, unlock_time));
+    }
+
+    /// Emitted when a queued contract upgrade is cancelled
+    ///
+    /// - topics - `["cancel_upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * wasm_hash - The hash of the cancelled wasm
+    pub fn cancel_upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "cancel_upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the contract is upgraded
+    ///
+    /// - topics - `["upgrade"]`
+    /// - data - `wasm_hash: BytesN<32>`
+    ///
+    /// ### Arguments
+    /// * wasm_hash - The hash of the new wasm
+    pub fn upgrade(e: &Env, wasm_hash: BytesN<32>) {
+        let topics = (Symbol::new(e, "upgrade"),);
+        e.events().publish(topics, wasm_hash);
+    }
+
+    /// Emitted when the pool's storage is migrated after an upgrade
+    ///
+    /// - topics - `["migrate_storage"]`
+    /// - data - `[from_version: u32, to_version: u32]`
+    ///
+    /// ### Arguments
+    /// * from_version - The storage version before the migration
+    /// * to_version - The storage version after the migration
+    pub fn migrate_storage(e: &Env, from_version: u32, to_version: u32) {
+        let topics = (Symbol::new(e, "migrate_storage"),);
+        e.events().publish(topics, (from_version, to_version));
+    }
+
+    /// Emitted when the soft liquidation configuration is updated
+    ///
+    /// - topics - `["set_soft_liq_config"]`
+    /// - data - `[min_hf: i128, discount: i128, max_pct: i128]`
+    ///
+    /// ### Arguments
+    /// * min_hf - The min health factor a user can be soft liquidated at
+    /// * discount - The discount on collateral received by the liquidator
+    /// * max_pct - The pct of a liability repaid per soft liquidation
+    pub fn set_soft_liquidation_config(e: &Env, min_hf: i128, discount: i128, max_pct: i128) {
+        let topics = (Symbol::new(e, "set_soft_liq_config"),);
+        e.events().publish(topics, (min_hf, discount, max_pct));
+    }
+
+    /// Emitted when a user opts in or out of soft liquidations
+    ///
+    /// - topics - `["set_soft_liq", user: Address]`
+    /// - data - `enabled: bool`
+    ///
+    /// ### Arguments
+    /// * user - The address of the user
+    /// * enabled - If soft liquidations are enabled for the user
+    pub fn set_soft_liquidation(e: &Env, user: Address, enabled: bool) {
+        let topics = (Symbol::new(e, "set_soft_liq"), user);
+        e.events().publish(topics, enabled);
+    }
+
+    /// Emitted when a user is soft liquidated
+    ///
+    /// - topics - `["soft_liquidation", user: Address]`
+    /// - data - `[filler: Address, filled_auction_data: AuctionData]`
+    ///
+    /// ### Arguments
+    /// * user - The address of the user soft liquidated
+    /// * filler - The address of the filler
+    /// * filled_auction_data - The liabilities (bid) and collateral (lot) moved to the filler
+    pub fn soft_liquidation(
+        e: &Env,
+        user: Address,
+        filler: Address,
+        filled_auction_data: AuctionData,
+    ) {
+        let topics = (Symbol::new(e, "soft_liquidation"), user);
+        e.events().publish(topics, (filler, filled_auction_data));
+    }
+
     /// Emitted when an auction is deleted
     ///
     /// - topics - `["delete_auction", auction_type: u32, user: Address]`
@@ -370,7 +760,7 @@ pub fn fill_auction(
     /// * auction_type - The type of auction
     /// * user - The address of the user
     pub fn delete_auction(e: &Env, auction_type: u32, user: Address) {
-        let topics = (Symbol::new(&e, "delete_auction"), auction_type, user);
+        let topics = (Symbol::new(e, "delete_auction"), auction_type, user);
         e.events().publish(topics, ());
     }
 }
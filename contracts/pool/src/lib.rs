@@ -18,12 +18,17 @@
 mod testutils;
 mod validator;
 
-pub use auctions::{AuctionData, AuctionType};
+pub use auctions::{ActiveAuction, AuctionData, AuctionType};
 pub use contract::*;
 pub use emissions::ReserveEmissionMetadata;
 pub use errors::PoolError;
-pub use pool::{FlashLoan, Positions, Request, RequestType};
+pub use pool::{
+    FlashLoan, PoolOverview, Positions, Request, RequestType, ReserveOverview,
+    WithdrawalQueuePosition,
+};
 pub use storage::{
-    AuctionKey, PoolConfig, PoolDataKey, PoolEmissionConfig, ReserveConfig, ReserveData,
-    ReserveEmissionData, UserEmissionData, UserReserveKey,
+    AuctionKey, KeeperConfig, KeeperData, PoolConfig, PoolDataKey, PoolEmissionConfig,
+    PoolLoanLimits, QueuedWithdrawal, ReserveConfig, ReserveData, ReserveEmissionData,
+    ReserveSunset, RewardKey, SoftLiquidationConfig, StatusPolicy, UserEmissionData,
+    UserReserveKey, UserRewardKey,
 };
@@ -1,11 +1,14 @@
+use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::Map;
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, vec, Address, Env, Vec};
 
 use crate::events::PoolEvents;
 use crate::AuctionType;
-use crate::{auctions, errors::PoolError, validator::require_nonnegative};
+use crate::{auctions, emissions, errors::PoolError, storage, validator::require_nonnegative};
 
 use super::pool::Pool;
+use super::reserve::Reserve;
+use super::withdrawal_queue::{dequeue_withdrawal, fill_withdrawal_queue, queue_withdrawal};
 use super::User;
 
 /// A request a user makes against the pool
@@ -14,7 +17,7 @@
 pub struct Request {
     pub request_type: u32,
     pub address: Address, // asset address or liquidatee
-    pub amount: i128,
+    pub amount: i128,     // amount, or a reserve token id of the asset's reserve for a claim
 }
 
 /// The type of request to be made against the pool
@@ -31,6 +34,10 @@ pub enum RequestType {
     FillBadDebtAuction = 7,
     FillInterestAuction = 8,
     DeleteLiquidationAuction = 9,
+    QueueWithdrawal = 10,
+    DequeueWithdrawal = 11,
+    ClaimEmissions = 12,
+    SoftLiquidation = 13,
 }
 
 impl RequestType {
@@ -50,6 +57,10 @@ pub fn from_u32(e: &Env, value: u32) -> Self {
             7 => RequestType::FillBadDebtAuction,
             8 => RequestType::FillInterestAuction,
             9 => RequestType::DeleteLiquidationAuction,
+            10 => RequestType::QueueWithdrawal,
+            11 => RequestType::DequeueWithdrawal,
+            12 => RequestType::ClaimEmissions,
+            13 => RequestType::SoftLiquidation,
             _ => panic_with_error!(e, PoolError::BadRequest),
         }
     }
@@ -68,6 +79,7 @@ pub struct Actions {
     pub pool_transfer: Map<Address, i128>,
     pub check_health: bool,
     pub check_max_util: Vec<Address>,
+    pub claimed_emissions: i128,
 }
 
 impl Actions {
@@ -78,6 +90,7 @@ pub fn new(e: &Env) -> Self {
             pool_transfer: Map::new(e),
             check_health: false,
             check_max_util: Vec::new(e),
+            claimed_emissions: 0,
         }
     }
 
@@ -97,6 +110,33 @@ pub fn add_for_pool_transfer(&mut self, asset: &Address, amount: i128) {
         );
     }
 
+    // Add claimed emissions the pool needs to pull from the backstop and transfer to "to"
+    pub fn add_for_claim_transfer(&mut self, blnd_token: &Address, amount: i128) {
+        self.claimed_emissions += amount;
+        self.add_for_pool_transfer(blnd_token, amount);
+    }
+
+    // Net the tokens the sender owes the pool against the tokens the pool owes "to"
+    pub fn net_transfers(&mut self, asset: &Address) {
+        let spender_amount = self.spender_transfer.get(asset.clone()).unwrap_or(0);
+        let pool_amount = self.pool_transfer.get(asset.clone()).unwrap_or(0);
+        let netted = spender_amount.min(pool_amount);
+        if netted <= 0 {
+            return;
+        }
+        if spender_amount == netted {
+            self.spender_transfer.remove(asset.clone());
+        } else {
+            self.spender_transfer
+                .set(asset.clone(), spender_amount - netted);
+        }
+        if pool_amount == netted {
+            self.pool_transfer.remove(asset.clone());
+        } else {
+            self.pool_transfer.set(asset.clone(), pool_amount - netted);
+        }
+    }
+
     // just a simple flag since we won't need
     // to switch it back to false once set to true.
     pub fn do_check_health(&mut self) {
@@ -149,6 +189,7 @@ pub fn build_actions_from_request(
                     request.amount,
                     b_tokens_minted,
                 );
+                fill_withdrawal_queue(e, pool, &request.address, request.amount);
             }
             RequestType::Withdraw => {
                 let (tokens_out, b_tokens_burnt) =
@@ -171,6 +212,7 @@ pub fn build_actions_from_request(
                     request.amount,
                     b_tokens_minted,
                 );
+                fill_withdrawal_queue(e, pool, &request.address, request.amount);
             }
             RequestType::WithdrawCollateral => {
                 let (tokens_out, b_tokens_burnt) =
@@ -203,6 +245,7 @@ pub fn build_actions_from_request(
                     tokens_in,
                     d_tokens_burnt,
                 );
+                fill_withdrawal_queue(e, pool, &request.address, tokens_in);
             }
             RequestType::FillUserLiquidationAuction => {
                 let filled_auction = auctions::fill(
@@ -274,12 +317,92 @@ pub fn build_actions_from_request(
                     from_state.address.clone(),
                 );
             }
+            RequestType::QueueWithdrawal => {
+                let b_tokens_queued =
+                    queue_withdrawal(e, pool, from_state, &request.address, request.amount);
+                PoolEvents::queue_withdrawal(
+                    e,
+                    request.address.clone(),
+                    from_state.address.clone(),
+                    b_tokens_queued,
+                );
+            }
+            RequestType::DequeueWithdrawal => {
+                let b_tokens_dequeued =
+                    dequeue_withdrawal(e, pool, from_state, &request.address, request.amount);
+                PoolEvents::dequeue_withdrawal(
+                    e,
+                    request.address.clone(),
+                    from_state.address.clone(),
+                    b_tokens_dequeued,
+                );
+            }
+            RequestType::ClaimEmissions => {
+                let (res_token_id, claimed) =
+                    apply_claim_emissions(e, &mut actions, pool, from_state, &request);
+                PoolEvents::claim(
+                    e,
+                    from_state.address.clone(),
+                    vec![e, res_token_id],
+                    claimed,
+                );
+            }
+            RequestType::SoftLiquidation => {
+                // Note: request amount is ignored
+                let filled = auctions::soft_liquidate(e, pool, &request.address, from_state);
+                actions.do_check_health();
+                PoolEvents::soft_liquidation(
+                    e,
+                    request.address.clone(),
+                    from_state.address.clone(),
+                    filled,
+                );
+            }
         }
     }
 
+    // claimed emissions can be used to cover BLND owed to the pool
+    if actions.claimed_emissions > 0 {
+        actions.net_transfers(&storage::get_blnd_token(e));
+    }
+
     actions
 }
 
+/// Apply a "claim_emissions" request to the pool
+///
+/// Each request claims a single reserve token id, passed as the request amount, which must
+/// belong to the reserve of the request address. To claim both the d_tokens and b_tokens of
+/// a reserve, submit one request for each.
+///
+/// Returns the reserve token id claimed and the amount of BLND claimed
+///
+/// ### Panics
+/// If the reserve token id is not the reserve's d_token id (index * 2) or b_token id
+/// (index * 2 + 1)
+fn apply_claim_emissions(
+    e: &Env,
+    actions: &mut Actions,
+    pool: &mut Pool,
+    user: &User,
+    request: &Request,
+) -> (u32, i128) {
+    let reserve = pool.load_reserve(e, &request.address, false);
+    let d_token_id = reserve.config.index * 2;
+    let res_token_id = if request.amount == i128::from(d_token_id) {
+        d_token_id
+    } else if request.amount == i128::from(d_token_id + 1) {
+        d_token_id + 1
+    } else {
+        panic_with_error!(e, PoolError::BadRequest)
+    };
+    let claimed = emissions::claim_reserve_token(e, &reserve, res_token_id, user);
+    if claimed > 0 {
+        actions.add_for_claim_transfer(&storage::get_blnd_token(e), claimed);
+    }
+    (res_token_id, claimed)
+}
+
 /// Apply a "supply" request to the pool
 ///
 /// Appends any necessary actions to the actions list, updates the user and pool's state
@@ -397,6 +520,7 @@ fn apply_borrow(
 ) -> i128 {
     let mut reserve = pool.load_reserve(e, &request.address, true);
     reserve.require_action_allowed(e, request.request_type);
+    require_min_borrow(e, pool, &reserve, request.amount);
     let d_tokens_minted = reserve.to_d_token_up(e, request.amount);
     user.add_liabilities(e, &mut reserve, d_tokens_minted);
     reserve.require_utilization_below_100(e);
@@ -407,6 +531,21 @@ fn apply_borrow(
     d_tokens_minted
 }
 
+/// Require that borrowing `amount` of the reserve's underlying meets the pool's minimum borrow
+///
+/// ### Panics
+/// If the borrow is smaller than the pool's minimum borrow
+pub(super) fn require_min_borrow(e: &Env, pool: &mut Pool, reserve: &Reserve, amount: i128) {
+    let min_borrow = storage::get_loan_limits(e).min_borrow;
+    if min_borrow > 0 {
+        let asset_to_base = pool.load_price(e, &reserve.asset);
+        let borrow_base = asset_to_base.fixed_mul_floor(e, &amount, &reserve.scalar);
+        if borrow_base < min_borrow {
+            panic_with_error!(e, PoolError::MinBorrowNotMet);
+        }
+    }
+}
+
 /// Apply a "repay" request to the pool
 ///
 /// Appends any necessary actions to the actions list, updates the user and pool's state
@@ -444,7 +583,7 @@ fn apply_repay(
 mod tests {
     use crate::{
         constants::SCALAR_7,
-        storage::{self, PoolConfig},
+        storage::{self, PoolConfig, ReserveEmissionData, UserEmissionData},
         testutils::{self, create_comet_lp_pool, create_pool},
         AuctionData, AuctionType, Positions,
     };
@@ -505,7 +644,7 @@ fn test_build_actions_from_request_supply() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
 
             let spender_transfer = actions.spender_transfer;
             let pool_transfer = actions.pool_transfer;
@@ -585,7 +724,7 @@ fn test_build_actions_from_request_withdraw() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
             assert_eq!(actions.check_max_util.len(), 0);
 
             let spender_transfer = actions.spender_transfer;
@@ -660,7 +799,7 @@ fn test_build_actions_from_request_withdraw_over_balance() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
             assert_eq!(actions.check_max_util.len(), 0);
 
             let spender_transfer = actions.spender_transfer;
@@ -787,7 +926,7 @@ fn test_build_actions_from_request_supply_collateral() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
 
             let spender_transfer = actions.spender_transfer;
             let pool_transfer = actions.pool_transfer;
@@ -866,7 +1005,7 @@ fn test_build_actions_from_request_withdraw_collateral() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
             assert_eq!(actions.check_max_util.len(), 0);
 
             let spender_transfer = actions.spender_transfer;
@@ -941,7 +1080,7 @@ fn test_build_actions_from_request_withdraw_collateral_over_balance() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
             assert_eq!(actions.check_max_util.len(), 0);
 
             let spender_transfer = actions.spender_transfer;
@@ -1067,7 +1206,7 @@ fn test_build_actions_from_request_borrow() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
             assert_eq!(actions.check_max_util, vec![&e, underlying.clone()]);
 
             let spender_transfer = actions.spender_transfer;
@@ -1147,7 +1286,7 @@ fn test_build_actions_from_request_borrow_adds_check_util_safely() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
             assert_eq!(
                 actions.check_max_util,
                 vec![&e, underlying_0.clone(), underlying_1.clone()]
@@ -1288,7 +1427,7 @@ fn test_build_actions_from_request_repay() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
 
             let spender_transfer = actions.spender_transfer;
             let pool_transfer = actions.pool_transfer;
@@ -1366,7 +1505,7 @@ fn test_build_actions_from_request_repay_over_balance() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, false);
+            assert!(!actions.check_health);
 
             let spender_transfer = actions.spender_transfer;
             let pool_transfer = actions.pool_transfer;
@@ -1468,7 +1607,7 @@ fn test_aggregating_actions() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
 
             let spender_transfer = actions.spender_transfer;
             let pool_transfer = actions.pool_transfer;
@@ -1609,7 +1748,7 @@ fn test_fill_user_liquidation() {
             let mut user = User::load(&e, &frodo);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
+            assert!(actions.check_health);
             let exp_new_auction = AuctionData {
                 bid: map![&e, (underlying_2.clone(), 6187500)],
                 lot: map![
@@ -1633,7 +1772,7 @@ fn test_fill_user_liquidation() {
     fn test_fill_bad_debt_auction() {
         let e = Env::default();
 
-        e.mock_all_auths();
+        e.mock_all_auths_allowing_non_root_auth();
         e.ledger().set(LedgerInfo {
             timestamp: 12345,
             protocol_version: 22,
@@ -1655,15 +1794,12 @@ fn test_fill_bad_debt_auction() {
 
         // creating reserves for a pool exhausts the budget
         e.cost_estimate().budget().reset_unlimited();
+        let (blnd_id, blnd_client) = testutils::create_blnd_token(&e, &pool_address, &bombadil);
+        let (usdc_id, usdc_client) = testutils::create_token_contract(&e, &bombadil);
         let (backstop_token_id, backstop_token_client) =
-            testutils::create_token_contract(&e, &bombadil);
-        let (backstop_address, backstop_client) = testutils::create_backstop(
-            &e,
-            &pool_address,
-            &backstop_token_id,
-            &Address::generate(&e),
-            &Address::generate(&e),
-        );
+            create_comet_lp_pool(&e, &bombadil, &blnd_id, &usdc_id);
+        let (backstop_address, backstop_client) =
+            testutils::create_backstop(&e, &pool_address, &backstop_token_id, &usdc_id, &blnd_id);
         let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
         let (mut reserve_config_0, mut reserve_data_0) = testutils::default_reserve_meta();
         reserve_data_0.last_time = 12345;
@@ -1714,8 +1850,14 @@ fn test_fill_bad_debt_auction() {
             ],
             supply: map![&e],
         };
-        backstop_token_client.mint(&samwise, &95_2000000);
-        backstop_token_client.approve(&samwise, &backstop_address, &i128::MAX, &1000000);
+        // filling the lot checks the pool's draw capacity, which values the backstop token
+        blnd_client.mint(&samwise, &10_000_0000000);
+        usdc_client.mint(&samwise, &250_0000000);
+        backstop_token_client.join_pool(
+            &(100 * SCALAR_7),
+            &vec![&e, 10_000_0000000, 250_0000000],
+            &samwise,
+        );
         backstop_client.deposit(&samwise, &pool_address, &95_2000000);
         e.as_contract(&pool_address, || {
             storage::set_pool_config(&e, &pool_config);
@@ -1740,11 +1882,12 @@ fn test_fill_bad_debt_auction() {
             let mut user = User::load(&e, &frodo);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
-            assert_eq!(
-                storage::has_auction(&e, &(AuctionType::BadDebtAuction as u32), &backstop_address),
-                false
-            );
+            assert!(actions.check_health);
+            assert!(!storage::has_auction(
+                &e,
+                &(AuctionType::BadDebtAuction as u32),
+                &backstop_address
+            ));
             assert_eq!(actions.pool_transfer.len(), 0);
             assert_eq!(actions.spender_transfer.len(), 0);
         });
@@ -1887,15 +2030,12 @@ fn test_fill_interest_auction() {
             );
             assert_eq!(underlying_0_client.balance(&samwise), 100_0000000);
             assert_eq!(underlying_1_client.balance(&samwise), 25_0000000);
-            assert_eq!(actions.check_health, false);
-            assert_eq!(
-                storage::has_auction(
-                    &e,
-                    &(AuctionType::InterestAuction as u32),
-                    &backstop_address
-                ),
-                false
-            );
+            assert!(!actions.check_health);
+            assert!(!storage::has_auction(
+                &e,
+                &(AuctionType::InterestAuction as u32),
+                &backstop_address
+            ));
             assert_eq!(actions.pool_transfer.len(), 0);
             assert_eq!(actions.spender_transfer.len(), 0);
         });
@@ -1966,16 +2106,192 @@ fn test_delete_liquidation_auction() {
             let mut user = User::load(&e, &samwise);
             let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
 
-            assert_eq!(actions.check_health, true);
-            assert_eq!(
-                storage::has_auction(&e, &(AuctionType::UserLiquidation as u32), &samwise),
-                false
-            );
+            assert!(actions.check_health);
+            assert!(!storage::has_auction(
+                &e,
+                &(AuctionType::UserLiquidation as u32),
+                &samwise
+            ));
             assert_eq!(actions.pool_transfer.len(), 0);
             assert_eq!(actions.spender_transfer.len(), 0);
         });
     }
 
+    /***** claim emissions *****/
+
+    #[test]
+    fn test_build_actions_from_request_claim_emissions_and_repay() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (blnd, _) = testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &blnd, &reserve_config, &reserve_data);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 2,
+        };
+        let user_positions = Positions {
+            liabilities: map![&e, (0, 20_0000000)],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &user_positions);
+            storage::set_res_emis_data(
+                &e,
+                &0,
+                &ReserveEmissionData {
+                    expiration: 1000,
+                    eps: 0_10000000000000,
+                    index: 1_0000000,
+                    last_time: 600,
+                },
+            );
+            storage::set_user_emissions(
+                &e,
+                &samwise,
+                &0,
+                &UserEmissionData {
+                    index: 1_0000000,
+                    accrued: 5_0000000,
+                },
+            );
+
+            let mut pool = Pool::load(&e);
+
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::ClaimEmissions as u32,
+                    address: blnd.clone(),
+                    amount: 0,
+                },
+                Request {
+                    request_type: RequestType::Repay as u32,
+                    address: blnd.clone(),
+                    amount: 10_1234567,
+                },
+            ];
+            let mut user = User::load(&e, &samwise);
+            let actions = build_actions_from_request(&e, &mut pool, &mut user, requests);
+
+            assert!(!actions.check_health);
+            assert_eq!(actions.claimed_emissions, 5_0000000);
+
+            // the claimed BLND covers part of the repayment
+            let spender_transfer = actions.spender_transfer;
+            let pool_transfer = actions.pool_transfer;
+            assert_eq!(spender_transfer.len(), 1);
+            assert_eq!(spender_transfer.get_unchecked(blnd.clone()), 5_1234567);
+            assert_eq!(pool_transfer.len(), 0);
+
+            let d_tokens_repaid = 10_1234451;
+            assert_eq!(user.get_liabilities(0), 20_0000000 - d_tokens_repaid);
+            let user_emis_data = storage::get_user_emissions(&e, &samwise, &0).unwrap();
+            assert_eq!(user_emis_data.accrued, 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_build_actions_from_request_claim_emissions_wrong_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            let mut pool = Pool::load(&e);
+
+            // reserve token id 3 belongs to reserve index 1
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::ClaimEmissions as u32,
+                    address: underlying.clone(),
+                    amount: 3,
+                },
+            ];
+            let mut user = User::load(&e, &samwise);
+            build_actions_from_request(&e, &mut pool, &mut user, requests);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_build_actions_from_request_claim_emissions_out_of_range() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            let mut pool = Pool::load(&e);
+
+            // the b_token id of reserve index 0 offset by 2^32
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::ClaimEmissions as u32,
+                    address: underlying.clone(),
+                    amount: i128::from(u32::MAX) + 2,
+                },
+            ];
+            let mut user = User::load(&e, &samwise);
+            build_actions_from_request(&e, &mut pool, &mut user, requests);
+        });
+    }
+
     /********** reserve conifg **********/
 
     #[test]
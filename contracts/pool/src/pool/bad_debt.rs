@@ -17,7 +17,7 @@ pub fn bad_debt(e: &Env, user: &Address) {
         }
         check_and_handle_backstop_bad_debt(e, &mut pool, user, &mut user_state)
     } else {
-        if storage::has_auction(e, &(AuctionType::UserLiquidation as u32), &user) {
+        if storage::has_auction(e, &(AuctionType::UserLiquidation as u32), user) {
             panic_with_error!(e, PoolError::AuctionInProgress);
         }
         check_and_handle_user_bad_debt(e, &mut pool, user, &mut user_state)
@@ -74,7 +74,7 @@ pub fn check_and_handle_user_bad_debt(
         backstop_state.store(e);
         return true;
     }
-    return false;
+    false
 }
 
 /// Check if the backstop's bad debt needs to be defaulted. This occurs when the backstop has less than
@@ -106,7 +106,10 @@ pub fn check_and_handle_backstop_bad_debt(
     if backstop_state.has_liabilities() {
         let backstop_client = BackstopClient::new(e, backstop_address);
         let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
-        let threshold = calc_pool_backstop_threshold(&pool_backstop_data);
+        let threshold = calc_pool_backstop_threshold(
+            &pool_backstop_data,
+            storage::get_status_policy(e).threshold_pc,
+        );
         if threshold < 0_0000003 {
             // ~5% of threshold
             let reserve_list = storage::get_res_list(e);
@@ -121,7 +124,7 @@ pub fn check_and_handle_backstop_bad_debt(
             return true;
         }
     }
-    return false;
+    false
 }
 
 #[cfg(test)]
@@ -706,7 +709,7 @@ fn test_check_and_handle_user_bad_debt_with_collateral() {
             let mut user = User::load(&e, &samwise);
 
             let result = check_and_handle_user_bad_debt(&e, &mut pool, &samwise, &mut user);
-            assert_eq!(result, false);
+            assert!(!result);
 
             // assert user not modified
             assert_eq!(user.positions.liabilities, positions.liabilities);
@@ -792,7 +795,7 @@ fn test_check_and_handle_user_bad_debt() {
             let mut user = User::load(&e, &samwise);
 
             let result = check_and_handle_user_bad_debt(&e, &mut pool, &samwise, &mut user);
-            assert_eq!(result, true);
+            assert!(result);
 
             // assert user forgiven liabilities and assigned to backstop
             assert_eq!(user.positions.liabilities.len(), 0);
@@ -898,7 +901,7 @@ fn test_check_and_handle_backstop_bad_debt() {
                 &backstop_address,
                 &mut backstop_user,
             );
-            assert_eq!(result, false);
+            assert!(!result);
 
             // assert nothing happens to backstop position
             assert_eq!(
@@ -988,7 +991,7 @@ fn test_check_and_handle_backstop_bad_debt_with_no_liabilities() {
                 &backstop_address,
                 &mut backstop_user,
             );
-            assert_eq!(result, false);
+            assert!(!result);
 
             // assert nothing happens to backstop position
             assert_eq!(
@@ -1078,7 +1081,7 @@ fn test_check_and_handle_backstop_bad_debt_with_unhealthy_backstop_defaults() {
                 &backstop_address,
                 &mut backstop_user,
             );
-            assert_eq!(result, true);
+            assert!(result);
 
             // assert backstop user updated
             assert_eq!(backstop_user.positions.liabilities.len(), 0);
@@ -0,0 +1,347 @@
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{unwrap::UnwrapOptimized, vec, Address, Env, Vec};
+
+use crate::{
+    constants::{BORROWER_PAGE_SIZE, SCALAR_7},
+    storage,
+};
+
+use super::{pool::Pool, PositionData};
+
+/// Add a user to the back of the index of users with liabilities
+///
+/// ### Arguments
+/// * user - The user taking on liabilities
+pub(super) fn add_borrower(e: &Env, user: &Address) {
+    let index = storage::get_borrower_count(e);
+    let page_index = index / BORROWER_PAGE_SIZE;
+    let mut page = storage::get_borrower_page(e, page_index);
+    page.push_back(user.clone());
+    storage::set_borrower_page(e, page_index, &page);
+    storage::set_borrower_index(e, user, index);
+    storage::set_borrower_count(e, index + 1);
+}
+
+/// Remove a user from the index of users with liabilities. The last user in the index
+/// is moved into the removed user's position.
+///
+/// ### Arguments
+/// * user - The user that no longer has liabilities
+pub(super) fn remove_borrower(e: &Env, user: &Address) {
+    let index = storage::get_borrower_index(e, user).unwrap_optimized();
+    let last_index = storage::get_borrower_count(e) - 1;
+    let last_page_index = last_index / BORROWER_PAGE_SIZE;
+    let mut last_page = storage::get_borrower_page(e, last_page_index);
+    let last_user = last_page.pop_back_unchecked();
+
+    if index != last_index {
+        let page_index = index / BORROWER_PAGE_SIZE;
+        if page_index == last_page_index {
+            last_page.set(index % BORROWER_PAGE_SIZE, last_user.clone());
+        } else {
+            let mut page = storage::get_borrower_page(e, page_index);
+            page.set(index % BORROWER_PAGE_SIZE, last_user.clone());
+            storage::set_borrower_page(e, page_index, &page);
+        }
+        storage::set_borrower_index(e, &last_user, index);
+    }
+    storage::set_borrower_page(e, last_page_index, &last_page);
+    storage::del_borrower_index(e, user);
+    storage::set_borrower_count(e, last_index);
+}
+
+/// Add any of `users` with liabilities that are missing from the index of users with liabilities.
+///
+/// Users are indexed when their positions are stored, so users that took on liabilities before
+/// the index existed are only indexed once they next interact with the pool. This allows the
+/// index to be backfilled without waiting on those users.
+///
+/// Returns the number of users added to the index
+///
+/// ### Arguments
+/// * users - The users to index
+pub fn execute_index_borrowers(e: &Env, users: &Vec<Address>) -> u32 {
+    let mut indexed = 0;
+    for user in users.iter() {
+        if !storage::has_borrower_index(e, &user)
+            && !storage::get_user_positions(e, &user).liabilities.is_empty()
+        {
+            add_borrower(e, &user);
+            indexed += 1;
+        }
+    }
+    indexed
+}
+
+/// Load the health factor of the users with liabilities, starting at `start` in the index of
+/// users with liabilities and returning at most `count` users
+///
+/// Users with liabilities that have not been indexed yet are not included. See
+/// `execute_index_borrowers`.
+///
+/// Returns a list of (user, health factor) with the health factor expressed in 7 decimals
+///
+/// ### Arguments
+/// * start - The position in the index to start at
+/// * count - The maximum number of users to load
+pub fn load_borrower_health_factors(e: &Env, start: u32, count: u32) -> Vec<(Address, i128)> {
+    let mut pool = Pool::load(e);
+    let end = storage::get_borrower_count(e).min(start.saturating_add(count));
+    let mut health_factors = vec![e];
+    let mut page_index = u32::MAX;
+    let mut page: Vec<Address> = vec![e];
+    for index in start..end {
+        if index / BORROWER_PAGE_SIZE != page_index {
+            page_index = index / BORROWER_PAGE_SIZE;
+            page = storage::get_borrower_page(e, page_index);
+        }
+        let user = page.get_unchecked(index % BORROWER_PAGE_SIZE);
+        let positions = storage::get_user_positions(e, &user);
+        let position_data = PositionData::calculate_from_positions(e, &mut pool, &positions);
+        let health_factor = if position_data.liability_base == 0 {
+            i128::MAX
+        } else {
+            position_data
+                .as_health_factor(e)
+                .fixed_mul_floor(e, &SCALAR_7, &position_data.scalar)
+        };
+        health_factors.push_back((user, health_factor));
+    }
+    health_factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pool::{Positions, User},
+        storage::PoolConfig,
+        testutils,
+    };
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger, LedgerInfo},
+        Symbol,
+    };
+
+    #[test]
+    fn test_borrower_index_add_and_remove_across_pages() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        let mut users: Vec<Address> = vec![&e];
+        for _ in 0..(BORROWER_PAGE_SIZE + 5) {
+            users.push_back(Address::generate(&e));
+        }
+
+        e.as_contract(&pool, || {
+            for user in users.iter() {
+                add_borrower(&e, &user);
+            }
+            assert_eq!(storage::get_borrower_count(&e), BORROWER_PAGE_SIZE + 5);
+            assert_eq!(storage::get_borrower_page(&e, 1).len(), 5);
+
+            // remove a user from the first page, the last user is moved into its position
+            let removed = users.get_unchecked(3);
+            let last = users.get_unchecked(BORROWER_PAGE_SIZE + 4);
+            remove_borrower(&e, &removed);
+            assert_eq!(storage::get_borrower_count(&e), BORROWER_PAGE_SIZE + 4);
+            assert_eq!(storage::get_borrower_page(&e, 0).get_unchecked(3), last);
+            assert_eq!(storage::get_borrower_page(&e, 1).len(), 4);
+            assert_eq!(storage::get_borrower_index(&e, &last), Some(3));
+            assert!(!storage::has_borrower_index(&e, &removed));
+
+            // remove the last user in the index
+            let last = users.get_unchecked(BORROWER_PAGE_SIZE + 3);
+            remove_borrower(&e, &last);
+            assert_eq!(storage::get_borrower_count(&e), BORROWER_PAGE_SIZE + 3);
+            assert_eq!(storage::get_borrower_page(&e, 1).len(), 3);
+            assert!(!storage::has_borrower_index(&e, &last));
+        });
+    }
+
+    #[test]
+    fn test_user_store_updates_borrower_index() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            let mut samwise_state = User {
+                address: samwise.clone(),
+                positions: Positions {
+                    liabilities: map![&e, (0, 10_0000000)],
+                    collateral: map![&e],
+                    supply: map![&e],
+                },
+            };
+            samwise_state.store(&e);
+            User {
+                address: frodo.clone(),
+                positions: Positions {
+                    liabilities: map![&e],
+                    collateral: map![&e, (0, 10_0000000)],
+                    supply: map![&e],
+                },
+            }
+            .store(&e);
+            assert_eq!(storage::get_borrower_count(&e), 1);
+            assert_eq!(storage::get_borrower_index(&e, &samwise), Some(0));
+            assert!(!storage::has_borrower_index(&e, &frodo));
+
+            // storing again does not duplicate the user
+            samwise_state.store(&e);
+            assert_eq!(storage::get_borrower_count(&e), 1);
+
+            samwise_state.positions.liabilities = map![&e];
+            samwise_state.store(&e);
+            assert_eq!(storage::get_borrower_count(&e), 0);
+            assert!(!storage::has_borrower_index(&e, &samwise));
+        });
+    }
+
+    #[test]
+    fn test_index_borrowers_backfills_unindexed_users() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+
+        e.as_contract(&pool, || {
+            // samwise and frodo took on liabilities before the index existed
+            let positions = Positions {
+                liabilities: map![&e, (0, 10_0000000)],
+                collateral: map![&e],
+                supply: map![&e],
+            };
+            storage::set_user_positions(&e, &samwise, &positions);
+            storage::set_user_positions(&e, &frodo, &positions);
+            storage::set_user_positions(
+                &e,
+                &merry,
+                &Positions {
+                    liabilities: map![&e],
+                    collateral: map![&e, (0, 10_0000000)],
+                    supply: map![&e],
+                },
+            );
+            assert_eq!(storage::get_borrower_count(&e), 0);
+
+            // frodo is indexed lazily once their positions are stored
+            User::load(&e, &frodo).store(&e);
+            assert_eq!(storage::get_borrower_index(&e, &frodo), Some(0));
+
+            let indexed = execute_index_borrowers(
+                &e,
+                &vec![&e, samwise.clone(), frodo.clone(), merry.clone()],
+            );
+            assert_eq!(indexed, 1);
+            assert_eq!(storage::get_borrower_count(&e), 2);
+            assert_eq!(storage::get_borrower_index(&e, &samwise), Some(1));
+            assert!(!storage::has_borrower_index(&e, &merry));
+
+            // indexing again is a no-op
+            let indexed = execute_index_borrowers(&e, &vec![&e, samwise.clone()]);
+            assert_eq!(indexed, 0);
+            assert_eq!(storage::get_borrower_count(&e), 2);
+        });
+    }
+
+    #[test]
+    fn test_load_borrower_health_factors() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 12345;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.index = 1;
+        reserve_data.last_time = 12345;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 1_0000000]);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                &e,
+                &PoolConfig {
+                    oracle,
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 0,
+                    max_positions: 4,
+                },
+            );
+            for (user, collateral) in [
+                (samwise.clone(), 20_0000000),
+                (frodo.clone(), 10_0000000),
+                (merry.clone(), 40_0000000),
+            ] {
+                User {
+                    address: user,
+                    positions: Positions {
+                        liabilities: map![&e, (1, 10_0000000)],
+                        collateral: map![&e, (0, collateral)],
+                        supply: map![&e],
+                    },
+                }
+                .store(&e);
+            }
+
+            let health_factors = load_borrower_health_factors(&e, 0, 2);
+            assert_eq!(health_factors.len(), 2);
+            let (user, health_factor) = health_factors.get_unchecked(0);
+            assert_eq!(user, samwise);
+            // 0.75 c_factor and 0.75 l_factor, with liabilities rounded up
+            assert_eq!(health_factor, 1_1249999);
+            let (user, health_factor) = health_factors.get_unchecked(1);
+            assert_eq!(user, frodo);
+            assert_eq!(health_factor, 0_5624999);
+
+            let health_factors = load_borrower_health_factors(&e, 2, 2);
+            assert_eq!(health_factors.len(), 1);
+            let (user, health_factor) = health_factors.get_unchecked(0);
+            assert_eq!(user, merry);
+            assert_eq!(health_factor, 2_2499999);
+
+            assert_eq!(load_borrower_health_factors(&e, 3, 2).len(), 0);
+        });
+    }
+}
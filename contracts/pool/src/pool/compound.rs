@@ -0,0 +1,284 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    panic_with_error, vec, Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+use crate::{
+    dependencies::{BackstopClient, CometClient},
+    emissions,
+    errors::PoolError,
+    events::PoolEvents,
+    storage,
+};
+
+use super::{
+    actions::{build_actions_from_request, Request, RequestType},
+    pool::Pool,
+    submit::validate_submit,
+    Positions, User,
+};
+
+/// Claim the emissions for `from` and supply them back into the pool for `from`.
+///
+/// If `asset` is the BLND reserve, the claimed BLND is supplied directly. Otherwise, the claimed
+/// BLND is swapped through the backstop's Comet LP pool into `asset` before being supplied, so
+/// `asset` must be one of the LP pool's underlying tokens.
+///
+/// `from` MUST be authenticated before calling
+///
+/// Returns the new positions for `from`
+///
+/// ### Arguments
+/// * from - The address of the user claiming emissions
+/// * reserve_token_ids - The reserve token ids to claim emissions for
+/// * asset - The reserve asset to supply the claimed emissions to
+/// * collateral - If the claimed emissions are supplied as collateral
+/// * min_tokens_out - The minimum amount of `asset` to receive from the swap
+///
+/// ### Panics
+/// * If no emissions were claimed
+/// * If the swap returns less than `min_tokens_out` of `asset`
+/// * If the supply request is unable to be executed
+pub fn execute_claim_and_supply(
+    e: &Env,
+    from: &Address,
+    reserve_token_ids: &Vec<u32>,
+    asset: &Address,
+    collateral: bool,
+    min_tokens_out: i128,
+) -> Positions {
+    let pool_address = e.current_contract_address();
+    if from == &pool_address {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    // claim the emissions to the pool
+    let claimed = emissions::execute_claim(e, from, reserve_token_ids, &pool_address);
+    if claimed <= 0 {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    PoolEvents::claim(e, from.clone(), reserve_token_ids.clone(), claimed);
+
+    let blnd_token = storage::get_blnd_token(e);
+    let tokens_in = if asset == &blnd_token {
+        claimed
+    } else {
+        swap_blnd(e, &blnd_token, claimed, asset, min_tokens_out)
+    };
+
+    let mut pool = Pool::load(e);
+    let mut from_state = User::load(e, from);
+    let prev_positions_count = from_state.positions.effective_count();
+
+    let request_type = if collateral {
+        RequestType::SupplyCollateral
+    } else {
+        RequestType::Supply
+    };
+    let requests = vec![
+        e,
+        Request {
+            request_type: request_type as u32,
+            address: asset.clone(),
+            amount: tokens_in,
+        },
+    ];
+    let actions = build_actions_from_request(e, &mut pool, &mut from_state, requests);
+
+    validate_submit(
+        e,
+        &mut pool,
+        &from_state,
+        prev_positions_count,
+        actions.check_health,
+        &actions.check_max_util,
+    );
+
+    pool.store_cached_reserves(e);
+    from_state.store(e);
+
+    from_state.positions
+}
+
+/// Swap BLND held by the pool for `token_out` through the backstop's Comet LP pool
+///
+/// Returns the amount of `token_out` received
+fn swap_blnd(
+    e: &Env,
+    blnd_token: &Address,
+    amount: i128,
+    token_out: &Address,
+    min_tokens_out: i128,
+) -> i128 {
+    let pool_address = e.current_contract_address();
+    let backstop = storage::get_backstop(e);
+    let comet = BackstopClient::new(e, &backstop).backstop_token();
+
+    // the LP pool pulls the tokens in via an allowance from the pool
+    let approval_ledger = (e.ledger().sequence() / 100000 + 1) * 100000;
+    let args: Vec<Val> = vec![
+        e,
+        pool_address.into_val(e),
+        comet.into_val(e),
+        amount.into_val(e),
+        approval_ledger.into_val(e),
+    ];
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: blnd_token.clone(),
+                fn_name: Symbol::new(e, "approve"),
+                args,
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+    let (tokens_out, _) = CometClient::new(e, &comet).swap_exact_amount_in(
+        blnd_token,
+        &amount,
+        token_out,
+        &min_tokens_out,
+        &i128::MAX,
+        &pool_address,
+    );
+    if tokens_out < min_tokens_out {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    tokens_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        storage::{PoolConfig, ReserveEmissionData, UserEmissionData},
+        testutils,
+    };
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger, LedgerInfo},
+    };
+
+    #[test]
+    fn test_claim_and_supply_blnd_reserve() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (backstop, _) = testutils::create_backstop(
+            &e,
+            &pool,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            &blnd,
+        );
+        e.as_contract(&backstop, || {
+            blnd_client.approve(&backstop, &pool, &100_000_0000000_i128, &1000000);
+        });
+        blnd_client.mint(&backstop, &100_000_0000000);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 1501000000,
+            protocol_version: 22,
+            sequence_number: 123,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 1501000000;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.index = 1;
+        reserve_data.last_time = 1501000000;
+        testutils::create_reserve(&e, &pool, &blnd, &reserve_config, &reserve_data);
+
+        let (oracle, _) = testutils::create_mock_oracle(&e);
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                &e,
+                &PoolConfig {
+                    oracle,
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 0,
+                    max_positions: 4,
+                },
+            );
+            storage::set_user_positions(
+                &e,
+                &samwise,
+                &Positions {
+                    liabilities: map![&e],
+                    collateral: map![&e],
+                    supply: map![&e, (0, 10_0000000)],
+                },
+            );
+            storage::set_res_emis_data(
+                &e,
+                &1,
+                &ReserveEmissionData {
+                    expiration: 1600000000,
+                    eps: 0_10000000000000,
+                    index: 1_0000000,
+                    last_time: 1501000000,
+                },
+            );
+            storage::set_user_emissions(
+                &e,
+                &samwise,
+                &1,
+                &UserEmissionData {
+                    index: 1_0000000,
+                    accrued: 5_0000000,
+                },
+            );
+
+            let positions = execute_claim_and_supply(&e, &samwise, &vec![&e, 1], &blnd, true, 0);
+
+            assert_eq!(positions.collateral.get_unchecked(1), 5_0000000);
+            assert_eq!(storage::get_res_data(&e, &blnd).b_supply, 105_0000000);
+            assert_eq!(
+                storage::get_user_emissions(&e, &samwise, &1)
+                    .unwrap()
+                    .accrued,
+                0
+            );
+        });
+        // 25 BLND of reserve liquidity plus the 5 BLND claimed
+        assert_eq!(blnd_client.balance(&pool), 30_0000000);
+        assert_eq!(blnd_client.balance(&samwise), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_claim_and_supply_nothing_claimed() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (blnd, _) = testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            execute_claim_and_supply(&e, &samwise, &vec![&e, 1], &blnd, false, 0);
+        });
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
     constants::{MAX_RESERVES, SCALAR_12, SCALAR_7, SECONDS_PER_WEEK},
     errors::PoolError,
+    events::PoolEvents,
     storage::{
-        self, has_queued_reserve_set, PoolConfig, QueuedReserveInit, ReserveConfig, ReserveData,
+        self, has_queued_reserve_set, PoolConfig, PoolLoanLimits, QueuedBaseAsset,
+        QueuedReserveInit, ReserveConfig, ReserveData, ReserveEmissionData, ReserveSunset,
     },
 };
 use soroban_sdk::{panic_with_error, Address, Env, String};
@@ -63,9 +65,118 @@ pub fn execute_update_pool(
     storage::set_pool_config(e, &pool_config);
 }
 
+/// Update the pool's per-user loan limits
+///
+/// ### Arguments
+/// * `min_borrow` - The minimum size of a single borrow request in the pool's base asset, or 0 for no minimum
+/// * `max_liability` - The maximum liability a single user can hold in the pool's base asset, or 0 for no maximum
+///
+/// ### Panics
+/// If either limit is negative, or the maximum is less than the minimum
+pub fn execute_set_loan_limits(e: &Env, min_borrow: i128, max_liability: i128) {
+    if min_borrow < 0 || max_liability < 0 || (max_liability > 0 && max_liability < min_borrow) {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+    storage::set_loan_limits(
+        e,
+        &PoolLoanLimits {
+            min_borrow,
+            max_liability,
+        },
+    );
+
+    PoolEvents::set_loan_limits(e, min_borrow, max_liability);
+}
+
+/// Queue a change to the asset the pool's prices are denominated in. Prices are denominated in
+/// the oracle's base asset if no base asset is set. The change rescales every health factor
+/// and limit in the pool, so it is timelocked for a week unless the pool is still being set up.
+///
+/// The minimum collateral and loan limits are denominated in the base asset, so they should be
+/// updated alongside the base asset.
+///
+/// ### Arguments
+/// * `base_asset` - The underlying asset of the reserve to denominate prices in, or None to use
+///   the oracle's base asset
+///
+/// ### Panics
+/// If a change is already queued or the base asset is not an active reserve of the pool
+pub fn execute_queue_set_base_asset(e: &Env, base_asset: &Option<Address>) {
+    if storage::get_queued_base_asset(e).is_some() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    require_valid_base_asset(e, base_asset);
+
+    let mut unlock_time = e.ledger().timestamp();
+    // require a timelock if pool status is not setup
+    if storage::get_pool_config(e).status != 6 {
+        unlock_time += SECONDS_PER_WEEK;
+    }
+    storage::set_queued_base_asset(
+        e,
+        &QueuedBaseAsset {
+            new_base_asset: base_asset.clone(),
+            unlock_time,
+        },
+    );
+
+    PoolEvents::queue_set_base_asset(e, base_asset.clone(), unlock_time);
+}
+
+/// Cancel a queued change to the pool's base asset
+pub fn execute_cancel_queued_set_base_asset(e: &Env) {
+    storage::del_queued_base_asset(e);
+
+    PoolEvents::cancel_set_base_asset(e);
+}
+
+/// Set the queued base asset once its timelock has expired
+///
+/// ### Panics
+/// If no change is queued, the change is still locked, the base asset is no longer an
+/// active reserve of the pool, or any reserve's price rebases to zero in the base asset
+pub fn execute_set_base_asset(e: &Env) {
+    let queued_base_asset = match storage::get_queued_base_asset(e) {
+        Some(queued_base_asset) => queued_base_asset,
+        None => panic_with_error!(e, PoolError::BadRequest),
+    };
+    if queued_base_asset.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+    // the reserve may have been queued for sunset since the change was queued
+    require_valid_base_asset(e, &queued_base_asset.new_base_asset);
+
+    storage::del_queued_base_asset(e);
+    match &queued_base_asset.new_base_asset {
+        Some(asset) => {
+            storage::set_base_asset(e, asset);
+            // every reserve must be priced in the new base asset
+            let mut pool = Pool::load(e);
+            for reserve in storage::get_res_list(e).iter() {
+                pool.load_price(e, &reserve);
+            }
+        }
+        None => storage::del_base_asset(e),
+    }
+
+    PoolEvents::set_base_asset(e, queued_base_asset.new_base_asset);
+}
+
+/// Require that the base asset is an active reserve of the pool, if one is given
+///
+/// ### Panics
+/// If the base asset is not an active reserve of the pool
+fn require_valid_base_asset(e: &Env, base_asset: &Option<Address>) {
+    if let Some(asset) = base_asset {
+        if !storage::has_res(e, asset) || storage::has_res_sunset(e, asset) {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+    }
+}
+
 /// Execute a queueing a reserve initialization for the pool
 pub fn execute_queue_set_reserve(e: &Env, asset: &Address, metadata: &ReserveConfig) {
-    if has_queued_reserve_set(e, asset) {
+    if has_queued_reserve_set(e, asset) || storage::has_res_sunset(e, asset) {
         panic_with_error!(&e, PoolError::BadRequest)
     }
     require_valid_reserve_metadata(e, metadata);
@@ -81,18 +192,18 @@ pub fn execute_queue_set_reserve(e: &Env, asset: &Address, metadata: &ReserveCon
         unlock_time += SECONDS_PER_WEEK;
     }
     storage::set_queued_reserve_set(
-        &e,
+        e,
         &QueuedReserveInit {
             new_config: metadata.clone(),
             unlock_time,
         },
-        &asset,
+        asset,
     );
 }
 
 /// Execute cancelling a queueing a reserve initialization for the pool
 pub fn execute_cancel_queued_set_reserve(e: &Env, asset: &Address) {
-    storage::del_queued_reserve_set(&e, &asset);
+    storage::del_queued_reserve_set(e, asset);
 }
 
 /// Execute a queued reserve initialization for the pool
@@ -102,6 +213,9 @@ pub fn execute_set_reserve(e: &Env, asset: &Address) -> u32 {
     if queued_init.unlock_time > e.ledger().timestamp() {
         panic_with_error!(e, PoolError::InitNotUnlocked);
     }
+    if storage::has_res_sunset(e, asset) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
 
     // remove queued reserve
     storage::del_queued_reserve_set(e, asset);
@@ -133,7 +247,20 @@ fn initialize_reserve(e: &Env, asset: &Address, config: &ReserveConfig) -> u32 {
         }
         reserve.store(e);
     } else {
-        index = storage::push_res_list(e, asset);
+        // reclaim the index of a retired reserve if one is available
+        let mut free_list = storage::get_res_free_list(e);
+        index = match free_list.pop_front() {
+            Some(free_index) => {
+                let retired_asset = storage::get_res_list(e).get_unchecked(free_index);
+                storage::del_res(e, &retired_asset);
+                storage::del_res_sunset(e, &retired_asset);
+                storage::set_res_list_index(e, free_index, asset);
+                storage::set_res_free_list(e, &free_list);
+                reset_reclaimed_emissions(e, free_index);
+                free_index
+            }
+            None => storage::push_res_list(e, asset),
+        };
         let init_data = ReserveData {
             b_rate: SCALAR_12,
             d_rate: SCALAR_12,
@@ -166,6 +293,166 @@ fn initialize_reserve(e: &Env, asset: &Address, config: &ReserveConfig) -> u32 {
     index
 }
 
+/// Reset the emission data of a reclaimed reserve index so the new reserve does not inherit
+/// the retired reserve's emissions
+///
+/// The emission index is moved past the retired reserve's index and recorded as the floor for
+/// user emission data. Users cannot be enumerated, so any user emission data below the floor
+/// is rebased onto the floor the next time it is loaded, keeping the user's accrued emissions.
+fn reset_reclaimed_emissions(e: &Env, index: u32) {
+    for res_token_id in [index * 2, index * 2 + 1] {
+        if let Some(emis_data) = storage::get_res_emis_data(e, &res_token_id) {
+            let floor = emis_data.index + 1;
+            storage::set_res_emis_data(
+                e,
+                &res_token_id,
+                &ReserveEmissionData {
+                    expiration: e.ledger().timestamp(),
+                    eps: 0,
+                    index: floor,
+                    last_time: e.ledger().timestamp(),
+                },
+            );
+            storage::set_res_emis_floor(e, &res_token_id, &floor);
+        }
+    }
+}
+
+/// Execute queueing a reserve to be sunset
+///
+/// The sunset can begin once the timelock has passed, unless the pool is in setup
+///
+/// ### Arguments
+/// * `asset` - The underlying asset of the reserve
+///
+/// ### Panics
+/// If the reserve does not exist, already has a sunset, or is the pool's base asset
+pub fn execute_queue_sunset_reserve(e: &Env, asset: &Address) {
+    if !storage::has_res(e, asset)
+        || storage::has_res_sunset(e, asset)
+        || storage::get_base_asset(e).as_ref() == Some(asset)
+    {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    let mut unlock_time = e.ledger().timestamp();
+    // require a timelock if pool status is not setup
+    if storage::get_pool_config(e).status != 6 {
+        unlock_time += SECONDS_PER_WEEK;
+    }
+    let reserve_config = storage::get_res_config(e, asset);
+    storage::set_res_sunset(
+        e,
+        asset,
+        &ReserveSunset {
+            c_factor: reserve_config.c_factor,
+            unlock_time,
+            start_time: 0,
+        },
+    );
+
+    PoolEvents::queue_sunset_reserve(e, asset.clone(), unlock_time);
+}
+
+/// Execute cancelling a queued reserve sunset
+///
+/// ### Arguments
+/// * `asset` - The underlying asset of the reserve
+///
+/// ### Panics
+/// If the reserve does not have a queued sunset, or the sunset has already begun
+pub fn execute_cancel_sunset_reserve(e: &Env, asset: &Address) {
+    match storage::get_res_sunset(e, asset) {
+        Some(sunset) if sunset.start_time == 0 => {
+            storage::del_res_sunset(e, asset);
+            PoolEvents::cancel_sunset_reserve(e, asset.clone());
+        }
+        _ => panic_with_error!(e, PoolError::BadRequest),
+    }
+}
+
+/// Execute beginning a queued reserve sunset
+///
+/// This disables new supply and borrows for the reserve, and begins ramping the reserve's
+/// collateral factor down to zero.
+///
+/// ### Arguments
+/// * `asset` - The underlying asset of the reserve
+///
+/// ### Panics
+/// If the reserve does not have a queued sunset or the sunset is not unlocked
+pub fn execute_sunset_reserve(e: &Env, asset: &Address) {
+    let mut sunset = match storage::get_res_sunset(e, asset) {
+        Some(sunset) if sunset.start_time == 0 => sunset,
+        _ => panic_with_error!(e, PoolError::BadRequest),
+    };
+    if sunset.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+
+    // accrue the reserve before it is disabled
+    let mut pool = Pool::load(e);
+    let reserve = pool.load_reserve(e, asset, false);
+    reserve.store(e);
+
+    let mut reserve_config = storage::get_res_config(e, asset);
+    reserve_config.enabled = false;
+    storage::set_res_config(e, asset, &reserve_config);
+
+    sunset.start_time = e.ledger().timestamp();
+    storage::set_res_sunset(e, asset, &sunset);
+
+    PoolEvents::sunset_reserve(e, asset.clone());
+}
+
+/// Execute retiring a sunset reserve, allowing its index to be reclaimed by the next
+/// new reserve added to the pool. Any emissions for the reserve are stopped, but
+/// previously accrued emissions remain claimable, including after the index is reclaimed.
+///
+/// ### Arguments
+/// * `asset` - The underlying asset of the reserve
+///
+/// ### Panics
+/// If the reserve sunset has not begun, or the reserve still has any supply, liabilities
+/// or backstop credit
+pub fn execute_retire_reserve(e: &Env, asset: &Address) -> u32 {
+    match storage::get_res_sunset(e, asset) {
+        Some(sunset) if sunset.start_time != 0 => (),
+        _ => panic_with_error!(e, PoolError::BadRequest),
+    };
+    let mut pool = Pool::load(e);
+    let reserve = pool.load_reserve(e, asset, false);
+    if reserve.data.b_supply != 0 || reserve.data.d_supply != 0 || reserve.data.backstop_credit != 0
+    {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let index = reserve.config.index;
+    let mut free_list = storage::get_res_free_list(e);
+    if free_list.contains(index) {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    reserve.store(e);
+
+    // stop any emissions to the reserve's d and b tokens
+    let mut pool_emissions = storage::get_pool_emissions(e);
+    for res_token_id in [index * 2, index * 2 + 1] {
+        pool_emissions.remove(res_token_id);
+        if let Some(mut emis_data) = storage::get_res_emis_data(e, &res_token_id) {
+            if emis_data.expiration > e.ledger().timestamp() {
+                emis_data.expiration = e.ledger().timestamp();
+                storage::set_res_emis_data(e, &res_token_id, &emis_data);
+            }
+        }
+    }
+    storage::set_pool_emissions(e, &pool_emissions);
+
+    free_list.push_back(index);
+    storage::set_res_free_list(e, &free_list);
+
+    PoolEvents::retire_reserve(e, asset.clone(), index);
+    index
+}
+
 #[allow(clippy::zero_prefixed_literal)]
 fn require_valid_reserve_metadata(e: &Env, metadata: &ReserveConfig) {
     const SCALAR_7_U32: u32 = SCALAR_7 as u32;
@@ -214,11 +501,16 @@ fn require_valid_pool_config(e: &Env, config: &PoolConfig) {
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::QueuedReserveInit;
+    use crate::storage::{QueuedReserveInit, UserEmissionData};
     use crate::testutils;
 
     use super::*;
-    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec, Symbol,
+    };
 
     #[test]
     fn test_execute_initialize() {
@@ -488,6 +780,234 @@ fn test_execute_update_pool_validates_max_positions() {
         });
     }
 
+    #[test]
+    fn test_execute_set_loan_limits() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            let loan_limits = storage::get_loan_limits(&e);
+            assert_eq!(loan_limits.min_borrow, 0);
+            assert_eq!(loan_limits.max_liability, 0);
+
+            execute_set_loan_limits(&e, 10_0000000, 500_0000000);
+
+            let loan_limits = storage::get_loan_limits(&e);
+            assert_eq!(loan_limits.min_borrow, 10_0000000);
+            assert_eq!(loan_limits.max_liability, 500_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_execute_set_loan_limits_validates_max_over_min() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_set_loan_limits(&e, 10_0000000, 9_9999999);
+        });
+    }
+
+    #[test]
+    fn test_execute_set_base_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set_timestamp(500);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![&e, Asset::Stellar(underlying.clone())],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 0_0500000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            assert!(storage::get_base_asset(&e).is_none());
+
+            execute_queue_set_base_asset(&e, &Some(underlying.clone()));
+            let queued_base_asset = storage::get_queued_base_asset(&e).unwrap();
+            assert_eq!(queued_base_asset.new_base_asset, Some(underlying.clone()));
+            assert_eq!(queued_base_asset.unlock_time, 500 + SECONDS_PER_WEEK);
+            assert!(storage::get_base_asset(&e).is_none());
+
+            e.ledger().set_timestamp(500 + SECONDS_PER_WEEK);
+            execute_set_base_asset(&e);
+            assert_eq!(storage::get_base_asset(&e), Some(underlying.clone()));
+            assert!(storage::get_queued_base_asset(&e).is_none());
+
+            execute_queue_set_base_asset(&e, &None);
+            e.ledger().set_timestamp(500 + 2 * SECONDS_PER_WEEK);
+            execute_set_base_asset(&e);
+            assert!(storage::get_base_asset(&e).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1210)")]
+    fn test_execute_set_base_asset_zero_rebased_price() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set_timestamp(500);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        reserve_config.index = 1;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        // underlying_1 is worth less than one stroop of underlying_0
+        oracle_client.set_price_stable(&vec![&e, 2_0000000, 0_0000001]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_base_asset(&e, &Some(underlying_0.clone()));
+
+            e.ledger().set_timestamp(500 + SECONDS_PER_WEEK);
+            execute_set_base_asset(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_execute_set_base_asset_locked() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set_timestamp(500);
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_base_asset(&e, &Some(underlying.clone()));
+
+            e.ledger().set_timestamp(500 + SECONDS_PER_WEEK - 1);
+            execute_set_base_asset(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_cancel_queued_set_base_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_set_base_asset(&e, &Some(underlying.clone()));
+            execute_cancel_queued_set_base_asset(&e);
+            assert!(storage::get_queued_base_asset(&e).is_none());
+
+            execute_set_base_asset(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_execute_queue_set_base_asset_requires_reserve() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_queue_set_base_asset(&e, &Some(Address::generate(&e)));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_queue_sunset_reserve_base_asset() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_base_asset(&e, &underlying);
+
+            execute_queue_sunset_reserve(&e, &underlying);
+        });
+    }
+
     #[test]
     fn test_queue_set_reserve_status_6() {
         let e = Env::default();
@@ -1241,6 +1761,290 @@ fn test_initialize_reserve_sets_index() {
         });
     }
 
+    #[test]
+    fn test_sunset_reserve_lifecycle() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 500,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        let (underlying_2, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_pool_emissions(&e, &map![&e, (1, 0_5000000), (3, 0_5000000)]);
+
+            execute_queue_sunset_reserve(&e, &underlying_0);
+            let sunset = storage::get_res_sunset(&e, &underlying_0).unwrap();
+            assert_eq!(sunset.c_factor, reserve_config.c_factor);
+            assert_eq!(sunset.unlock_time, 500 + SECONDS_PER_WEEK);
+            assert_eq!(sunset.start_time, 0);
+
+            e.ledger().set_timestamp(500 + SECONDS_PER_WEEK);
+            execute_sunset_reserve(&e, &underlying_0);
+            let sunset = storage::get_res_sunset(&e, &underlying_0).unwrap();
+            assert_eq!(sunset.start_time, 500 + SECONDS_PER_WEEK);
+            assert!(!storage::get_res_config(&e, &underlying_0).enabled);
+
+            let index = execute_retire_reserve(&e, &underlying_0);
+            assert_eq!(index, 0);
+            assert_eq!(storage::get_res_free_list(&e), vec![&e, 0]);
+            let pool_emissions = storage::get_pool_emissions(&e);
+            assert_eq!(pool_emissions.len(), 1);
+            assert_eq!(pool_emissions.get_unchecked(3), 0_5000000);
+
+            // the next new reserve reclaims the retired index
+            storage::set_queued_reserve_set(
+                &e,
+                &QueuedReserveInit {
+                    new_config: reserve_config.clone(),
+                    unlock_time: e.ledger().timestamp(),
+                },
+                &underlying_2,
+            );
+            let new_index = execute_set_reserve(&e, &underlying_2);
+            assert_eq!(new_index, 0);
+            assert_eq!(
+                storage::get_res_list(&e),
+                vec![&e, underlying_2.clone(), underlying_1.clone()]
+            );
+            assert_eq!(storage::get_res_config(&e, &underlying_2).index, 0);
+            assert_eq!(storage::get_res_free_list(&e).len(), 0);
+            assert!(!storage::has_res(&e, &underlying_0));
+            assert!(!storage::has_res_sunset(&e, &underlying_0));
+        });
+    }
+
+    #[test]
+    fn test_reclaim_reserve_resets_emissions() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 500,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_supply = 0;
+        reserve_data.d_supply = 0;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_pool_emissions(&e, &map![&e, (1, 1_0000000)]);
+            storage::set_res_emis_data(
+                &e,
+                &1,
+                &ReserveEmissionData {
+                    expiration: 500 + SECONDS_PER_WEEK,
+                    eps: 0_1000000000000,
+                    index: 123456789,
+                    last_time: 500,
+                },
+            );
+            storage::set_user_emissions(
+                &e,
+                &samwise,
+                &1,
+                &UserEmissionData {
+                    index: 100000000,
+                    accrued: 5_0000000,
+                },
+            );
+
+            execute_queue_sunset_reserve(&e, &underlying_0);
+            execute_sunset_reserve(&e, &underlying_0);
+            execute_retire_reserve(&e, &underlying_0);
+
+            e.ledger().set_timestamp(1000);
+            storage::set_queued_reserve_set(
+                &e,
+                &QueuedReserveInit {
+                    new_config: reserve_config.clone(),
+                    unlock_time: e.ledger().timestamp(),
+                },
+                &underlying_1,
+            );
+            let new_index = execute_set_reserve(&e, &underlying_1);
+            assert_eq!(new_index, 0);
+
+            // reserve emissions are reset past the retired reserve's index
+            let emis_data = storage::get_res_emis_data(&e, &1).unwrap();
+            assert_eq!(emis_data.expiration, 1000);
+            assert_eq!(emis_data.eps, 0);
+            assert_eq!(emis_data.index, 123456790);
+            assert_eq!(emis_data.last_time, 1000);
+            assert_eq!(storage::get_res_emis_floor(&e, &1), 123456790);
+            assert!(storage::get_res_emis_data(&e, &0).is_none());
+            assert_eq!(storage::get_res_emis_floor(&e, &0), 0);
+
+            // stale user emissions are rebased onto the floor and keep their accrued emissions
+            let user_data = crate::emissions::load_user_emissions(&e, &samwise, 1).unwrap();
+            assert_eq!(user_data.index, 123456790);
+            assert_eq!(user_data.accrued, 5_0000000);
+            crate::emissions::update_emissions(&e, 1, 0, SCALAR_7, &samwise, 0);
+            let user_data = crate::emissions::load_user_emissions(&e, &samwise, 1).unwrap();
+            assert_eq!(user_data.index, 123456790);
+            assert_eq!(user_data.accrued, 5_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_sunset_reserve_requires_unlock() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 500,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            execute_queue_sunset_reserve(&e, &underlying);
+            e.ledger().set_timestamp(500 + SECONDS_PER_WEEK - 1);
+            execute_sunset_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_retire_reserve_requires_empty_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.ledger().set(LedgerInfo {
+            timestamp: 500,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let mut pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 6,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_sunset_reserve(&e, &underlying);
+            execute_sunset_reserve(&e, &underlying);
+
+            pool_config.status = 0;
+            storage::set_pool_config(&e, &pool_config);
+            execute_retire_reserve(&e, &underlying);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_queue_set_reserve_sunset_reserve() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let pool = testutils::create_pool(&e);
+        let bombadil = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            execute_queue_sunset_reserve(&e, &underlying);
+
+            execute_queue_set_reserve(&e, &underlying, &reserve_config);
+        });
+    }
+
     #[test]
     fn test_validate_reserve_metadata() {
         let e = Env::default();
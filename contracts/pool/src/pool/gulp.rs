@@ -1,6 +1,9 @@
 use sep_41_token::TokenClient;
+use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{Address, Env};
 
+use crate::{constants::SCALAR_7, emissions, storage};
+
 use super::{Pool, RequestType, Reserve};
 
 /// Gulps the excess tokens in the pool, determined by the difference between the pool token balance
@@ -8,14 +11,16 @@
 ///
 /// ### Arguments
 /// * `asset` - The address of the asset to gulp
+/// * `keeper` - The address of the keeper performing the gulp, if any. The keeper is rewarded if
+///   the gulped delta is at least the pool's keeper gulp threshold.
 ///
 /// ### Returns
 /// * The gulped token delta accrued to the backstop credit
 ///
 /// ### Panics
 /// * If borrowing is not enabled on the pool. This ensures that the backstop can safely process
-/// interest auctions.
-pub fn execute_gulp(e: &Env, asset: &Address) -> i128 {
+///   interest auctions.
+pub fn execute_gulp(e: &Env, asset: &Address, keeper: Option<Address>) -> i128 {
     let pool = Pool::load(e);
 
     // ensure the backstop can safely accept new interest
@@ -23,24 +28,37 @@ pub fn execute_gulp(e: &Env, asset: &Address) -> i128 {
 
     let mut reserve = Reserve::load(e, &pool.config, asset);
     let pool_token_balance = TokenClient::new(e, asset).balance(&e.current_contract_address());
-    let reserve_token_balance =
-        reserve.total_supply(e) + reserve.data.backstop_credit - reserve.total_liabilities(e);
+    let reserve_token_balance = reserve.total_supply(e) + reserve.data.backstop_credit
+        - reserve.total_liabilities(e)
+        + storage::get_withdrawal_total(e, asset);
     let token_balance_delta = pool_token_balance - reserve_token_balance;
     if token_balance_delta <= 0 {
         return 0;
     }
 
+    let keeper_threshold = reserve.total_supply(e).fixed_mul_floor(
+        e,
+        &i128::from(storage::get_keeper_config(e).gulp_threshold),
+        &SCALAR_7,
+    );
+
     reserve.data.backstop_credit += token_balance_delta;
     reserve.store(e);
 
-    return token_balance_delta;
+    if let Some(keeper) = keeper {
+        if token_balance_delta >= keeper_threshold {
+            emissions::pay_keeper_reward(e, &keeper);
+        }
+    }
+
+    token_balance_delta
 }
 
 #[cfg(test)]
 mod tests {
     use crate::constants::SCALAR_7;
     use crate::pool::execute_gulp;
-    use crate::storage::{self, PoolConfig};
+    use crate::storage::{self, KeeperConfig, KeeperData, PoolConfig};
     use crate::testutils;
     use soroban_sdk::{
         testutils::{Address as _, Ledger, LedgerInfo},
@@ -88,7 +106,7 @@ fn test_execute_gulp() {
             };
             storage::set_pool_config(&e, &pool_config);
 
-            let token_delta_result = execute_gulp(&e, &underlying);
+            let token_delta_result = execute_gulp(&e, &underlying, None);
             assert_eq!(token_delta_result, additional_tokens);
 
             let new_reserve_data = storage::get_res_data(&e, &underlying);
@@ -141,7 +159,7 @@ fn test_execute_gulp_accrues_interest_before_gulp() {
             };
             storage::set_pool_config(&e, &pool_config);
 
-            let token_delta_result = execute_gulp(&e, &underlying);
+            let token_delta_result = execute_gulp(&e, &underlying, None);
             assert_eq!(token_delta_result, additional_tokens);
 
             let new_reserve_data = storage::get_res_data(&e, &underlying);
@@ -193,7 +211,7 @@ fn test_execute_gulp_zero_delta_skips() {
             };
             storage::set_pool_config(&e, &pool_config);
 
-            let token_delta_result = execute_gulp(&e, &underlying);
+            let token_delta_result = execute_gulp(&e, &underlying, None);
             assert_eq!(token_delta_result, 0);
 
             // data not set
@@ -243,7 +261,7 @@ fn test_execute_gulp_negative_delta_skips() {
             };
             storage::set_pool_config(&e, &pool_config);
 
-            let token_delta_result = execute_gulp(&e, &underlying);
+            let token_delta_result = execute_gulp(&e, &underlying, None);
             assert_eq!(token_delta_result, 0);
 
             // data not set
@@ -296,7 +314,95 @@ fn test_execute_gulp_checks_status() {
             };
             storage::set_pool_config(&e, &pool_config);
 
-            execute_gulp(&e, &underlying);
+            execute_gulp(&e, &underlying, None);
+        });
+    }
+
+    #[test]
+    fn test_execute_gulp_pays_keeper_reward() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set(LedgerInfo {
+            timestamp: 100,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let bombadil = Address::generate(&e);
+        let keeper = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, _) = testutils::create_mock_oracle(&e);
+
+        let (blnd, blnd_client) = testutils::create_blnd_token(&e, &pool, &bombadil);
+        let (backstop, _) = testutils::create_backstop(
+            &e,
+            &pool,
+            &Address::generate(&e),
+            &Address::generate(&e),
+            &blnd,
+        );
+        e.as_contract(&backstop, || {
+            blnd_client.approve(&backstop, &pool, &10_0000000, &1000000);
+        });
+        blnd_client.mint(&backstop, &10_0000000);
+
+        let (underlying, underlying_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.b_rate = 1_000_000_000_000;
+        reserve_data.d_rate = 1_000_000_000_000;
+        reserve_data.d_supply = 500 * SCALAR_7;
+        reserve_data.b_supply = 1000 * SCALAR_7;
+        reserve_data.backstop_credit = 0;
+        reserve_data.last_time = 100;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        e.as_contract(&pool, || {
+            let pool_config = PoolConfig {
+                oracle,
+                min_collateral: 1_0000000,
+                bstop_rate: 0_1000000,
+                status: 1,
+                max_positions: 4,
+            };
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 1_0000000,
+                    max_per_period: 10_0000000,
+                    gulp_threshold: 0_0100000,
+                },
+            );
+            storage::set_keeper_data(
+                &e,
+                &KeeperData {
+                    budget: 10_0000000,
+                    period_start: 0,
+                    paid: 0,
+                },
+            );
+        });
+
+        // gulp under 1% of total supply does not reward the keeper
+        underlying_client.mint(&pool, &(5 * SCALAR_7));
+        e.as_contract(&pool, || {
+            let token_delta_result = execute_gulp(&e, &underlying, Some(keeper.clone()));
+            assert_eq!(token_delta_result, 5 * SCALAR_7);
+        });
+        assert_eq!(blnd_client.balance(&keeper), 0);
+
+        // gulp of at least 1% of total supply rewards the keeper
+        underlying_client.mint(&pool, &(10 * SCALAR_7));
+        e.as_contract(&pool, || {
+            let token_delta_result = execute_gulp(&e, &underlying, Some(keeper.clone()));
+            assert_eq!(token_delta_result, 10 * SCALAR_7);
+            assert_eq!(storage::get_keeper_data(&e).budget, 9_0000000);
         });
+        assert_eq!(blnd_client.balance(&keeper), 1_0000000);
     }
 }
@@ -32,7 +32,11 @@ pub fn calculate_from_positions(e: &Env, pool: &mut Pool, positions: &Positions)
         let mut liability_base = 0;
         let mut collateral_raw = 0;
         let mut liability_raw = 0;
-        for i in 0..reserve_list.len() {
+        // only visit the reserves the user holds effective positions in
+        let mut reserve_bitmap = positions.effective_bitmap();
+        while reserve_bitmap != 0 {
+            let i = reserve_bitmap.trailing_zeros();
+            reserve_bitmap &= reserve_bitmap - 1;
             let b_token_balance = positions.collateral.get(i).unwrap_or(0);
             let d_token_balance = positions.liabilities.get(i).unwrap_or(0);
             if b_token_balance == 0 && d_token_balance == 0 {
@@ -206,6 +210,87 @@ fn test_calculate_from_positions() {
         });
     }
 
+    #[test]
+    fn test_calculate_from_positions_with_base_asset() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.decimals = 9;
+        reserve_config.c_factor = 0_8500000;
+        reserve_config.l_factor = 0_8000000;
+        reserve_data.b_supply = 100_000_000_000;
+        reserve_data.d_supply = 70_000_000_000;
+        reserve_data.b_rate = 1_100_000_000_000;
+        reserve_data.d_rate = 1_150_000_000_000;
+        reserve_config.index = 1;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 2_5000000]);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 0,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 5,
+        };
+
+        let positions = Positions {
+            liabilities: map![&e, (1, 50_987_654_321)],
+            collateral: map![&e, (0, 100_1234567)],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            let mut pool = Pool::load(&e);
+            let usd_data = PositionData::calculate_from_positions(&e, &mut pool, &positions);
+
+            storage::set_base_asset(&e, &underlying_1);
+            let mut pool = Pool::load(&e);
+            let rebased_data = PositionData::calculate_from_positions(&e, &mut pool, &positions);
+
+            // balances are denominated in units of underlying_1, worth 2.5 USD
+            assert!((rebased_data.collateral_base * 25 / 10 - usd_data.collateral_base).abs() <= 3);
+            assert!((rebased_data.liability_base * 25 / 10 - usd_data.liability_base).abs() <= 3);
+            assert_eq!(rebased_data.scalar, usd_data.scalar);
+
+            // the health factor does not depend on the base asset, up to rounding
+            assert!((rebased_data.as_health_factor(&e) - usd_data.as_health_factor(&e)).abs() <= 1);
+        });
+    }
+
     #[test]
     fn test_as_health_factor_rounds_floor() {
         let e = Env::default();
@@ -236,7 +321,7 @@ fn test_is_hf_under() {
 
         let result = position_data.is_hf_under(&e, 1_0000100);
         // no panic
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -253,7 +338,7 @@ fn test_is_hf_under_odd_scalar() {
 
         let result = position_data.is_hf_under(&e, 1_0000100);
         // no panic
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -270,7 +355,7 @@ fn test_is_hf_under_no_liabilites() {
 
         let result = position_data.is_hf_under(&e, 1_0000100);
         // no panic
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -304,7 +389,7 @@ fn test_is_hf_over() {
 
         let result = position_data.is_hf_over(&e, 1_1000000);
         // no panic
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -321,7 +406,7 @@ fn test_is_hf_over_odd_scalar() {
 
         let result = position_data.is_hf_over(&e, 1_1000000);
         // no panic
-        assert_eq!(result, false);
+        assert!(!result);
     }
 
     #[test]
@@ -8,33 +8,25 @@
     PoolError,
 };
 
-/// Calculates the loan accrual ratio for the Reserve based on the current utilization and
+/// Calculates the current interest rate for the Reserve based on the current utilization and
 /// rate modifier for the reserve.
 ///
 /// ### Arguments
-/// * `config` - The Reserve config to calculate an accrual for
+/// * `config` - The Reserve config to calculate the interest rate for
 /// * `cur_util` - The current utilization rate of the reserve (7 decimals)
-/// * `ir_mod` - The current interest rate modifier of the reserve (9 decimals)
-/// * `last_block` - The last block an accrual was performed
+/// * `ir_mod` - The current interest rate modifier of the reserve (7 decimals)
 ///
 /// ### Returns
-/// * (i128, i128) - (accrual amount scaled to 9 decimal places, new interest rate modifier scaled to 9 decimal places)
+/// * i128 - The annual interest rate for borrowers (7 decimals)
 #[allow(clippy::zero_prefixed_literal)]
-pub fn calc_accrual(
-    e: &Env,
-    config: &ReserveConfig,
-    cur_util: i128,
-    ir_mod: i128,
-    last_time: u64,
-) -> (i128, i128) {
-    let cur_ir: i128;
+pub fn calc_interest_rate(e: &Env, config: &ReserveConfig, cur_util: i128, ir_mod: i128) -> i128 {
     let target_util: i128 = i128(config.util);
     if cur_util <= target_util {
         let util_scalar = cur_util.fixed_div_ceil(e, &target_util, &SCALAR_7);
         let base_rate =
             util_scalar.fixed_mul_ceil(e, &i128(config.r_one), &SCALAR_7) + i128(config.r_base);
 
-        cur_ir = base_rate.fixed_mul_ceil(e, &ir_mod, &SCALAR_7);
+        base_rate.fixed_mul_ceil(e, &ir_mod, &SCALAR_7)
     } else if cur_util <= 0_9500000 {
         let util_scalar =
             (cur_util - target_util).fixed_div_ceil(e, &(0_9500000 - target_util), &SCALAR_7);
@@ -42,7 +34,7 @@ pub fn calc_accrual(
             + i128(config.r_one)
             + i128(config.r_base);
 
-        cur_ir = base_rate.fixed_mul_ceil(e, &ir_mod, &SCALAR_7);
+        base_rate.fixed_mul_ceil(e, &ir_mod, &SCALAR_7)
     } else {
         let util_scalar = (cur_util - 0_9500000).fixed_div_ceil(e, &0_0500000, &SCALAR_7);
         let extra_rate = util_scalar.fixed_mul_ceil(e, &i128(config.r_three), &SCALAR_7);
@@ -52,8 +44,31 @@ pub fn calc_accrual(
             &i128(config.r_two + config.r_one + config.r_base),
             &SCALAR_7,
         );
-        cur_ir = extra_rate + intersection;
+        extra_rate + intersection
     }
+}
+
+/// Calculates the loan accrual ratio for the Reserve based on the current utilization and
+/// rate modifier for the reserve.
+///
+/// ### Arguments
+/// * `config` - The Reserve config to calculate an accrual for
+/// * `cur_util` - The current utilization rate of the reserve (7 decimals)
+/// * `ir_mod` - The current interest rate modifier of the reserve (9 decimals)
+/// * `last_block` - The last block an accrual was performed
+///
+/// ### Returns
+/// * (i128, i128) - (accrual amount scaled to 9 decimal places, new interest rate modifier scaled to 9 decimal places)
+#[allow(clippy::zero_prefixed_literal)]
+pub fn calc_accrual(
+    e: &Env,
+    config: &ReserveConfig,
+    cur_util: i128,
+    ir_mod: i128,
+    last_time: u64,
+) -> (i128, i128) {
+    let cur_ir = calc_interest_rate(e, config, cur_util, ir_mod);
+    let target_util: i128 = i128(config.util);
 
     // update rate_modifier
     let delta_time = i128(e.ledger().timestamp() - last_time);
@@ -0,0 +1,808 @@
+use sep_41_token::TokenClient;
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    map, panic_with_error, vec, Address, Env, IntoVal, Map, Symbol, Vec,
+};
+
+use crate::{
+    dependencies::PoolClient, errors::PoolError, events::PoolEvents, storage, AuctionType,
+};
+
+use super::{
+    actions::{require_min_borrow, Request, RequestType},
+    pool::Pool,
+    submit::validate_submit,
+    Positions, User,
+};
+
+/// Transfer all positions held by `from` to `to`. Liabilities, collateral and supply are moved
+/// share for share, and emissions are accrued for both users before their balances change.
+///
+/// `from` and `to` MUST be authenticated before calling
+///
+/// Returns the new positions for `to`
+///
+/// ### Arguments
+/// * from - The address of the user whose positions are being transferred
+/// * to - The address of the user receiving the positions
+///
+/// ### Panics
+/// * If `from` and `to` are the same, or either is the pool or the backstop
+/// * If either user has an active liquidation auction
+/// * If `to` exceeds the max positions or is unhealthy after the transfer
+pub fn execute_transfer_positions(e: &Env, from: &Address, to: &Address) -> Positions {
+    let backstop = storage::get_backstop(e);
+    if from == to
+        || from == &e.current_contract_address()
+        || to == &e.current_contract_address()
+        || from == &backstop
+        || to == &backstop
+    {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    let liquidation = AuctionType::UserLiquidation as u32;
+    if storage::has_auction(e, &liquidation, from) || storage::has_auction(e, &liquidation, to) {
+        panic_with_error!(e, PoolError::AuctionInProgress);
+    }
+
+    let mut pool = Pool::load(e);
+    let mut from_state = User::load(e, from);
+    let mut to_state = User::load(e, to);
+    let prev_positions_count = to_state.positions.effective_count();
+    let reserve_list = storage::get_res_list(e);
+
+    for (reserve_index, amount) in from_state.positions.liabilities.clone().iter() {
+        let mut reserve = pool.load_reserve(e, &reserve_list.get_unchecked(reserve_index), true);
+        from_state.remove_liabilities(e, &mut reserve, amount);
+        to_state.add_liabilities(e, &mut reserve, amount);
+        pool.cache_reserve(reserve);
+    }
+    for (reserve_index, amount) in from_state.positions.collateral.clone().iter() {
+        let mut reserve = pool.load_reserve(e, &reserve_list.get_unchecked(reserve_index), true);
+        from_state.remove_collateral(e, &mut reserve, amount);
+        to_state.add_collateral(e, &mut reserve, amount);
+        pool.cache_reserve(reserve);
+    }
+    for (reserve_index, amount) in from_state.positions.supply.clone().iter() {
+        let mut reserve = pool.load_reserve(e, &reserve_list.get_unchecked(reserve_index), true);
+        from_state.remove_supply(e, &mut reserve, amount);
+        to_state.add_supply(e, &mut reserve, amount);
+        pool.cache_reserve(reserve);
+    }
+
+    // `from` no longer holds any positions, so only `to` needs to be validated
+    validate_submit(
+        e,
+        &mut pool,
+        &to_state,
+        prev_positions_count,
+        true,
+        &vec![e],
+    );
+
+    pool.store_cached_reserves(e);
+    from_state.store(e);
+    to_state.store(e);
+
+    PoolEvents::transfer_positions(e, from.clone(), to.clone());
+    to_state.positions
+}
+
+/// Migrate positions held by `from` in `source_pool` into this pool.
+///
+/// This pool flash lends the `repay` amounts to `from` as new liabilities and repays them in
+/// `source_pool` on behalf of `from`, while withdrawing the `withdraw` collateral from
+/// `source_pool`. Any tokens returned by `source_pool` are supplied as collateral for `from`
+/// in this pool, and the health of `from` is validated against this pool once all tokens
+/// have been received.
+///
+/// `from` MUST be authenticated before calling, and MUST authorize the `submit` call made
+/// against `source_pool`.
+///
+/// Returns the new positions for `from` in this pool
+///
+/// ### Arguments
+/// * from - The address of the user whose positions are being migrated
+/// * source_pool - The address of the pool the positions are migrated out of
+/// * repay - A map of asset to the amount of underlying to repay in `source_pool`
+/// * withdraw - A map of asset to the amount of underlying collateral to withdraw from `source_pool`
+///
+/// ### Panics
+/// * If the migration is invalid or the `source_pool` submit fails
+/// * If a repayment is smaller than the pool's minimum borrow
+/// * If `from` is unhealthy or over the pool's maximum liability after the migration
+pub fn execute_migrate(
+    e: &Env,
+    from: &Address,
+    source_pool: &Address,
+    repay: Map<Address, i128>,
+    withdraw: Map<Address, i128>,
+) -> Positions {
+    let pool_address = e.current_contract_address();
+    if from == &pool_address || source_pool == &pool_address || from == source_pool {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    if repay.is_empty() && withdraw.is_empty() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    let mut pool = Pool::load(e);
+    let mut from_state = User::load(e, from);
+    let prev_positions_count = from_state.positions.effective_count();
+
+    let mut source_requests: Vec<Request> = vec![e];
+    let mut check_max_util: Vec<Address> = vec![e];
+    // the expected token balance of the pool for each asset once the repayments are sent
+    let mut expected_balances: Map<Address, i128> = map![e];
+    let mut transfer_auths: Vec<InvokerContractAuthEntry> = vec![e];
+
+    if !repay.is_empty() {
+        pool.require_action_allowed(e, RequestType::Borrow as u32);
+    }
+    for (asset, amount) in repay.iter() {
+        if amount <= 0 {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+        let mut reserve = pool.load_reserve(e, &asset, true);
+        reserve.require_action_allowed(e, RequestType::Borrow as u32);
+        require_min_borrow(e, &mut pool, &reserve, amount);
+        let d_tokens_minted = reserve.to_d_token_up(e, amount);
+        from_state.add_liabilities(e, &mut reserve, d_tokens_minted);
+        reserve.require_utilization_below_100(e);
+        pool.cache_reserve(reserve);
+        check_max_util.push_back(asset.clone());
+
+        let balance = TokenClient::new(e, &asset).balance(&pool_address);
+        expected_balances.set(asset.clone(), balance - amount);
+        source_requests.push_back(Request {
+            request_type: RequestType::Repay as u32,
+            address: asset.clone(),
+            amount,
+        });
+        transfer_auths.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: asset.clone(),
+                fn_name: Symbol::new(e, "transfer"),
+                args: vec![
+                    e,
+                    pool_address.into_val(e),
+                    source_pool.into_val(e),
+                    amount.into_val(e),
+                ],
+            },
+            sub_invocations: vec![e],
+        }));
+
+        PoolEvents::borrow(e, asset, from.clone(), amount, d_tokens_minted);
+    }
+    for (asset, amount) in withdraw.iter() {
+        if amount <= 0 {
+            panic_with_error!(e, PoolError::BadRequest);
+        }
+        if !expected_balances.contains_key(asset.clone()) {
+            let balance = TokenClient::new(e, &asset).balance(&pool_address);
+            expected_balances.set(asset.clone(), balance);
+        }
+        source_requests.push_back(Request {
+            request_type: RequestType::WithdrawCollateral as u32,
+            address: asset,
+            amount,
+        });
+    }
+
+    // repay and withdraw in the source pool on behalf of `from`. The pool is the
+    // spender of the repayments and the receiver of the withdrawn collateral.
+    if !transfer_auths.is_empty() {
+        e.authorize_as_current_contract(transfer_auths);
+    }
+    PoolClient::new(e, source_pool).submit(from, &pool_address, &pool_address, &source_requests);
+
+    // supply everything returned by the source pool as collateral for `from`
+    for (asset, expected_balance) in expected_balances.iter() {
+        let received = TokenClient::new(e, &asset).balance(&pool_address) - expected_balance;
+        if received <= 0 {
+            continue;
+        }
+        let mut reserve = pool.load_reserve(e, &asset, true);
+        reserve.require_action_allowed(e, RequestType::SupplyCollateral as u32);
+        let b_tokens_minted = reserve.to_b_token_down(e, received);
+        from_state.add_collateral(e, &mut reserve, b_tokens_minted);
+        if reserve.total_supply(e) > reserve.config.supply_cap {
+            panic_with_error!(e, PoolError::ExceededSupplyCap);
+        }
+        pool.cache_reserve(reserve);
+
+        PoolEvents::supply_collateral(e, asset, from.clone(), received, b_tokens_minted);
+    }
+
+    validate_submit(
+        e,
+        &mut pool,
+        &from_state,
+        prev_positions_count,
+        true,
+        &check_max_util,
+    );
+
+    pool.store_cached_reserves(e);
+    from_state.store(e);
+
+    PoolEvents::migrate(e, source_pool.clone(), from.clone());
+    from_state.positions
+}
+
+/// Move the positions of `users` from the legacy map storage into the packed storage, and delete
+/// the legacy entries. If a user already has packed positions, they are newer than the legacy
+/// ones and are kept. Users without legacy positions are skipped.
+///
+/// Returns the number of users whose legacy positions were removed
+///
+/// ### Arguments
+/// * users - The addresses of the users to migrate
+pub fn execute_migrate_positions(e: &Env, users: &Vec<Address>) -> u32 {
+    let mut migrated: u32 = 0;
+    for user in users.iter() {
+        if let Some(positions) = storage::get_legacy_user_positions(e, &user) {
+            if !storage::has_user_positions(e, &user) {
+                storage::set_user_positions(e, &user, &positions);
+            }
+            storage::del_legacy_user_positions(e, &user);
+            migrated += 1;
+        }
+    }
+    migrated
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        storage::{self, PoolConfig, PoolLoanLimits},
+        testutils, AuctionData,
+    };
+
+    use super::*;
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger, LedgerInfo},
+        vec, Symbol,
+    };
+
+    #[test]
+    fn test_transfer_positions() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        let positions = Positions {
+            liabilities: map![&e, (1, 1_0000000)],
+            collateral: map![&e, (0, 20_0000000)],
+            supply: map![&e, (1, 5_0000000)],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &positions);
+            let pre_res_0_data = storage::get_res_data(&e, &underlying_0);
+            let pre_res_1_data = storage::get_res_data(&e, &underlying_1);
+
+            let new_positions = execute_transfer_positions(&e, &samwise, &frodo);
+
+            assert_eq!(new_positions.liabilities.get_unchecked(1), 1_0000000);
+            assert_eq!(new_positions.collateral.get_unchecked(0), 20_0000000);
+            assert_eq!(new_positions.supply.get_unchecked(1), 5_0000000);
+
+            let samwise_positions = storage::get_user_positions(&e, &samwise);
+            assert_eq!(samwise_positions.liabilities.len(), 0);
+            assert_eq!(samwise_positions.collateral.len(), 0);
+            assert_eq!(samwise_positions.supply.len(), 0);
+
+            let frodo_positions = storage::get_user_positions(&e, &frodo);
+            assert_eq!(frodo_positions.effective_count(), 2);
+            assert_eq!(frodo_positions.supply.get_unchecked(1), 5_0000000);
+
+            // reserve supplies are unchanged by the transfer
+            let res_0_data = storage::get_res_data(&e, &underlying_0);
+            assert_eq!(res_0_data.b_supply, pre_res_0_data.b_supply);
+            assert_eq!(res_0_data.d_supply, pre_res_0_data.d_supply);
+            let res_1_data = storage::get_res_data(&e, &underlying_1);
+            assert_eq!(res_1_data.b_supply, pre_res_1_data.b_supply);
+            assert_eq!(res_1_data.d_supply, pre_res_1_data.d_supply);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1205)")]
+    fn test_transfer_positions_unhealthy_receiver() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 4,
+        };
+        let samwise_positions = Positions {
+            liabilities: map![&e, (1, 1_0000000)],
+            collateral: map![&e, (0, 20_0000000)],
+            supply: map![&e],
+        };
+        let frodo_positions = Positions {
+            liabilities: map![&e, (1, 5_0000000)],
+            collateral: map![&e],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &samwise_positions);
+            storage::set_user_positions(&e, &frodo, &frodo_positions);
+
+            execute_transfer_positions(&e, &samwise, &frodo);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_transfer_positions_to_self() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_transfer_positions(&e, &samwise, &samwise);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1212)")]
+    fn test_transfer_positions_during_liquidation() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let auction_data = AuctionData {
+            bid: map![&e],
+            lot: map![&e],
+            block: 100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_auction(
+                &e,
+                &(AuctionType::UserLiquidation as u32),
+                &samwise,
+                &auction_data,
+            );
+
+            execute_transfer_positions(&e, &samwise, &frodo);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1212)")]
+    fn test_transfer_positions_to_user_in_liquidation() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let auction_data = AuctionData {
+            bid: map![&e],
+            lot: map![&e],
+            block: 100,
+        };
+        e.as_contract(&pool, || {
+            storage::set_auction(
+                &e,
+                &(AuctionType::UserLiquidation as u32),
+                &frodo,
+                &auction_data,
+            );
+
+            execute_transfer_positions(&e, &samwise, &frodo);
+        });
+    }
+
+    #[test]
+    fn test_migrate() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let source_pool = testutils::create_pool(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, underlying_0_client) = testutils::create_token_contract(&e, &bombadil);
+        let (underlying_1, underlying_1_client) = testutils::create_token_contract(&e, &bombadil);
+        for pool_address in [source_pool.clone(), pool.clone()] {
+            let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+            reserve_data.last_time = 600;
+            testutils::create_reserve(
+                &e,
+                &pool_address,
+                &underlying_0,
+                &reserve_config,
+                &reserve_data,
+            );
+            testutils::create_reserve(
+                &e,
+                &pool_address,
+                &underlying_1,
+                &reserve_config,
+                &reserve_data,
+            );
+            e.as_contract(&pool_address, || {
+                storage::set_pool_config(
+                    &e,
+                    &PoolConfig {
+                        oracle: oracle.clone(),
+                        min_collateral: 1_0000000,
+                        bstop_rate: 0_1000000,
+                        status: 0,
+                        max_positions: 4,
+                    },
+                );
+            });
+        }
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 2_0000000]);
+
+        // samwise has 20 underlying_0 of collateral against 5 underlying_1 in the source pool
+        e.as_contract(&source_pool, || {
+            storage::set_user_positions(
+                &e,
+                &samwise,
+                &Positions {
+                    liabilities: map![&e, (1, 5_0000000)],
+                    collateral: map![&e, (0, 20_0000000)],
+                    supply: map![&e],
+                },
+            );
+        });
+
+        let pre_source_0 = underlying_0_client.balance(&source_pool);
+        let pre_source_1 = underlying_1_client.balance(&source_pool);
+        let pre_pool_0 = underlying_0_client.balance(&pool);
+        let pre_pool_1 = underlying_1_client.balance(&pool);
+
+        let repay = map![&e, (underlying_1.clone(), 5_0000000)];
+        let withdraw = map![&e, (underlying_0.clone(), 20_0000000)];
+        let pool_client = crate::PoolClient::new(&e, &pool);
+        let positions = pool_client.migrate(&samwise, &source_pool, &repay, &withdraw);
+
+        // `from` authorizes the migration and the submit made against the source pool
+        let source_requests = vec![
+            &e,
+            Request {
+                request_type: RequestType::Repay as u32,
+                address: underlying_1.clone(),
+                amount: 5_0000000,
+            },
+            Request {
+                request_type: RequestType::WithdrawCollateral as u32,
+                address: underlying_0.clone(),
+                amount: 20_0000000,
+            },
+        ];
+        assert_eq!(
+            e.auths()[0],
+            (
+                samwise.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        pool.clone(),
+                        Symbol::new(&e, "migrate"),
+                        vec![
+                            &e,
+                            samwise.to_val(),
+                            source_pool.to_val(),
+                            repay.to_val(),
+                            withdraw.to_val(),
+                        ]
+                    )),
+                    sub_invocations: std::vec![AuthorizedInvocation {
+                        function: AuthorizedFunction::Contract((
+                            source_pool.clone(),
+                            Symbol::new(&e, "submit"),
+                            vec![
+                                &e,
+                                samwise.to_val(),
+                                pool.to_val(),
+                                pool.to_val(),
+                                source_requests.to_val(),
+                            ]
+                        )),
+                        sub_invocations: std::vec![]
+                    }]
+                }
+            )
+        );
+
+        // the repayment moves from this pool to the source pool, and the collateral moves back
+        assert_eq!(
+            underlying_1_client.balance(&source_pool),
+            pre_source_1 + 5_0000000
+        );
+        assert_eq!(
+            underlying_0_client.balance(&source_pool),
+            pre_source_0 - 20_0000000
+        );
+        assert_eq!(underlying_1_client.balance(&pool), pre_pool_1 - 5_0000000);
+        assert_eq!(underlying_0_client.balance(&pool), pre_pool_0 + 20_0000000);
+        assert_eq!(underlying_0_client.balance(&samwise), 0);
+        assert_eq!(underlying_1_client.balance(&samwise), 0);
+
+        let source_positions = crate::PoolClient::new(&e, &source_pool).get_positions(&samwise);
+        assert_eq!(source_positions.liabilities.len(), 0);
+        assert_eq!(source_positions.collateral.len(), 0);
+
+        assert_eq!(positions.liabilities.get_unchecked(1), 5_0000000);
+        assert_eq!(positions.collateral.get_unchecked(0), 20_0000000);
+        assert_eq!(positions.supply.len(), 0);
+        let pool_positions = pool_client.get_positions(&samwise);
+        assert_eq!(pool_positions.liabilities, positions.liabilities);
+        assert_eq!(pool_positions.collateral, positions.collateral);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1225)")]
+    fn test_migrate_under_min_borrow() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let source_pool = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 600;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![&e, Asset::Stellar(underlying_0.clone())],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000]);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                &e,
+                &PoolConfig {
+                    oracle,
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 0,
+                    max_positions: 4,
+                },
+            );
+            storage::set_loan_limits(
+                &e,
+                &PoolLoanLimits {
+                    min_borrow: 10_0000000,
+                    max_liability: 0,
+                },
+            );
+
+            execute_migrate(
+                &e,
+                &samwise,
+                &source_pool,
+                map![&e, (underlying_0.clone(), 5_0000000)],
+                map![&e],
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_migrate_from_self() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+
+        e.as_contract(&pool, || {
+            execute_migrate(
+                &e,
+                &samwise,
+                &pool,
+                map![&e, (underlying_0.clone(), 1_0000000)],
+                map![&e],
+            );
+        });
+    }
+
+    #[test]
+    fn test_migrate_positions() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+
+        let legacy_positions = Positions {
+            liabilities: map![&e, (1, 5_0000000)],
+            collateral: map![&e, (0, 10_0000000), (40, 2_0000000)],
+            supply: map![&e, (3, 1_0000000)],
+        };
+        let new_positions = Positions {
+            liabilities: map![&e],
+            collateral: map![&e, (0, 1_0000000)],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            for user in [samwise.clone(), frodo.clone()] {
+                e.storage()
+                    .persistent()
+                    .set(&storage::PoolDataKey::Positions(user), &legacy_positions);
+            }
+            // frodo has stored positions since the packed encoding was introduced, which
+            // replaces their legacy positions
+            storage::set_user_positions(&e, &frodo, &new_positions);
+            assert!(storage::get_legacy_user_positions(&e, &frodo).is_none());
+
+            // legacy positions are loaded until they are migrated
+            let positions = storage::get_user_positions(&e, &samwise);
+            assert_eq!(positions.liabilities, legacy_positions.liabilities);
+            assert_eq!(positions.collateral, legacy_positions.collateral);
+            assert_eq!(positions.supply, legacy_positions.supply);
+
+            let migrated = execute_migrate_positions(
+                &e,
+                &vec![&e, samwise.clone(), frodo.clone(), merry.clone()],
+            );
+            assert_eq!(migrated, 1);
+            assert!(storage::get_legacy_user_positions(&e, &samwise).is_none());
+            assert!(!storage::has_user_positions(&e, &merry));
+
+            let positions = storage::get_user_positions(&e, &samwise);
+            assert_eq!(positions.liabilities, legacy_positions.liabilities);
+            assert_eq!(positions.collateral, legacy_positions.collateral);
+            assert_eq!(positions.supply, legacy_positions.supply);
+            let positions = storage::get_user_positions(&e, &frodo);
+            assert_eq!(positions.collateral, new_positions.collateral);
+            assert_eq!(positions.liabilities.len(), 0);
+
+            // migrating again is a no-op
+            assert_eq!(execute_migrate_positions(&e, &vec![&e, samwise.clone()]), 0);
+        });
+    }
+}
@@ -1,13 +1,22 @@
 mod actions;
 pub use actions::{FlashLoan, Request, RequestType};
 
+mod borrowers;
+pub use borrowers::{execute_index_borrowers, load_borrower_health_factors};
+
 mod bad_debt;
 pub use bad_debt::{bad_debt, check_and_handle_backstop_bad_debt, check_and_handle_user_bad_debt};
 
+mod compound;
+pub use compound::execute_claim_and_supply;
+
 mod config;
 pub use config::{
-    execute_cancel_queued_set_reserve, execute_initialize, execute_queue_set_reserve,
-    execute_set_reserve, execute_update_pool,
+    execute_cancel_queued_set_base_asset, execute_cancel_queued_set_reserve,
+    execute_cancel_sunset_reserve, execute_initialize, execute_queue_set_base_asset,
+    execute_queue_set_reserve, execute_queue_sunset_reserve, execute_retire_reserve,
+    execute_set_base_asset, execute_set_loan_limits, execute_set_reserve, execute_sunset_reserve,
+    execute_update_pool,
 };
 
 mod health_factor;
@@ -15,6 +24,9 @@
 
 mod interest;
 
+mod migration;
+pub use migration::{execute_migrate, execute_migrate_positions, execute_transfer_positions};
+
 mod submit;
 
 pub use submit::{execute_submit, execute_submit_with_flash_loan};
@@ -27,12 +39,33 @@
 pub use reserve::Reserve;
 
 mod user;
-pub use user::{Positions, User};
+pub use user::{PackedPositions, Positions, User};
 
 mod status;
 pub use status::{
-    calc_pool_backstop_threshold, execute_set_pool_status, execute_update_pool_status,
+    calc_pool_backstop_threshold, execute_cancel_queued_set_status_policy,
+    execute_queue_set_status_policy, execute_set_pool_status, execute_set_status_policy,
+    execute_update_pool_status,
 };
 
 mod gulp;
 pub use gulp::execute_gulp;
+
+mod overview;
+pub use overview::{load_pool_overview, PoolOverview, ReserveOverview};
+
+mod upgrade;
+pub use upgrade::{
+    execute_cancel_upgrade, execute_migrate_storage, execute_queue_upgrade, execute_upgrade,
+};
+
+mod ttl;
+pub use ttl::{execute_bump_users, load_user_ledger_keys};
+
+mod withdrawal_queue;
+pub use withdrawal_queue::{
+    execute_claim_withdrawal, load_withdrawal_queue_position, WithdrawalQueuePosition,
+};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,208 @@
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{contracttype, vec, Address, Env, Vec};
+
+use crate::{constants::SCALAR_7, dependencies::BackstopClient, storage};
+
+use super::{calc_pool_backstop_threshold, interest::calc_interest_rate, pool::Pool};
+
+/// A summary of a reserve's utilization and exposure
+#[derive(Clone)]
+#[contracttype]
+pub struct ReserveOverview {
+    pub asset: Address,            // the underlying asset address
+    pub total_supply: i128,        // the total underlying supplied to the reserve
+    pub total_liabilities: i128,   // the total underlying borrowed from the reserve
+    pub utilization: i128,         // the utilization of the reserve (7 decimals)
+    pub borrow_rate: i128,         // the annual interest rate paid by borrowers (7 decimals)
+    pub supply_rate: i128,         // the annual interest rate earned by suppliers (7 decimals)
+    pub backstop_credit: i128,     // the underlying owed to the backstop
+    pub supply_cap_headroom: i128, // the underlying that can be supplied before the cap
+    pub price: i128,               // the price of the asset in the base asset
+    pub supply_base: i128,         // the total supply denominated in the base asset
+    pub liabilities_base: i128,    // the total liabilities denominated in the base asset
+}
+
+/// A summary of the pool's utilization and exposure
+#[derive(Clone)]
+#[contracttype]
+pub struct PoolOverview {
+    pub reserves: Vec<ReserveOverview>, // the overview of each active reserve
+    pub total_supply_base: i128,        // the pool's total supply in the base asset
+    pub total_liabilities_base: i128,   // the pool's total liabilities in the base asset
+    pub price_decimals: u32,            // the decimals of the base asset prices
+    pub status: u32,                    // the pool's status
+    pub backstop_threshold: i128,       // the backstop's threshold percentage (7 decimals)
+}
+
+/// Load an overview of the utilization and exposure of every reserve in the pool. Retired
+/// reserves are not included.
+pub fn load_pool_overview(e: &Env) -> PoolOverview {
+    let mut pool = Pool::load(e);
+    let price_decimals = pool.load_price_decimals(e);
+    let free_list = storage::get_res_free_list(e);
+
+    let mut reserves: Vec<ReserveOverview> = vec![e];
+    let mut total_supply_base: i128 = 0;
+    let mut total_liabilities_base: i128 = 0;
+    for asset in storage::get_res_list(e).iter() {
+        let reserve = pool.load_reserve(e, &asset, false);
+        if free_list.contains(reserve.config.index) {
+            continue;
+        }
+        let price = pool.load_price(e, &asset);
+
+        let total_supply = reserve.total_supply(e);
+        let total_liabilities = reserve.total_liabilities(e);
+        let utilization = reserve.utilization(e);
+        let borrow_rate = calc_interest_rate(e, &reserve.config, utilization, reserve.data.ir_mod);
+        let supply_rate = borrow_rate
+            .fixed_mul_floor(e, &utilization, &SCALAR_7)
+            .fixed_mul_floor(
+                e,
+                &(SCALAR_7 - i128::from(pool.config.bstop_rate)),
+                &SCALAR_7,
+            );
+        let supply_base = price.fixed_mul_floor(e, &total_supply, &reserve.scalar);
+        let liabilities_base = price.fixed_mul_ceil(e, &total_liabilities, &reserve.scalar);
+        total_supply_base += supply_base;
+        total_liabilities_base += liabilities_base;
+
+        reserves.push_back(ReserveOverview {
+            asset,
+            total_supply,
+            total_liabilities,
+            utilization,
+            borrow_rate,
+            supply_rate,
+            backstop_credit: reserve.data.backstop_credit,
+            supply_cap_headroom: (reserve.config.supply_cap - total_supply).max(0),
+            price,
+            supply_base,
+            liabilities_base,
+        });
+        pool.cache_reserve(reserve);
+    }
+
+    let pool_backstop_data =
+        BackstopClient::new(e, &storage::get_backstop(e)).pool_data(&e.current_contract_address());
+    PoolOverview {
+        reserves,
+        total_supply_base,
+        total_liabilities_base,
+        price_decimals,
+        status: pool.config.status,
+        backstop_threshold: calc_pool_backstop_threshold(
+            &pool_backstop_data,
+            storage::get_status_policy(e).threshold_pc,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        storage::PoolConfig,
+        testutils::{self, create_backstop, create_comet_lp_pool, create_pool},
+    };
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger, LedgerInfo},
+        Symbol,
+    };
+
+    #[test]
+    fn test_load_pool_overview() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        e.ledger().set(LedgerInfo {
+            timestamp: 12345,
+            protocol_version: 22,
+            sequence_number: 100,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 172800,
+            min_persistent_entry_ttl: 172800,
+            max_entry_ttl: 9999999,
+        });
+
+        let bombadil = Address::generate(&e);
+        let pool = create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (blnd, _) = testutils::create_token_contract(&e, &bombadil);
+        let (usdc, _) = testutils::create_token_contract(&e, &bombadil);
+        let (lp_token, _) = create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        create_backstop(&e, &pool, &lp_token, &usdc, &blnd);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.supply_cap = 150_0000000;
+        reserve_data.last_time = 12345;
+        reserve_data.backstop_credit = 1_0000000;
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_config.index = 1;
+        reserve_data.last_time = 12345;
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 2_0000000]);
+
+        e.as_contract(&pool, || {
+            storage::set_pool_config(
+                &e,
+                &PoolConfig {
+                    oracle,
+                    min_collateral: 1_0000000,
+                    bstop_rate: 0_1000000,
+                    status: 1,
+                    max_positions: 4,
+                },
+            );
+
+            let overview = load_pool_overview(&e);
+            assert_eq!(overview.reserves.len(), 2);
+            assert_eq!(overview.price_decimals, 7);
+            assert_eq!(overview.status, 1);
+            // the backstop has no deposits for the pool
+            assert_eq!(overview.backstop_threshold, 0);
+
+            // 100 supplied and 75 borrowed at a 75% target utilization
+            let reserve_0 = overview.reserves.get_unchecked(0);
+            assert_eq!(reserve_0.asset, underlying_0);
+            assert_eq!(reserve_0.total_supply, 100_0000000);
+            assert_eq!(reserve_0.total_liabilities, 75_0000000);
+            assert_eq!(reserve_0.utilization, 0_7500000);
+            // r_base + r_one at the target utilization
+            assert_eq!(reserve_0.borrow_rate, 0_0600000);
+            assert_eq!(reserve_0.supply_rate, 0_0405000);
+            assert_eq!(reserve_0.backstop_credit, 1_0000000);
+            assert_eq!(reserve_0.supply_cap_headroom, 50_0000000);
+            assert_eq!(reserve_0.supply_base, 100_0000000);
+            assert_eq!(reserve_0.liabilities_base, 75_0000000);
+
+            let reserve_1 = overview.reserves.get_unchecked(1);
+            assert_eq!(reserve_1.asset, underlying_1);
+            assert_eq!(reserve_1.price, 2_0000000);
+            assert_eq!(reserve_1.supply_base, 200_0000000);
+            assert_eq!(reserve_1.liabilities_base, 150_0000000);
+
+            assert_eq!(overview.total_supply_base, 300_0000000);
+            assert_eq!(overview.total_liabilities_base, 225_0000000);
+        });
+    }
+}
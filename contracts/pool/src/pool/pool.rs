@@ -1,8 +1,10 @@
+use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::{map, panic_with_error, unwrap::UnwrapOptimized, vec, Address, Env, Map, Vec};
 
 use sep_40_oracle::{Asset, PriceFeedClient};
 
 use crate::{
+    constants::SCALAR_12,
     errors::PoolError,
     storage::{self, PoolConfig},
     Positions,
@@ -16,6 +18,8 @@ pub struct Pool {
     reserves_to_store: Vec<Address>,
     price_decimals: Option<u32>,
     prices: Map<Address, i128>,
+    base_asset: Option<Address>,
+    base_rate: Option<i128>,
 }
 
 impl Pool {
@@ -28,42 +32,8 @@ pub fn load(e: &Env) -> Self {
             reserves_to_store: vec![e],
             price_decimals: None,
             prices: map![e],
-        }
-
-    pub fn batch_operations(
-        env: Env,
-        user: Address,
-        operations: Vec<PoolOperation>
-    ) -> Result<Vec<PoolResult>, PoolError> {
-        user.require_auth();
-
-        let mut results = Vec::new(&env);
-
-        // Validate all operations first
-        for operation in &operations {
-            Self::validate_operation(&env, &user, operation)?;
-        }
-
-        // Execute all operations
-        for operation in operations {
-            let result = match operation {
-                PoolOperation::Supply { asset, amount } => {
-                    Self::supply(env.clone(), user.clone(), asset, amount)?;
-                    PoolResult::Supply { success: true }
-                },
-                PoolOperation::Borrow { asset, amount } => {
-                    Self::borrow(env.clone(), user.clone(), asset, amount)?;
-                    PoolResult::Borrow { success: true }
-                },
-                PoolOperation::Repay { asset, amount } => {
-                    Self::repay(env.clone(), user.clone(), asset, amount)?;
-                    PoolResult::Repay { success: true }
-                },
-            };
-            results.push_back(result);
-        }
-
-            Ok(results)
+            base_asset: storage::get_base_asset(e),
+            base_rate: None,
         }
     }
 
@@ -79,7 +49,7 @@ pub fn load_reserve(&mut self, e: &Env, asset: &Address, store: bool) -> Reserve
         }
 
         if let Some(reserve) = self.reserves.get(asset.clone()) {
-            return reserve;
+            reserve
         } else {
             Reserve::load(e, &self.config, asset)
         }
@@ -147,6 +117,10 @@ pub fn load_price_decimals(&mut self, e: &Env) -> u32 {
 
     /// Load a price from the Pool's oracle. Returns a cached version if one already exists.
     ///
+    /// If the pool has a base asset, the price is rebased from the oracle's base asset to the
+    /// pool's base asset, with the same decimals as the oracle. The rebase rate is kept with 12
+    /// decimals so cheap assets are not rounded down before being scaled to the oracle decimals.
+    ///
     /// ### Arguments
     /// * asset - The address of the underlying asset
     ///
@@ -156,13 +130,55 @@ pub fn load_price(&mut self, e: &Env, asset: &Address) -> i128 {
         if let Some(price) = self.prices.get(asset.clone()) {
             return price;
         }
+        let price = match self.base_asset.clone() {
+            Some(base_asset) => {
+                if *asset == base_asset {
+                    10i128.pow(self.load_price_decimals(e))
+                } else {
+                    let base_rate = self.load_base_rate(e, &base_asset);
+                    let price = self
+                        .load_oracle_price(e, asset)
+                        .fixed_mul_floor(e, &base_rate, &SCALAR_12);
+                    if price <= 0 {
+                        panic_with_error!(e, PoolError::InvalidPrice);
+                    }
+                    price
+                }
+            }
+            None => self.load_oracle_price(e, asset),
+        };
+        self.prices.set(asset.clone(), price);
+        price
+    }
+
+    /// Load the rate that rebases an oracle price into the pool's base asset, with 12 decimals.
+    /// Returns a cached version if one already exists.
+    fn load_base_rate(&mut self, e: &Env, base_asset: &Address) -> i128 {
+        if let Some(rate) = self.base_rate {
+            return rate;
+        }
+        let oracle_scalar = 10i128.pow(self.load_price_decimals(e));
+        let rate =
+            oracle_scalar.fixed_div_floor(e, &self.load_oracle_price(e, base_asset), &SCALAR_12);
+        self.base_rate = Some(rate);
+        rate
+    }
+
+    /// Load a price in the oracle's base asset from the Pool's oracle, ignoring the pool's base
+    /// asset. Used when valuing assets against the backstop token, which is priced in USDC.
+    ///
+    /// ### Arguments
+    /// * asset - The address of the underlying asset
+    ///
+    /// ### Panics
+    /// If the price is invalid due to being over a day old or being less than or equal to 0
+    pub fn load_oracle_price(&self, e: &Env, asset: &Address) -> i128 {
         let oracle_client = PriceFeedClient::new(e, &self.config.oracle);
         let oracle_asset = Asset::Stellar(asset.clone());
         let price_data = oracle_client.lastprice(&oracle_asset).unwrap_optimized();
         if price_data.timestamp + 24 * 60 * 60 < e.ledger().timestamp() || price_data.price <= 0 {
             panic_with_error!(e, PoolError::InvalidPrice);
         }
-        self.prices.set(asset.clone(), price_data.price);
         price_data.price
     }
 }
@@ -677,6 +693,104 @@ fn test_load_price() {
         });
     }
 
+    #[test]
+    fn test_load_price_with_base_asset() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let asset_0 = Address::generate(&e);
+        let asset_1 = Address::generate(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(asset_0.clone()),
+                Asset::Stellar(asset_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 0_0500000, 1_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_base_asset(&e, &asset_0);
+            let mut pool = Pool::load(&e);
+
+            // the base asset is always worth one unit of itself
+            let price = pool.load_price(&e, &asset_0);
+            assert_eq!(price, 1_0000000);
+
+            let price = pool.load_price(&e, &asset_1);
+            assert_eq!(price, 20_0000000);
+
+            // verify the rebased price is cached
+            oracle_client.set_price_stable(&vec![&e, 0_1000000, 1_0000000]);
+            let price = pool.load_price(&e, &asset_1);
+            assert_eq!(price, 20_0000000);
+        });
+    }
+
+    #[test]
+    fn test_load_price_with_base_asset_rounds_down() {
+        let e = Env::default();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let asset_0 = Address::generate(&e);
+        let asset_1 = Address::generate(&e);
+        let asset_2 = Address::generate(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(asset_0.clone()),
+                Asset::Stellar(asset_1.clone()),
+                Asset::Stellar(asset_2.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 0_0300000, 1_0000000, 0_0000007]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_base_asset(&e, &asset_0);
+            let mut pool = Pool::load(&e);
+
+            // 1 / 0.03 = 33.3333333...
+            let price = pool.load_price(&e, &asset_1);
+            assert_eq!(price, 33_3333333);
+
+            // 0.0000007 / 0.03 = 0.0000233...
+            let price = pool.load_price(&e, &asset_2);
+            assert_eq!(price, 0_0000233);
+        });
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #1210)")]
     fn test_load_price_panics_if_stale() {
@@ -909,19 +1023,4 @@ fn test_require_under_max_panics_if_over() {
             pool.require_under_max(&e, &user.positions, prev_positions);
         });
     }
-
-
-    #[derive(Clone, Debug)]
-    pub enum PoolOperation {
-        Supply { asset: Address, amount: i128 },
-        Borrow { asset: Address, amount: i128 },
-        Repay { asset: Address, amount: i128 },
-    }
-
-    #[derive(Clone, Debug)]
-    pub enum PoolResult {
-        Supply { success: bool },
-        Borrow { success: bool },
-        Repay { success: bool },
-    }
 }
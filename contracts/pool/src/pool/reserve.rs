@@ -3,10 +3,10 @@
 use soroban_sdk::{contracttype, panic_with_error, Address, Env};
 
 use crate::{
-    constants::{SCALAR_12, SCALAR_7},
+    constants::{SCALAR_12, SCALAR_7, SUNSET_PERIOD},
     errors::PoolError,
     pool::actions::RequestType,
-    storage::{self, PoolConfig, ReserveConfig, ReserveData},
+    storage::{self, PoolConfig, ReserveConfig, ReserveData, ReserveSunset},
 };
 
 use super::interest::calc_accrual;
@@ -42,6 +42,13 @@ pub fn load(e: &Env, pool_config: &PoolConfig, asset: &Address) -> Reserve {
             data: reserve_data,
         };
 
+        // ramp the collateral factor of a sunset reserve down to zero
+        if !reserve.config.enabled {
+            if let Some(sunset) = storage::get_res_sunset(e, asset) {
+                reserve.config.c_factor = calc_sunset_c_factor(e, &sunset);
+            }
+        }
+
         // short circuit if the reserve has already been updated this ledger
         if e.ledger().timestamp() == reserve.data.last_time {
             return reserve;
@@ -145,13 +152,12 @@ pub fn require_utilization_below_100(&self, e: &Env) {
     /// * `action_type` - The type of action being performed
     pub fn require_action_allowed(&self, e: &Env, action_type: u32) {
         // disable borrowing or auction cancellation for any non-active pool and disable supplying for any frozen pool
-        if !self.config.enabled {
-            if action_type == RequestType::Supply as u32
+        if !self.config.enabled
+            && (action_type == RequestType::Supply as u32
                 || action_type == RequestType::SupplyCollateral as u32
-                || action_type == RequestType::Borrow as u32
-            {
-                panic_with_error!(e, PoolError::ReserveDisabled);
-            }
+                || action_type == RequestType::Borrow as u32)
+        {
+            panic_with_error!(e, PoolError::ReserveDisabled);
         }
     }
 
@@ -165,6 +171,12 @@ pub fn total_supply(&self, e: &Env) -> i128 {
         self.to_asset_from_b_token(e, self.data.b_supply)
     }
 
+    /// Fetch the b_token supply that earns emissions. b_tokens queued for withdrawal are
+    /// excluded, as they are not held by any user's positions.
+    pub fn emission_b_supply(&self, e: &Env) -> i128 {
+        self.data.b_supply - storage::get_withdrawal_queued(e, &self.asset)
+    }
+
     /********** Conversion Functions **********/
 
     /// Convert d_tokens to the corresponding asset value
@@ -236,6 +248,22 @@ pub fn to_b_token_down(&self, e: &Env, amount: i128) -> i128 {
     }
 }
 
+/// Calculate the collateral factor of a sunset reserve. The collateral factor ramps linearly
+/// from its value when the sunset began down to zero over the `SUNSET_PERIOD`.
+///
+/// ### Arguments
+/// * `sunset` - The sunset for the reserve
+fn calc_sunset_c_factor(e: &Env, sunset: &ReserveSunset) -> u32 {
+    if sunset.start_time == 0 {
+        return sunset.c_factor;
+    }
+    let elapsed = e.ledger().timestamp() - sunset.start_time;
+    if elapsed >= SUNSET_PERIOD {
+        return 0;
+    }
+    (sunset.c_factor as u64 * (SUNSET_PERIOD - elapsed) / SUNSET_PERIOD) as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -492,6 +520,60 @@ fn test_load_reserve_zero_bstop_rate() {
         });
     }
 
+    #[test]
+    fn test_load_reserve_sunset_ramps_c_factor() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 10000000,
+            protocol_version: 22,
+            sequence_number: 123456,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let oracle = Address::generate(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (mut reserve_config, reserve_data) = testutils::default_reserve_meta();
+        reserve_config.enabled = false;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_2000000,
+            status: 0,
+            max_positions: 5,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_res_sunset(
+                &e,
+                &underlying,
+                &ReserveSunset {
+                    c_factor: 0_7500000,
+                    unlock_time: 10000000 - SUNSET_PERIOD,
+                    start_time: 10000000 - SUNSET_PERIOD / 2,
+                },
+            );
+            let reserve = Reserve::load(&e, &pool_config, &underlying);
+            assert_eq!(reserve.config.c_factor, 0_3750000);
+            // the stored config is not modified
+            assert_eq!(storage::get_res_config(&e, &underlying).c_factor, 0_7500000);
+
+            e.ledger().set_timestamp(10000000 + SUNSET_PERIOD);
+            let reserve = Reserve::load(&e, &pool_config, &underlying);
+            assert_eq!(reserve.config.c_factor, 0);
+        });
+    }
+
     #[test]
     fn test_store() {
         let e = Env::default();
@@ -1,22 +1,34 @@
 use crate::{
-    constants::SCALAR_7,
+    constants::{SCALAR_7, SECONDS_PER_WEEK},
     dependencies::{BackstopClient, PoolBackstopData},
-    storage, PoolError,
+    emissions,
+    events::PoolEvents,
+    storage::{self, QueuedStatusPolicy, StatusPolicy},
+    PoolError,
 };
-use soroban_sdk::{panic_with_error, Env};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+/// The largest backstop product constant a status policy can require. The threshold
+/// calculation raises it to the 5th power and scales it by SCALAR_7, which must fit in an i128.
+const MAX_THRESHOLD_PC: i128 = 1_000_000;
 
 /// Update the pool status based on the backstop module
-#[allow(clippy::zero_prefixed_literal)]
-#[allow(clippy::inconsistent_digit_grouping)]
-pub fn execute_update_pool_status(e: &Env) -> u32 {
+///
+/// If a `keeper` is provided, the keeper is rewarded if the pool status changes
+///
+/// ### Arguments
+/// * `keeper` - The address of the keeper performing the update, if any
+pub fn execute_update_pool_status(e: &Env, keeper: Option<Address>) -> u32 {
     let mut pool_config = storage::get_pool_config(e);
+    let policy = storage::get_status_policy(e);
+    let prev_status = pool_config.status;
 
     // check the pool has met minimum backstop deposits
     let backstop_id = storage::get_backstop(e);
     let backstop_client = BackstopClient::new(e, &backstop_id);
 
     let pool_backstop_data = backstop_client.pool_data(&e.current_contract_address());
-    let threshold = calc_pool_backstop_threshold(&pool_backstop_data);
+    let threshold = calc_pool_backstop_threshold(&pool_backstop_data, policy.threshold_pc);
     let mut met_threshold = true;
     if threshold < SCALAR_7 {
         met_threshold = false;
@@ -35,25 +47,25 @@ pub fn execute_update_pool_status(e: &Env) -> u32 {
         }
         // Admin on-ice
         2 => {
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
-                // Q4W over 75% freezes the pool
+            if pool_backstop_data.q4w_pct >= policy.admin_frozen_q4w {
+                // Q4W over the admin frozen pct freezes the pool
                 pool_config.status = 5;
             }
         }
         // Admin active
         0 => {
-            if !met_threshold || pool_backstop_data.q4w_pct >= 0_5000000 {
-                // Q4w over 50% or being under threshold puts the pool on-ice
+            if !met_threshold || pool_backstop_data.q4w_pct >= policy.admin_on_ice_q4w {
+                // Q4w over the admin on-ice pct or being under threshold puts the pool on-ice
                 pool_config.status = 3;
             }
         }
         // Admin status isn't set
         _ => {
-            if pool_backstop_data.q4w_pct >= 0_6000000 {
-                // Q4w over 60% sets pool to Frozen
+            if pool_backstop_data.q4w_pct >= policy.frozen_q4w {
+                // Q4w over the frozen pct sets pool to Frozen
                 pool_config.status = 5;
-            } else if pool_backstop_data.q4w_pct >= 0_3000000 || !met_threshold {
-                // Q4w over 30% sets pool to On-Ice
+            } else if pool_backstop_data.q4w_pct >= policy.on_ice_q4w || !met_threshold {
+                // Q4w over the on-ice pct sets pool to On-Ice
                 pool_config.status = 3;
             } else {
                 // Backstop is healthy and the pool is set to Active
@@ -62,14 +74,18 @@ pub fn execute_update_pool_status(e: &Env) -> u32 {
         }
     }
     storage::set_pool_config(e, &pool_config);
+    if let Some(keeper) = keeper {
+        if pool_config.status != prev_status {
+            emissions::pay_keeper_reward(e, &keeper);
+        }
+    }
     pool_config.status
 }
 
 /// Admin set the pool status
-#[allow(clippy::zero_prefixed_literal)]
-#[allow(clippy::inconsistent_digit_grouping)]
 pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
     let mut pool_config = storage::get_pool_config(e);
+    let policy = storage::get_status_policy(e);
 
     // check the pool has met minimum backstop deposits
     let backstop_id = storage::get_backstop(e);
@@ -79,9 +95,9 @@ pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
 
     match pool_status {
         0 => {
-            // Threshold must be met and q4w must be under 50% for the admin to set Active
-            if calc_pool_backstop_threshold(&pool_backstop_data) < SCALAR_7
-                || pool_backstop_data.q4w_pct >= 0_5000000
+            // Threshold must be met and q4w must be under the admin on-ice pct for the admin to set Active
+            if calc_pool_backstop_threshold(&pool_backstop_data, policy.threshold_pc) < SCALAR_7
+                || pool_backstop_data.q4w_pct >= policy.admin_on_ice_q4w
             {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
@@ -89,16 +105,16 @@ pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
             pool_config.status = 0;
         }
         2 => {
-            // Q4w must be under 75% for admin to set On-Ice
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
+            // Q4w must be under the admin frozen pct for admin to set On-Ice
+            if pool_backstop_data.q4w_pct >= policy.admin_frozen_q4w {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
             // Admin On-Ice
             pool_config.status = 2;
         }
         3 => {
-            // Q4w must be under 75% for admin to set permissionless On-Ice
-            if pool_backstop_data.q4w_pct >= 0_7500000 {
+            // Q4w must be under the admin frozen pct for admin to set permissionless On-Ice
+            if pool_backstop_data.q4w_pct >= policy.admin_frozen_q4w {
                 panic_with_error!(e, PoolError::StatusNotAllowed);
             }
             // On-Ice
@@ -116,6 +132,84 @@ pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
     storage::set_pool_config(e, &pool_config);
 }
 
+/// Queue a change to the pool's status policy. The change is timelocked for a week unless
+/// the pool is still being set up.
+///
+/// ### Arguments
+/// * `policy` - The new status policy
+///
+/// ### Panics
+/// If a change is already queued or the policy is invalid
+pub fn execute_queue_set_status_policy(e: &Env, policy: &StatusPolicy) {
+    if storage::get_queued_status_policy(e).is_some() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    require_valid_status_policy(e, policy);
+
+    let mut unlock_time = e.ledger().timestamp();
+    // require a timelock if pool status is not setup
+    if storage::get_pool_config(e).status != 6 {
+        unlock_time += SECONDS_PER_WEEK;
+    }
+    storage::set_queued_status_policy(
+        e,
+        &QueuedStatusPolicy {
+            new_policy: policy.clone(),
+            unlock_time,
+        },
+    );
+
+    PoolEvents::queue_set_status_policy(e, policy.clone(), unlock_time);
+}
+
+/// Cancel a queued change to the pool's status policy
+pub fn execute_cancel_queued_set_status_policy(e: &Env) {
+    storage::del_queued_status_policy(e);
+
+    PoolEvents::cancel_set_status_policy(e);
+}
+
+/// Set the queued status policy once its timelock has expired
+///
+/// ### Panics
+/// If no change is queued or the change is still locked
+pub fn execute_set_status_policy(e: &Env) {
+    let queued_policy = match storage::get_queued_status_policy(e) {
+        Some(queued_policy) => queued_policy,
+        None => panic_with_error!(e, PoolError::BadRequest),
+    };
+    if queued_policy.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+
+    storage::del_queued_status_policy(e);
+    storage::set_status_policy(e, &queued_policy.new_policy);
+
+    PoolEvents::set_status_policy(e, queued_policy.new_policy);
+}
+
+/// Require that a status policy is valid
+///
+/// The q4w percentages must be positive and at most 100%, each frozen pct must be greater
+/// than its on-ice pct, and the admin pcts can't be stricter than the permissionless ones.
+///
+/// ### Panics
+/// If the policy is invalid
+fn require_valid_status_policy(e: &Env, policy: &StatusPolicy) {
+    if policy.on_ice_q4w <= 0
+        || policy.frozen_q4w <= policy.on_ice_q4w
+        || policy.frozen_q4w > SCALAR_7
+        || policy.admin_on_ice_q4w < policy.on_ice_q4w
+        || policy.admin_frozen_q4w <= policy.admin_on_ice_q4w
+        || policy.admin_frozen_q4w < policy.frozen_q4w
+        || policy.admin_frozen_q4w > SCALAR_7
+        || policy.threshold_pc <= 0
+        || policy.threshold_pc > MAX_THRESHOLD_PC
+    {
+        panic_with_error!(e, PoolError::InvalidPoolConfigArgs);
+    }
+}
+
 /// Calculate the threshold for the pool's backstop balance
 ///
 /// Returns the threshold as a percentage^5 in SCALAR_7 points such that SCALAR_7 = 100%
@@ -126,13 +220,20 @@ pub fn execute_set_pool_status(e: &Env, pool_status: u32) {
 ///         - 0_0000100 = ~10%
 ///         - 0_0000003 = ~5%
 ///         - 0_0000000 = ~0-4%
-pub fn calc_pool_backstop_threshold(pool_backstop_data: &PoolBackstopData) -> i128 {
+///
+/// ### Arguments
+/// * `pool_backstop_data` - The pool's backstop data
+/// * `threshold_pc` - The backstop product constant required to meet the threshold, in whole tokens
+pub fn calc_pool_backstop_threshold(
+    pool_backstop_data: &PoolBackstopData,
+    threshold_pc: i128,
+) -> i128 {
     // @dev: Calculation for pools product constant of underlying will often overflow i128
     //       so saturating mul is used. This is safe because the threshold is below i128::MAX and the
     //       protocol does not need to differentiate between pools over the threshold product constant.
     //       The calculation is:
-    //        - Threshold % = (bal_blnd^4 * bal_usdc) / PC^5 such that PC is 100k
-    let threshold_pc = 10_000_000_000_000_000_000_000_000i128; // 1e25 (100k^5)
+    //        - Threshold % = (bal_blnd^4 * bal_usdc) / PC^5 such that PC defaults to 100k
+    let threshold_pc = threshold_pc.pow(5);
 
     // floor balances to nearest full unit and calculate saturated pool product constant
     // and scale to SCALAR_7 to get final division result in SCALAR_7 points
@@ -150,12 +251,15 @@ pub fn calc_pool_backstop_threshold(pool_backstop_data: &PoolBackstopData) -> i1
 #[cfg(test)]
 mod tests {
     use crate::{
-        storage::PoolConfig,
+        storage::{KeeperConfig, KeeperData, PoolConfig},
         testutils::{create_backstop, create_comet_lp_pool, create_pool, create_token_contract},
     };
 
     use super::*;
-    use soroban_sdk::{testutils::Address as _, vec, Address};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        vec, Address,
+    };
 
     #[test]
     fn test_set_pool_status_active() {
@@ -553,7 +657,7 @@ fn test_update_pool_status_active() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -600,7 +704,7 @@ fn test_update_pool_status_admin_set_no_changes() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -608,6 +712,77 @@ fn test_update_pool_status_admin_set_no_changes() {
         });
     }
 
+    #[test]
+    fn test_update_pool_status_pays_keeper_on_change() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        let pool_id = create_pool(&e);
+        let oracle_id = Address::generate(&e);
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let keeper = Address::generate(&e);
+
+        let (blnd, blnd_client) = create_token_contract(&e, &bombadil);
+        let (usdc, usdc_client) = create_token_contract(&e, &bombadil);
+        let (lp_token, lp_token_client) = create_comet_lp_pool(&e, &bombadil, &blnd, &usdc);
+        let (backstop_id, backstop_client) = create_backstop(&e, &pool_id, &lp_token, &usdc, &blnd);
+
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &50_000_0000000,
+            &vec![&e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_id, &50_000_0000000);
+
+        // mock backstop having emissions for the pool's keeper budget
+        blnd_client.mint(&backstop_id, &10_0000000);
+        blnd_client.approve(&backstop_id, &pool_id, &10_0000000, &99999);
+
+        let pool_config = PoolConfig {
+            oracle: oracle_id,
+            min_collateral: 0,
+            bstop_rate: 0,
+            status: 3,
+            max_positions: 4,
+        };
+        e.as_contract(&pool_id, || {
+            storage::set_blnd_token(&e, &blnd);
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_keeper_config(
+                &e,
+                &KeeperConfig {
+                    reward: 1_0000000,
+                    max_per_period: 10_0000000,
+                    gulp_threshold: 0,
+                },
+            );
+            storage::set_keeper_data(
+                &e,
+                &KeeperData {
+                    budget: 10_0000000,
+                    period_start: 0,
+                    paid: 0,
+                },
+            );
+
+            let status = execute_update_pool_status(&e, Some(keeper.clone()));
+            assert_eq!(status, 1);
+
+            // no reward when the status does not change
+            let status = execute_update_pool_status(&e, Some(keeper.clone()));
+            assert_eq!(status, 1);
+            assert_eq!(storage::get_keeper_data(&e).budget, 9_0000000);
+        });
+        assert_eq!(blnd_client.balance(&keeper), 1_0000000);
+    }
+
     #[test]
     fn test_update_pool_status_on_ice_tokens() {
         let e = Env::default();
@@ -647,7 +822,7 @@ fn test_update_pool_status_on_ice_tokens() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -695,7 +870,7 @@ fn test_update_pool_status_on_ice_30_q4w() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -743,7 +918,7 @@ fn test_update_pool_status_on_ice_30_q4w_admin_active() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -791,7 +966,7 @@ fn test_update_pool_status_on_ice_50_q4w_admin_active() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -839,7 +1014,7 @@ fn test_update_pool_status_frozen() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -886,7 +1061,7 @@ fn test_update_pool_status_frozen_admin_on_ice() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -934,7 +1109,7 @@ fn test_update_pool_status_frozen_75_q4w() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            let status = execute_update_pool_status(&e);
+            let status = execute_update_pool_status(&e, None);
 
             let new_pool_config = storage::get_pool_config(&e);
             assert_eq!(new_pool_config.status, status);
@@ -982,7 +1157,7 @@ fn test_update_pool_status_admin_frozen() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            execute_update_pool_status(&e);
+            execute_update_pool_status(&e, None);
         });
     }
 
@@ -1026,7 +1201,7 @@ fn test_update_pool_status_setup() {
             storage::set_admin(&e, &bombadil);
             storage::set_pool_config(&e, &pool_config);
 
-            execute_update_pool_status(&e);
+            execute_update_pool_status(&e, None);
         });
     }
 
@@ -1090,9 +1265,10 @@ fn test_calc_pool_backstop_threshold() {
             shares: 50_000_0000000,
             usdc: 6_500_0000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // ~90.5% threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 0_6096289);
     }
 
@@ -1108,9 +1284,10 @@ fn test_calc_pool_backstop_threshold_too_small() {
             shares: 1_000_0000000,
             usdc: 1_000_0000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // ~3.6% threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 0);
     }
 
@@ -1126,9 +1303,10 @@ fn test_calc_pool_backstop_threshold_over() {
             shares: 1_000_0000000,
             usdc: 6_250_0000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // 100% threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 1_0000000);
     }
 
@@ -1144,9 +1322,10 @@ fn test_calc_pool_backstop_threshold_saturates() {
             shares: 999_999_0000000,
             usdc: 10_000_000_0000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // 362x threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 1701411_8346046);
     }
 
@@ -1162,9 +1341,10 @@ fn test_calc_pool_backstop_threshold_10_percent() {
             shares: 1_000_0000000,
             usdc: 625_0000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // 10% threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 0_0000100);
     }
 
@@ -1180,9 +1360,285 @@ fn test_calc_pool_backstop_threshold_5pct() {
             shares: 999_999_0000000,
             usdc: 312_5000000,
             token_spot_price: 0_5000000,
+            draw_capacity: 0,
         }; // 5% threshold
 
-        let result = calc_pool_backstop_threshold(&pool_backstop_data);
+        let result = calc_pool_backstop_threshold(&pool_backstop_data, 100_000);
         assert_eq!(result, 0_0000003);
     }
+
+    fn default_policy() -> StatusPolicy {
+        StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_7500000,
+            threshold_pc: 100_000,
+        }
+    }
+
+    fn small_pool_policy() -> StatusPolicy {
+        StatusPolicy {
+            on_ice_q4w: 0_1000000,
+            frozen_q4w: 0_2000000,
+            admin_on_ice_q4w: 0_1500000,
+            admin_frozen_q4w: 0_2500000,
+            threshold_pc: 10_000,
+        }
+    }
+
+    /// Create a pool with `status` whose backstop holds `lp_deposit` LP tokens, of which
+    /// `lp_q4w` are queued for withdrawal
+    fn setup_status_test(e: &Env, lp_deposit: i128, lp_q4w: i128, status: u32) -> Address {
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+        let pool_id = create_pool(e);
+
+        let bombadil = Address::generate(e);
+        let samwise = Address::generate(e);
+
+        let (blnd, blnd_client) = create_token_contract(e, &bombadil);
+        let (usdc, usdc_client) = create_token_contract(e, &bombadil);
+        let (lp_token, lp_token_client) = create_comet_lp_pool(e, &bombadil, &blnd, &usdc);
+        let (_, backstop_client) = create_backstop(e, &pool_id, &lp_token, &usdc, &blnd);
+
+        // mint lp tokens
+        blnd_client.mint(&samwise, &500_001_0000000);
+        blnd_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        usdc_client.mint(&samwise, &12_501_0000000);
+        usdc_client.approve(&samwise, &lp_token, &i128::MAX, &99999);
+        lp_token_client.join_pool(
+            &lp_deposit,
+            &vec![e, 500_001_0000000, 12_501_0000000],
+            &samwise,
+        );
+        backstop_client.deposit(&samwise, &pool_id, &lp_deposit);
+        if lp_q4w > 0 {
+            backstop_client.queue_withdrawal(&samwise, &pool_id, &lp_q4w);
+        }
+
+        e.as_contract(&pool_id, || {
+            storage::set_admin(e, &bombadil);
+            storage::set_pool_config(
+                e,
+                &PoolConfig {
+                    oracle: Address::generate(e),
+                    min_collateral: 0,
+                    bstop_rate: 0,
+                    status,
+                    max_positions: 4,
+                },
+            );
+        });
+        pool_id
+    }
+
+    #[test]
+    fn test_update_pool_status_with_policy() {
+        // (policy, lp deposit, lp q4w, status, expected status)
+        let cases = [
+            // default policy
+            (default_policy(), 50_000_0000000, 0, 3, 1),
+            (default_policy(), 50_000_0000000, 14_999_0000000, 1, 1),
+            (default_policy(), 50_000_0000000, 15_000_0000000, 1, 3),
+            (default_policy(), 50_000_0000000, 30_000_0000000, 1, 5),
+            (default_policy(), 50_000_0000000, 30_000_0000000, 5, 5),
+            (default_policy(), 5_000_0000000, 0, 1, 3),
+            (default_policy(), 50_000_0000000, 24_999_0000000, 0, 0),
+            (default_policy(), 50_000_0000000, 25_000_0000000, 0, 3),
+            (default_policy(), 5_000_0000000, 0, 0, 3),
+            (default_policy(), 50_000_0000000, 37_499_0000000, 2, 2),
+            (default_policy(), 50_000_0000000, 37_500_0000000, 2, 5),
+            // small pool policy
+            (small_pool_policy(), 5_000_0000000, 0, 3, 1),
+            (small_pool_policy(), 5_000_0000000, 499_0000000, 1, 1),
+            (small_pool_policy(), 5_000_0000000, 500_0000000, 1, 3),
+            (small_pool_policy(), 5_000_0000000, 1_000_0000000, 1, 5),
+            (small_pool_policy(), 5_000_0000000, 0, 0, 0),
+            (small_pool_policy(), 5_000_0000000, 750_0000000, 0, 3),
+            (small_pool_policy(), 5_000_0000000, 1_249_0000000, 2, 2),
+            (small_pool_policy(), 5_000_0000000, 1_250_0000000, 2, 5),
+        ];
+        for (policy, lp_deposit, lp_q4w, status, expected_status) in cases {
+            let e = Env::default();
+            let pool_id = setup_status_test(&e, lp_deposit, lp_q4w, status);
+            e.as_contract(&pool_id, || {
+                storage::set_status_policy(&e, &policy);
+
+                let new_status = execute_update_pool_status(&e, None);
+                assert_eq!(new_status, expected_status);
+                assert_eq!(storage::get_pool_config(&e).status, expected_status);
+            });
+        }
+    }
+
+    #[test]
+    fn test_set_pool_status_with_policy() {
+        // (policy, lp deposit, lp q4w, requested status)
+        let cases = [
+            (default_policy(), 50_000_0000000, 24_999_0000000, 0),
+            (default_policy(), 50_000_0000000, 37_499_0000000, 2),
+            (default_policy(), 50_000_0000000, 37_499_0000000, 3),
+            (default_policy(), 50_000_0000000, 50_000_0000000, 4),
+            (small_pool_policy(), 5_000_0000000, 749_0000000, 0),
+            (small_pool_policy(), 5_000_0000000, 1_249_0000000, 2),
+            (small_pool_policy(), 5_000_0000000, 1_249_0000000, 3),
+            (small_pool_policy(), 5_000_0000000, 5_000_0000000, 4),
+        ];
+        for (policy, lp_deposit, lp_q4w, status) in cases {
+            let e = Env::default();
+            let pool_id = setup_status_test(&e, lp_deposit, lp_q4w, 1);
+            e.as_contract(&pool_id, || {
+                storage::set_status_policy(&e, &policy);
+
+                execute_set_pool_status(&e, status);
+                assert_eq!(storage::get_pool_config(&e).status, status);
+            });
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1204)")]
+    fn test_set_pool_status_active_blocks_with_policy_q4w() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 5_000_0000000, 750_0000000, 1);
+        e.as_contract(&pool_id, || {
+            storage::set_status_policy(&e, &small_pool_policy());
+
+            execute_set_pool_status(&e, 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1204)")]
+    fn test_set_pool_status_active_blocks_with_policy_threshold() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            let mut policy = default_policy();
+            policy.threshold_pc = 1_000_000;
+            storage::set_status_policy(&e, &policy);
+
+            execute_set_pool_status(&e, 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1204)")]
+    fn test_set_pool_status_on_ice_blocks_with_policy_q4w() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 5_000_0000000, 1_250_0000000, 1);
+        e.as_contract(&pool_id, || {
+            storage::set_status_policy(&e, &small_pool_policy());
+
+            execute_set_pool_status(&e, 2);
+        });
+    }
+
+    #[test]
+    fn test_status_policy_timelock() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 5_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            let policy = small_pool_policy();
+            execute_queue_set_status_policy(&e, &policy);
+            let queued_policy = storage::get_queued_status_policy(&e).unwrap();
+            assert_eq!(
+                queued_policy.unlock_time,
+                e.ledger().timestamp() + SECONDS_PER_WEEK
+            );
+            assert_eq!(storage::get_status_policy(&e).threshold_pc, 100_000);
+
+            e.ledger().set_timestamp(queued_policy.unlock_time);
+            execute_set_status_policy(&e);
+            assert!(storage::get_queued_status_policy(&e).is_none());
+            let new_policy = storage::get_status_policy(&e);
+            assert_eq!(new_policy.on_ice_q4w, policy.on_ice_q4w);
+            assert_eq!(new_policy.frozen_q4w, policy.frozen_q4w);
+            assert_eq!(new_policy.admin_on_ice_q4w, policy.admin_on_ice_q4w);
+            assert_eq!(new_policy.admin_frozen_q4w, policy.admin_frozen_q4w);
+            assert_eq!(new_policy.threshold_pc, policy.threshold_pc);
+
+            // the smaller backstop now meets the threshold
+            assert_eq!(execute_update_pool_status(&e, None), 1);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_set_status_policy_locked() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            execute_queue_set_status_policy(&e, &small_pool_policy());
+            e.ledger()
+                .set_timestamp(e.ledger().timestamp() + SECONDS_PER_WEEK - 1);
+
+            execute_set_status_policy(&e);
+        });
+    }
+
+    #[test]
+    fn test_status_policy_no_timelock_during_setup() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 6);
+        e.as_contract(&pool_id, || {
+            execute_queue_set_status_policy(&e, &small_pool_policy());
+            execute_set_status_policy(&e);
+            assert_eq!(storage::get_status_policy(&e).threshold_pc, 10_000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_cancel_queued_status_policy() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 6);
+        e.as_contract(&pool_id, || {
+            execute_queue_set_status_policy(&e, &small_pool_policy());
+            execute_cancel_queued_set_status_policy(&e);
+            assert!(storage::get_queued_status_policy(&e).is_none());
+
+            execute_set_status_policy(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_frozen_under_on_ice() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            let mut policy = default_policy();
+            policy.frozen_q4w = policy.on_ice_q4w;
+
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_admin_stricter() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            let mut policy = default_policy();
+            policy.admin_frozen_q4w = 0_5500000;
+
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1201)")]
+    fn test_queue_set_status_policy_threshold_overflows() {
+        let e = Env::default();
+        let pool_id = setup_status_test(&e, 50_000_0000000, 0, 1);
+        e.as_contract(&pool_id, || {
+            let mut policy = default_policy();
+            policy.threshold_pc = MAX_THRESHOLD_PC + 1;
+
+            execute_queue_set_status_policy(&e, &policy);
+        });
+    }
 }
@@ -1,8 +1,7 @@
-// use moderc3156::FlashLoanClient; // Commented to avoid dependency issues
 use sep_41_token::TokenClient;
 use soroban_sdk::{panic_with_error, Address, Env, Map, Vec};
 
-use crate::{events::PoolEvents, storage, AuctionType, PoolError};
+use crate::{dependencies::FlashLoanClient, events::PoolEvents, storage, AuctionType, PoolError};
 
 use super::{
     actions::{build_actions_from_request, Actions, Request},
@@ -128,13 +127,12 @@ pub fn execute_submit_with_flash_loan(
         &flash_loan.amount,
     );
     // calls the receiver contract with "from" as the caller
-    // FlashLoanClient::new(&e, &flash_loan.contract).exec_op(
-    //     &from,
-    //     &flash_loan.asset,
-    //     &flash_loan.amount,
-    //     &0,
-    // );
-    // TODO: Re-enable flash loan functionality when moderc3156 dependency is resolved
+    FlashLoanClient::new(e, &flash_loan.contract).exec_op(
+        from,
+        &flash_loan.asset,
+        &flash_loan.amount,
+        &0,
+    );
 
     // note: at this point, the pool has sum_by_asset(actions.flash_borrow.1) for each involved asset, but the user also has
     // increased liabilities. These will have to be either fully repaid by now in the requests following the flash borrow
@@ -157,7 +155,7 @@ pub fn execute_submit_with_flash_loan(
 /// * from_state - The user state for "from"
 /// * prev_positions_count - The initial number of positions for "from"
 /// * check_health - A bool indicating if the health factor should be checked
-fn validate_submit(
+pub(super) fn validate_submit(
     e: &Env,
     pool: &mut Pool,
     from_state: &User,
@@ -193,10 +191,32 @@ fn validate_submit(
         } else if position_data.collateral_base < pool.config.min_collateral {
             panic_with_error!(e, PoolError::MinCollateralNotMet);
         }
+
+        // verify the user's total liabilities are within the pool's loan limit
+        let loan_limits = storage::get_loan_limits(e);
+        if loan_limits.max_liability > 0 && position_data.liability_raw > loan_limits.max_liability
+        {
+            panic_with_error!(e, PoolError::MaxLiabilityExceeded);
+        }
+    }
+}
+
+/// Pull any claimed emissions from the backstop's BLND allowance into the pool, so they can be
+/// transferred with the rest of the pool's tokens
+fn pull_claimed_emissions(e: &Env, actions: &Actions) {
+    if actions.claimed_emissions > 0 {
+        TokenClient::new(e, &storage::get_blnd_token(e)).transfer_from(
+            &e.current_contract_address(),
+            &storage::get_backstop(e),
+            &e.current_contract_address(),
+            &actions.claimed_emissions,
+        );
     }
 }
 
 fn handle_transfer_with_allowance(e: &Env, actions: &Actions, spender: &Address, to: &Address) {
+    pull_claimed_emissions(e, actions);
+
     // map of token -> amount
     // amount can be negative:
     // pool owes when amount > 0
@@ -234,6 +254,8 @@ fn handle_transfer_with_allowance(e: &Env, actions: &Actions, spender: &Address,
 }
 
 fn handle_transfers(e: &Env, actions: &Actions, spender: &Address, to: &Address) {
+    pull_claimed_emissions(e, actions);
+
     // transfer tokens from sender to pool
     for (address, amount) in actions.spender_transfer.iter() {
         TokenClient::new(e, &address).transfer(spender, &e.current_contract_address(), &amount);
@@ -248,7 +270,7 @@ fn handle_transfers(e: &Env, actions: &Actions, spender: &Address, to: &Address)
 #[cfg(test)]
 mod tests {
     use crate::{
-        storage::{self, PoolConfig},
+        storage::{self, PoolConfig, PoolLoanLimits},
         testutils, AuctionData, RequestType,
     };
 
@@ -1528,6 +1550,261 @@ fn test_submit_under_min_collateral_fails() {
         });
     }
 
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1225)")]
+    fn test_submit_under_min_borrow_fails() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, underlying_0_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        underlying_0_client.mint(&frodo, &16_0000000);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            e.mock_all_auths_allowing_non_root_auth();
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_loan_limits(
+                &e,
+                &PoolLoanLimits {
+                    min_borrow: 10_0000000,
+                    max_liability: 0,
+                },
+            );
+
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::SupplyCollateral as u32,
+                    address: underlying_0,
+                    amount: 15_0000000,
+                },
+                Request {
+                    request_type: RequestType::Borrow as u32,
+                    address: underlying_1,
+                    amount: 1_5000000,
+                },
+            ];
+            execute_submit(&e, &samwise, &frodo, &merry, requests, false);
+        });
+    }
+
+    #[test]
+    fn test_submit_min_borrow_only_checks_borrow_requests() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, underlying_1_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        underlying_1_client.mint(&samwise, &1_5000000);
+        underlying_1_client.mint(&pool, &10_0000000);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        let user_positions = Positions {
+            liabilities: map![&e, (1, 2_0000000)],
+            collateral: map![&e, (0, 100_0000000)],
+            supply: map![&e],
+        };
+        e.as_contract(&pool, || {
+            e.mock_all_auths_allowing_non_root_auth();
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &user_positions);
+            storage::set_loan_limits(
+                &e,
+                &PoolLoanLimits {
+                    min_borrow: 10_0000000,
+                    max_liability: 0,
+                },
+            );
+
+            // repaying below the minimum and borrowing exactly the minimum are both allowed
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::Repay as u32,
+                    address: underlying_1.clone(),
+                    amount: 1_5000000,
+                },
+                Request {
+                    request_type: RequestType::Borrow as u32,
+                    address: underlying_1.clone(),
+                    amount: 2_0000000,
+                },
+            ];
+            let positions = execute_submit(&e, &samwise, &samwise, &samwise, requests, false);
+            assert!(positions.liabilities.get(1).unwrap() > 2_0000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1226)")]
+    fn test_submit_over_max_liability_fails() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.mock_all_auths_allowing_non_root_auth();
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying_0, underlying_0_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_0, &reserve_config, &reserve_data);
+
+        let (underlying_1, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying_1, &reserve_config, &reserve_data);
+
+        underlying_0_client.mint(&frodo, &16_0000000);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![
+                &e,
+                Asset::Stellar(underlying_0.clone()),
+                Asset::Stellar(underlying_1.clone()),
+            ],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000, 5_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            e.mock_all_auths_allowing_non_root_auth();
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_loan_limits(
+                &e,
+                &PoolLoanLimits {
+                    min_borrow: 0,
+                    max_liability: 5_0000000,
+                },
+            );
+
+            let requests = vec![
+                &e,
+                Request {
+                    request_type: RequestType::SupplyCollateral as u32,
+                    address: underlying_0,
+                    amount: 15_0000000,
+                },
+                Request {
+                    request_type: RequestType::Borrow as u32,
+                    address: underlying_1,
+                    amount: 1_5000000,
+                },
+            ];
+            execute_submit(&e, &samwise, &frodo, &merry, requests, false);
+        });
+    }
+
     #[test]
     fn test_submit_withdraw_over_max_util() {
         let e = Env::default();
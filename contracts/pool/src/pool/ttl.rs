@@ -0,0 +1,146 @@
+use soroban_sdk::{vec, Address, Env, Vec};
+
+use crate::storage::{self, PoolDataKey};
+
+/// Load the ledger keys of every entry stored for a user, including their positions, emissions,
+/// external rewards and auctions. These are the entries `execute_bump_users` extends.
+///
+/// Auction keys are stored in temporary storage, all other keys in persistent storage.
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn load_user_ledger_keys(e: &Env, user: &Address) -> Vec<PoolDataKey> {
+    let positions = storage::get_user_positions(e, user);
+    let mut res_token_ids: Vec<u32> = vec![e];
+    for index in positions.liabilities.keys().iter() {
+        res_token_ids.push_back(index * 2);
+    }
+    for index in positions.collateral.keys().iter() {
+        res_token_ids.push_back(index * 2 + 1);
+    }
+    for index in positions.supply.keys().iter() {
+        let b_token_id = index * 2 + 1;
+        if !res_token_ids.contains(b_token_id) {
+            res_token_ids.push_back(b_token_id);
+        }
+    }
+    storage::get_user_storage_keys(e, user, &res_token_ids)
+}
+
+/// Extend the TTL of every entry stored for each user. Anyone can call this to keep dormant
+/// users' liability bearing entries from being archived.
+///
+/// Returns the number of entries bumped
+///
+/// ### Arguments
+/// * `users` - The addresses of the users
+pub fn execute_bump_users(e: &Env, users: &Vec<Address>) -> u32 {
+    let mut bumped: u32 = 0;
+    for user in users.iter() {
+        for key in load_user_ledger_keys(e, &user).iter() {
+            storage::bump_user_storage_key(e, &key);
+            bumped += 1;
+        }
+    }
+    bumped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pool::Positions, storage::UserEmissionData, testutils, AuctionData};
+    use soroban_sdk::{
+        map,
+        testutils::{storage::Persistent, storage::Temporary, Address as _, Ledger, LedgerInfo},
+    };
+
+    const ONE_DAY_LEDGERS: u32 = 17280;
+
+    #[test]
+    fn test_bump_users() {
+        let e = Env::default();
+        e.cost_estimate().budget().reset_unlimited();
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 50 * ONE_DAY_LEDGERS,
+            max_entry_ttl: 3110400,
+        });
+
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let reward_token = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let emis_data = UserEmissionData {
+            index: 1,
+            accrued: 0,
+        };
+        e.as_contract(&pool, || {
+            storage::set_user_positions(
+                &e,
+                &samwise,
+                &Positions {
+                    liabilities: map![&e, (1, 1_0000000)],
+                    collateral: map![&e, (0, 20_0000000)],
+                    supply: map![&e, (0, 5_0000000)],
+                },
+            );
+            storage::set_borrower_index(&e, &samwise, 0);
+            storage::set_user_emissions(&e, &samwise, &1, &emis_data);
+            storage::set_user_emissions(&e, &samwise, &2, &emis_data);
+            storage::set_reward_tokens(&e, &1, &vec![&e, reward_token.clone()]);
+            storage::set_user_reward(&e, &samwise, &1, &reward_token, &emis_data);
+            storage::set_auction(
+                &e,
+                &0,
+                &samwise,
+                &AuctionData {
+                    bid: map![&e],
+                    lot: map![&e],
+                    block: 1234,
+                },
+            );
+
+            let keys = load_user_ledger_keys(&e, &samwise);
+            assert_eq!(keys.len(), 6);
+            assert_eq!(load_user_ledger_keys(&e, &frodo).len(), 0);
+        });
+
+        // 30 days pass without samwise interacting with the pool
+        e.ledger().set_sequence_number(1234 + 30 * ONE_DAY_LEDGERS);
+
+        e.as_contract(&pool, || {
+            let pos_key = PoolDataKey::UserPos(samwise.clone());
+            let auction_key =
+                storage::get_user_storage_keys(&e, &samwise, &vec![&e]).last_unchecked();
+            assert_eq!(
+                e.storage().persistent().get_ttl(&pos_key),
+                90 * ONE_DAY_LEDGERS
+            );
+            assert_eq!(
+                e.storage().temporary().get_ttl(&auction_key),
+                16 * ONE_DAY_LEDGERS
+            );
+
+            let bumped = execute_bump_users(&e, &vec![&e, samwise.clone(), frodo.clone()]);
+            assert_eq!(bumped, 6);
+
+            for key in load_user_ledger_keys(&e, &samwise).iter() {
+                match key {
+                    PoolDataKey::Auction(_) => {
+                        assert_eq!(e.storage().temporary().get_ttl(&key), 46 * ONE_DAY_LEDGERS)
+                    }
+                    _ => assert_eq!(
+                        e.storage().persistent().get_ttl(&key),
+                        120 * ONE_DAY_LEDGERS
+                    ),
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,191 @@
+use soroban_sdk::{panic_with_error, BytesN, Env};
+
+use crate::{
+    constants::{SECONDS_PER_WEEK, STORAGE_VERSION},
+    errors::PoolError,
+    events::PoolEvents,
+    storage::{self, QueuedUpgrade},
+};
+
+/// Queue an upgrade of the pool's wasm. The upgrade is timelocked for the same week as
+/// reserve changes so users can exit before it is applied.
+///
+/// ### Arguments
+/// * `wasm_hash` - The hash of the uploaded wasm to upgrade to
+///
+/// ### Panics
+/// If an upgrade is already queued
+pub fn execute_queue_upgrade(e: &Env, wasm_hash: &BytesN<32>) {
+    if storage::get_queued_upgrade(e).is_some() {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    let unlock_time = e.ledger().timestamp() + SECONDS_PER_WEEK;
+    storage::set_queued_upgrade(
+        e,
+        &QueuedUpgrade {
+            wasm_hash: wasm_hash.clone(),
+            unlock_time,
+        },
+    );
+
+    PoolEvents::queue_upgrade(e, wasm_hash.clone(), unlock_time);
+}
+
+/// Cancel the queued upgrade of the pool's wasm
+///
+/// ### Panics
+/// If no upgrade is queued
+pub fn execute_cancel_upgrade(e: &Env) {
+    let queued_upgrade = match storage::get_queued_upgrade(e) {
+        Some(queued_upgrade) => queued_upgrade,
+        None => panic_with_error!(e, PoolError::BadRequest),
+    };
+    storage::del_queued_upgrade(e);
+
+    PoolEvents::cancel_upgrade(e, queued_upgrade.wasm_hash);
+}
+
+/// Upgrade the pool's wasm to the queued wasm once its timelock has expired. The new wasm
+/// takes effect after the current invocation, so `execute_migrate_storage` must be called
+/// separately once the upgrade has landed.
+///
+/// Returns the hash of the new wasm
+///
+/// ### Panics
+/// If no upgrade is queued or the upgrade is still locked
+pub fn execute_upgrade(e: &Env) -> BytesN<32> {
+    let queued_upgrade = match storage::get_queued_upgrade(e) {
+        Some(queued_upgrade) => queued_upgrade,
+        None => panic_with_error!(e, PoolError::BadRequest),
+    };
+    if queued_upgrade.unlock_time > e.ledger().timestamp() {
+        panic_with_error!(e, PoolError::InitNotUnlocked);
+    }
+
+    storage::del_queued_upgrade(e);
+    e.deployer()
+        .update_current_contract_wasm(queued_upgrade.wasm_hash.clone());
+
+    PoolEvents::upgrade(e, queued_upgrade.wasm_hash.clone());
+    queued_upgrade.wasm_hash
+}
+
+/// Migrate the pool's storage from the stored version to `STORAGE_VERSION`. Run once after
+/// an upgrade that bumps `STORAGE_VERSION`.
+///
+/// Returns the new storage version
+///
+/// ### Panics
+/// If the storage is already at `STORAGE_VERSION`
+pub fn execute_migrate_storage(e: &Env) -> u32 {
+    let version = storage::get_storage_version(e);
+    if version >= STORAGE_VERSION {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+
+    // @dev: storage migrations for each version after `version` are applied here, in order
+
+    storage::set_storage_version(e, STORAGE_VERSION);
+
+    PoolEvents::migrate_storage(e, version, STORAGE_VERSION);
+    STORAGE_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutils;
+    use sep_41_token::testutils::MockTokenWASM;
+    use soroban_sdk::testutils::{BytesN as _, Ledger};
+
+    #[test]
+    fn test_queue_and_upgrade() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+        let wasm_hash = e.deployer().upload_contract_wasm(MockTokenWASM);
+
+        e.as_contract(&pool, || {
+            execute_queue_upgrade(&e, &wasm_hash);
+            let queued_upgrade = storage::get_queued_upgrade(&e).unwrap();
+            assert_eq!(queued_upgrade.wasm_hash, wasm_hash);
+            assert_eq!(
+                queued_upgrade.unlock_time,
+                e.ledger().timestamp() + SECONDS_PER_WEEK
+            );
+
+            e.ledger().set_timestamp(queued_upgrade.unlock_time);
+            assert_eq!(execute_upgrade(&e), wasm_hash);
+            assert!(storage::get_queued_upgrade(&e).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1203)")]
+    fn test_upgrade_locked() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+        let wasm_hash = e.deployer().upload_contract_wasm(MockTokenWASM);
+
+        e.as_contract(&pool, || {
+            execute_queue_upgrade(&e, &wasm_hash);
+            e.ledger()
+                .set_timestamp(e.ledger().timestamp() + SECONDS_PER_WEEK - 1);
+
+            execute_upgrade(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_queue_upgrade_already_queued() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_queue_upgrade(&e, &BytesN::random(&e));
+            execute_queue_upgrade(&e, &BytesN::random(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_cancel_upgrade() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_queue_upgrade(&e, &BytesN::random(&e));
+            execute_cancel_upgrade(&e);
+            assert!(storage::get_queued_upgrade(&e).is_none());
+
+            e.ledger()
+                .set_timestamp(e.ledger().timestamp() + SECONDS_PER_WEEK);
+            execute_upgrade(&e);
+        });
+    }
+
+    #[test]
+    fn test_migrate_storage() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            storage::set_storage_version(&e, STORAGE_VERSION - 1);
+
+            assert_eq!(execute_migrate_storage(&e), STORAGE_VERSION);
+            assert_eq!(storage::get_storage_version(&e), STORAGE_VERSION);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_migrate_storage_already_migrated() {
+        let e = Env::default();
+        let pool = testutils::create_pool(&e);
+
+        e.as_contract(&pool, || {
+            execute_migrate_storage(&e);
+        });
+    }
+}
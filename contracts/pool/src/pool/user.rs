@@ -1,9 +1,12 @@
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, Vec};
 
 use crate::{constants::SCALAR_12, emissions, storage, validator::require_nonnegative, PoolError};
 
-use super::{Pool, Reserve};
+use super::{
+    borrowers::{add_borrower, remove_borrower},
+    Pool, Reserve,
+};
 
 /// A user / contracts position's with the pool, stored in the Reserve's decimals
 #[derive(Clone)]
@@ -31,6 +34,81 @@ pub fn env_default(e: &Env) -> Self {
     pub fn effective_count(&self) -> u32 {
         self.liabilities.len() + self.collateral.len()
     }
+
+    /// Get a bitmap of the reserve indexes the user holds effective (impacts health factor)
+    /// positions in.
+    pub fn effective_bitmap(&self) -> u64 {
+        let mut bitmap: u64 = 0;
+        for index in self.liabilities.keys().iter() {
+            bitmap |= 1 << index;
+        }
+        for index in self.collateral.keys().iter() {
+            bitmap |= 1 << index;
+        }
+        bitmap
+    }
+
+    /// Pack the positions into their compact storage encoding
+    pub fn pack(&self, e: &Env) -> PackedPositions {
+        let mut balances = Vec::new(e);
+        let liabilities = pack_balances(&self.liabilities, &mut balances);
+        let collateral = pack_balances(&self.collateral, &mut balances);
+        let supply = pack_balances(&self.supply, &mut balances);
+        PackedPositions {
+            liabilities,
+            collateral,
+            supply,
+            balances,
+        }
+    }
+}
+
+/// A user / contracts position's with the pool, as stored on the ledger
+///
+/// Each bitmap has a bit set for every reserve index the user holds a balance in. The balances
+/// are packed in ascending reserve index order, first for the liabilities, then the collateral,
+/// then the supply.
+#[derive(Clone)]
+#[contracttype]
+pub struct PackedPositions {
+    pub liabilities: u64, // Bitmap of Reserve Indexes with a liability share balance
+    pub collateral: u64,  // Bitmap of Reserve Indexes with a collateral supply share balance
+    pub supply: u64,      // Bitmap of Reserve Indexes with a non-collateral supply share balance
+    pub balances: Vec<i128>, // The packed share balances
+}
+
+impl PackedPositions {
+    /// Unpack the positions from their compact storage encoding
+    pub fn unpack(&self, e: &Env) -> Positions {
+        let mut offset: u32 = 0;
+        Positions {
+            liabilities: unpack_balances(e, self.liabilities, &self.balances, &mut offset),
+            collateral: unpack_balances(e, self.collateral, &self.balances, &mut offset),
+            supply: unpack_balances(e, self.supply, &self.balances, &mut offset),
+        }
+    }
+}
+
+/// Push the balances of a position map onto `balances` and return the bitmap of its indexes
+fn pack_balances(positions: &Map<u32, i128>, balances: &mut Vec<i128>) -> u64 {
+    let mut bitmap: u64 = 0;
+    for (index, balance) in positions.iter() {
+        bitmap |= 1 << index;
+        balances.push_back(balance);
+    }
+    bitmap
+}
+
+/// Read the balances for the set bits of `bitmap` from `balances`, starting at `offset`
+fn unpack_balances(e: &Env, bitmap: u64, balances: &Vec<i128>, offset: &mut u32) -> Map<u32, i128> {
+    let mut positions = Map::new(e);
+    let mut remaining = bitmap;
+    while remaining != 0 {
+        positions.set(remaining.trailing_zeros(), balances.get_unchecked(*offset));
+        *offset += 1;
+        remaining &= remaining - 1;
+    }
+    positions
 }
 
 /// A user / contracts position's with the pool
@@ -49,9 +127,18 @@ pub fn load(e: &Env, address: &Address) -> Self {
         }
     }
 
-    /// Store the user's positions to the ledger
+    /// Store the user's positions to the ledger, and update the index of users with
+    /// liabilities if the user took on or repaid all their liabilities
     pub fn store(&self, e: &Env) {
         storage::set_user_positions(e, &self.address, &self.positions);
+        let has_liabilities = self.has_liabilities();
+        if has_liabilities != storage::has_borrower_index(e, &self.address) {
+            if has_liabilities {
+                add_borrower(e, &self.address);
+            } else {
+                remove_borrower(e, &self.address);
+            }
+        }
     }
 
     /// Check if the user has liabilities
@@ -108,7 +195,7 @@ pub fn default_liabilities(&mut self, e: &Env, reserve: &mut Reserve, amount: i1
         // determine amount of funds in underlying that have defaulted
         // and deduct them from the b_rate
         let default_amount = reserve.to_asset_from_d_token(e, amount);
-        let b_rate_loss = default_amount.fixed_div_ceil(&e, &reserve.data.b_supply, &SCALAR_12);
+        let b_rate_loss = default_amount.fixed_div_ceil(e, &reserve.data.b_supply, &SCALAR_12);
         reserve.data.b_rate -= b_rate_loss;
         if reserve.data.b_rate < 0 {
             reserve.data.b_rate = 0;
@@ -264,7 +351,7 @@ fn update_b_emissions(&self, e: &Env, reserve: &Reserve, amount: i128) {
         emissions::update_emissions(
             e,
             reserve.config.index * 2 + 1,
-            reserve.data.b_supply,
+            reserve.emission_b_supply(e),
             reserve.scalar,
             &self.address,
             amount,
@@ -280,6 +367,7 @@ mod tests {
     use soroban_sdk::{
         map,
         testutils::{Address as _, Ledger, LedgerInfo},
+        xdr::ToXdr,
     };
 
     #[test]
@@ -328,12 +416,12 @@ fn test_liabilities() {
         };
         e.as_contract(&pool, || {
             assert_eq!(user.get_liabilities(0), 0);
-            assert_eq!(user.has_liabilities(), false);
+            assert!(!user.has_liabilities());
 
             user.add_liabilities(&e, &mut reserve_0, 123);
             assert_eq!(user.get_liabilities(0), 123);
             assert_eq!(reserve_0.data.d_supply, starting_d_supply_0 + 123);
-            assert_eq!(user.has_liabilities(), true);
+            assert!(user.has_liabilities());
 
             user.add_liabilities(&e, &mut reserve_1, 456);
             assert_eq!(user.get_liabilities(0), 123);
@@ -353,7 +441,7 @@ fn test_liabilities() {
             assert_eq!(user.get_liabilities(0), 0);
             assert_eq!(user.positions.liabilities.len(), 0);
             assert_eq!(reserve_0.data.d_supply, starting_d_supply_0);
-            assert_eq!(user.has_liabilities(), false);
+            assert!(!user.has_liabilities());
         });
     }
 
@@ -420,7 +508,7 @@ fn test_add_liabilities_accrues_emissions() {
         };
 
         e.as_contract(&pool, || {
-            let res_0_d_token_index = reserve_0.config.index * 2 + 0;
+            let res_0_d_token_index = reserve_0.config.index * 2;
             storage::set_res_emis_data(&e, &res_0_d_token_index, &emis_res_data);
             storage::set_user_emissions(&e, &samwise, &res_0_d_token_index, &emis_user_data);
 
@@ -439,12 +527,11 @@ fn test_add_liabilities_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -513,7 +600,7 @@ fn test_remove_liabilities_accrues_emissions() {
             },
         };
         e.as_contract(&pool, || {
-            let res_0_d_token_index = reserve_0.config.index * 2 + 0;
+            let res_0_d_token_index = reserve_0.config.index * 2;
             storage::set_res_emis_data(&e, &res_0_d_token_index, &emis_res_data);
             storage::set_user_emissions(&e, &samwise, &res_0_d_token_index, &emis_user_data);
 
@@ -532,12 +619,11 @@ fn test_remove_liabilities_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -696,35 +782,35 @@ fn test_collateral() {
         };
         e.as_contract(&pool, || {
             assert_eq!(user.get_collateral(0), 0);
-            assert_eq!(user.has_collateral(), false);
+            assert!(!user.has_collateral());
 
             user.add_collateral(&e, &mut reserve_0, 123);
             assert_eq!(user.get_collateral(0), 123);
             assert_eq!(reserve_0.data.b_supply, starting_b_supply_0 + 123);
-            assert_eq!(user.has_collateral(), true);
+            assert!(user.has_collateral());
 
             user.add_collateral(&e, &mut reserve_1, 456);
             assert_eq!(user.get_collateral(0), 123);
             assert_eq!(user.get_collateral(1), 456);
             assert_eq!(reserve_1.data.b_supply, starting_b_supply_1 + 456);
-            assert_eq!(user.has_collateral(), true);
+            assert!(user.has_collateral());
 
             user.remove_collateral(&e, &mut reserve_1, 100);
             assert_eq!(user.get_collateral(1), 356);
             assert_eq!(reserve_1.data.b_supply, starting_b_supply_1 + 356);
-            assert_eq!(user.has_collateral(), true);
+            assert!(user.has_collateral());
 
             user.remove_collateral(&e, &mut reserve_1, 356);
             assert_eq!(user.get_collateral(1), 0);
             assert_eq!(user.positions.collateral.len(), 1);
             assert_eq!(reserve_1.data.b_supply, starting_b_supply_1);
-            assert_eq!(user.has_collateral(), true);
+            assert!(user.has_collateral());
 
             user.remove_collateral(&e, &mut reserve_0, 123);
             assert_eq!(user.get_collateral(0), 0);
             assert_eq!(user.positions.collateral.len(), 0);
             assert_eq!(reserve_0.data.b_supply, starting_b_supply_0);
-            assert_eq!(user.has_collateral(), false);
+            assert!(!user.has_collateral());
         });
     }
 
@@ -809,12 +895,11 @@ fn test_add_collateral_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -903,12 +988,11 @@ fn test_remove_collateral_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -1057,12 +1141,11 @@ fn test_add_supply_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -1151,12 +1234,11 @@ fn test_remove_supply_accrues_emissions() {
             assert_eq!(new_emis_res_data.index, new_index);
             let user_emis_data =
                 storage::get_user_emissions(&e, &samwise, &res_0_d_token_index).unwrap();
-            let new_accrual = 0
-                + (new_index - emis_user_data.index).fixed_mul_floor(
-                    &e,
-                    &1000,
-                    &(SCALAR_7 * SCALAR_7),
-                );
+            let new_accrual = (new_index - emis_user_data.index).fixed_mul_floor(
+                &e,
+                &1000,
+                &(SCALAR_7 * SCALAR_7),
+            );
             assert_eq!(user_emis_data.accrued, new_accrual);
         });
     }
@@ -1207,4 +1289,51 @@ fn test_total_supply() {
             assert_eq!(user.get_total_supply(1), 456 + 789);
         });
     }
+
+    #[test]
+    fn test_pack_and_unpack_positions() {
+        let e = Env::default();
+        let positions = Positions {
+            liabilities: map![&e, (2, 100), (49, 200)],
+            collateral: map![&e, (0, 300), (2, 400), (33, 500)],
+            supply: map![&e, (7, 600)],
+        };
+
+        let packed = positions.pack(&e);
+        assert_eq!(packed.liabilities, (1 << 2) | (1 << 49));
+        assert_eq!(packed.collateral, (1 << 0) | (1 << 2) | (1 << 33));
+        assert_eq!(packed.supply, 1 << 7);
+        assert_eq!(
+            packed.balances,
+            soroban_sdk::vec![&e, 100, 200, 300, 400, 500, 600]
+        );
+        assert_eq!(
+            positions.effective_bitmap(),
+            (1 << 0) | (1 << 2) | (1 << 33) | (1 << 49)
+        );
+
+        let unpacked = packed.unpack(&e);
+        assert_eq!(unpacked.liabilities, positions.liabilities);
+        assert_eq!(unpacked.collateral, positions.collateral);
+        assert_eq!(unpacked.supply, positions.supply);
+
+        let empty = Positions::env_default(&e).pack(&e);
+        assert_eq!(empty.liabilities | empty.collateral | empty.supply, 0);
+        assert_eq!(empty.unpack(&e).effective_count(), 0);
+    }
+
+    #[test]
+    fn test_packed_positions_are_smaller() {
+        let e = Env::default();
+        let mut positions = Positions::env_default(&e);
+        for index in 0..8 {
+            positions.liabilities.set(index, 1_0000000);
+            positions.collateral.set(index + 8, 2_0000000);
+        }
+
+        // the packed encoding drops the per-balance reserve index keys
+        let legacy_size = positions.clone().to_xdr(&e).len();
+        let packed_size = positions.pack(&e).to_xdr(&e).len();
+        assert!(packed_size < legacy_size);
+    }
 }
@@ -0,0 +1,650 @@
+use sep_41_token::TokenClient;
+use soroban_fixed_point_math::SorobanFixedPoint;
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::{
+    constants::MAX_WITHDRAWAL_QUEUE,
+    errors::PoolError,
+    events::PoolEvents,
+    storage::{self, QueuedWithdrawal},
+};
+
+use super::{pool::Pool, Reserve, User};
+
+/// A user's position in a reserve's withdrawal queue
+#[derive(Clone)]
+#[contracttype]
+pub struct WithdrawalQueuePosition {
+    pub index: u32,           // the user's index in the queue, filled from 0
+    pub b_tokens: i128,       // the b_tokens queued by the user
+    pub b_tokens_ahead: i128, // the b_tokens queued ahead of the user
+}
+
+/// Queue b_tokens from a user's supply position for withdrawal. The queued b_tokens are tracked by
+/// the queue until they are filled or dequeued, and continue to earn interest. They do not earn
+/// emissions, and are excluded from the reserve's emission supply while queued.
+///
+/// Only uncollateralized supply can be queued, and each queued withdrawal must be worth at least
+/// the pool's minimum collateral.
+///
+/// Returns the amount of b_tokens queued
+///
+/// ### Arguments
+/// * pool - The pool
+/// * user - The user queueing the withdrawal
+/// * asset - The underlying asset of the reserve
+/// * amount - The amount of underlying tokens to queue for withdrawal
+///
+/// ### Panics
+/// If the user has no supply, the queue is full, or the queued withdrawal is worth less than the
+/// pool's minimum collateral
+pub fn queue_withdrawal(
+    e: &Env,
+    pool: &mut Pool,
+    user: &mut User,
+    asset: &Address,
+    amount: i128,
+) -> i128 {
+    let mut reserve = pool.load_reserve(e, asset, true);
+    let cur_b_tokens = user.get_supply(reserve.config.index);
+    let mut to_queue = reserve.to_b_token_up(e, amount);
+    if to_queue > cur_b_tokens {
+        to_queue = cur_b_tokens;
+    }
+
+    // the queued b_tokens leave the user's position but remain part of the reserve's supply
+    user.remove_supply(e, &mut reserve, to_queue);
+    reserve.data.b_supply += to_queue;
+    let queued = storage::get_withdrawal_queued(e, asset);
+    storage::set_withdrawal_queued(e, asset, &(queued + to_queue));
+
+    let mut queue = storage::get_withdrawal_queue(e, asset);
+    let queued_b_tokens = match queue.iter().position(|entry| entry.user == user.address) {
+        Some(index) => {
+            let mut entry = queue.get_unchecked(index as u32);
+            entry.b_tokens += to_queue;
+            queue.set(index as u32, entry.clone());
+            entry.b_tokens
+        }
+        None => {
+            if queue.len() >= MAX_WITHDRAWAL_QUEUE {
+                panic_with_error!(e, PoolError::BadRequest);
+            }
+            queue.push_back(QueuedWithdrawal {
+                user: user.address.clone(),
+                b_tokens: to_queue,
+            });
+            to_queue
+        }
+    };
+    require_min_queued(e, pool, &reserve, queued_b_tokens);
+
+    storage::set_withdrawal_queue(e, asset, &queue);
+    pool.cache_reserve(reserve);
+    to_queue
+}
+
+/// Remove b_tokens from a user's queued withdrawal and return them to the user's supply position
+///
+/// Returns the amount of b_tokens dequeued
+///
+/// ### Arguments
+/// * pool - The pool
+/// * user - The user dequeueing the withdrawal
+/// * asset - The underlying asset of the reserve
+/// * amount - The amount of underlying tokens to dequeue
+///
+/// ### Panics
+/// If the user does not have a queued withdrawal for the reserve, or the remaining queued
+/// withdrawal is worth less than the pool's minimum collateral
+pub fn dequeue_withdrawal(
+    e: &Env,
+    pool: &mut Pool,
+    user: &mut User,
+    asset: &Address,
+    amount: i128,
+) -> i128 {
+    let mut queue = storage::get_withdrawal_queue(e, asset);
+    let index = match queue.iter().position(|entry| entry.user == user.address) {
+        Some(index) => index as u32,
+        None => panic_with_error!(e, PoolError::BadRequest),
+    };
+    let mut entry = queue.get_unchecked(index);
+
+    let mut reserve = pool.load_reserve(e, asset, true);
+    let mut to_dequeue = reserve.to_b_token_up(e, amount);
+    if to_dequeue > entry.b_tokens {
+        to_dequeue = entry.b_tokens;
+    }
+
+    // return the b_tokens to the emission supply before the user's position is updated
+    let queued = storage::get_withdrawal_queued(e, asset);
+    storage::set_withdrawal_queued(e, asset, &(queued - to_dequeue));
+    reserve.data.b_supply -= to_dequeue;
+    user.add_supply(e, &mut reserve, to_dequeue);
+
+    entry.b_tokens -= to_dequeue;
+    if entry.b_tokens == 0 {
+        queue.remove(index);
+    } else {
+        require_min_queued(e, pool, &reserve, entry.b_tokens);
+        queue.set(index, entry);
+    }
+
+    storage::set_withdrawal_queue(e, asset, &queue);
+    pool.cache_reserve(reserve);
+    to_dequeue
+}
+
+/// Route newly available liquidity for a reserve to its queued withdrawals, oldest first. Each
+/// filled withdrawal burns the queued b_tokens and credits the tokens to the queued user, who
+/// can claim them with `execute_claim_withdrawal`.
+///
+/// ### Arguments
+/// * pool - The pool
+/// * asset - The underlying asset of the reserve
+/// * amount - The amount of underlying tokens made available
+pub(super) fn fill_withdrawal_queue(e: &Env, pool: &mut Pool, asset: &Address, amount: i128) {
+    let mut queue = storage::get_withdrawal_queue(e, asset);
+    if queue.is_empty() || amount <= 0 {
+        return;
+    }
+
+    let mut reserve = pool.load_reserve(e, asset, true);
+    let mut total_filled: i128 = 0;
+    let mut total_burnt: i128 = 0;
+    let mut remaining = amount;
+    while remaining > 0 {
+        let mut entry = match queue.first() {
+            Some(entry) => entry,
+            None => break,
+        };
+        let entry_tokens = reserve.to_asset_from_b_token(e, entry.b_tokens);
+        let (tokens_out, b_tokens_burnt) = if entry_tokens <= remaining {
+            (entry_tokens, entry.b_tokens)
+        } else {
+            let mut to_burn = reserve.to_b_token_up(e, remaining);
+            if to_burn > entry.b_tokens {
+                to_burn = entry.b_tokens;
+            }
+            (remaining, to_burn)
+        };
+
+        entry.b_tokens -= b_tokens_burnt;
+        if entry.b_tokens == 0 {
+            queue.pop_front();
+        } else {
+            queue.set(0, entry.clone());
+        }
+        reserve.data.b_supply -= b_tokens_burnt;
+        total_burnt += b_tokens_burnt;
+        if tokens_out > 0 {
+            let claimable = storage::get_withdrawal_claim(e, &entry.user, asset);
+            storage::set_withdrawal_claim(e, &entry.user, asset, &(claimable + tokens_out));
+            total_filled += tokens_out;
+        }
+        remaining -= tokens_out;
+
+        PoolEvents::fill_withdrawal(
+            e,
+            asset.clone(),
+            entry.user.clone(),
+            tokens_out,
+            b_tokens_burnt,
+        );
+    }
+
+    storage::set_withdrawal_queue(e, asset, &queue);
+    let queued = storage::get_withdrawal_queued(e, asset);
+    storage::set_withdrawal_queued(e, asset, &(queued - total_burnt));
+    if total_filled > 0 {
+        let total = storage::get_withdrawal_total(e, asset);
+        storage::set_withdrawal_total(e, asset, &(total + total_filled));
+    }
+    pool.cache_reserve(reserve);
+}
+
+/// Require that a queued withdrawal is worth at least the pool's minimum collateral, so the
+/// limited queue slots cannot be taken up by dust withdrawals
+///
+/// ### Panics
+/// If the queued b_tokens are worth less than the pool's minimum collateral
+fn require_min_queued(e: &Env, pool: &mut Pool, reserve: &Reserve, b_tokens: i128) {
+    if pool.config.min_collateral > 0 {
+        let asset_to_base = pool.load_price(e, &reserve.asset);
+        let queued_base = asset_to_base.fixed_mul_floor(
+            e,
+            &reserve.to_asset_from_b_token(e, b_tokens),
+            &reserve.scalar,
+        );
+        if queued_base < pool.config.min_collateral {
+            panic_with_error!(e, PoolError::MinCollateralNotMet);
+        }
+    }
+}
+
+/// Claim a user's filled withdrawals for a reserve
+///
+/// Returns the amount of tokens claimed
+///
+/// ### Arguments
+/// * from - The user claiming their filled withdrawals
+/// * asset - The underlying asset of the reserve
+/// * to - The address receiving the tokens
+///
+/// ### Panics
+/// If the user has no filled withdrawals to claim
+pub fn execute_claim_withdrawal(e: &Env, from: &Address, asset: &Address, to: &Address) -> i128 {
+    let amount = storage::get_withdrawal_claim(e, from, asset);
+    if amount <= 0 {
+        panic_with_error!(e, PoolError::BadRequest);
+    }
+    storage::del_withdrawal_claim(e, from, asset);
+    let total = storage::get_withdrawal_total(e, asset);
+    storage::set_withdrawal_total(e, asset, &(total - amount));
+
+    TokenClient::new(e, asset).transfer(&e.current_contract_address(), to, &amount);
+    amount
+}
+
+/// Load a user's position in a reserve's withdrawal queue
+///
+/// Returns None if the user does not have a queued withdrawal
+///
+/// ### Arguments
+/// * asset - The underlying asset of the reserve
+/// * user - The user
+pub fn load_withdrawal_queue_position(
+    e: &Env,
+    asset: &Address,
+    user: &Address,
+) -> Option<WithdrawalQueuePosition> {
+    let queue = storage::get_withdrawal_queue(e, asset);
+    let mut b_tokens_ahead: i128 = 0;
+    for (index, entry) in queue.iter().enumerate() {
+        if &entry.user == user {
+            return Some(WithdrawalQueuePosition {
+                index: index as u32,
+                b_tokens: entry.b_tokens,
+                b_tokens_ahead,
+            });
+        }
+        b_tokens_ahead += entry.b_tokens;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pool::{actions::build_actions_from_request, Positions, Request, RequestType},
+        storage::PoolConfig,
+        testutils,
+    };
+
+    use super::*;
+    use sep_40_oracle::testutils::Asset;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger, LedgerInfo},
+        vec, Symbol,
+    };
+
+    #[test]
+    fn test_queue_and_fill_withdrawal() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let merry = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 600;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![&e, Asset::Stellar(underlying.clone())],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000]);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+
+        let user_positions = Positions {
+            liabilities: map![&e],
+            collateral: map![&e],
+            supply: map![&e, (0, 20_0000000)],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &user_positions);
+            storage::set_user_positions(&e, &frodo, &user_positions);
+
+            let mut pool = Pool::load(&e);
+
+            // samwise and frodo queue withdrawals
+            let mut samwise_state = User::load(&e, &samwise);
+            let actions = build_actions_from_request(
+                &e,
+                &mut pool,
+                &mut samwise_state,
+                vec![
+                    &e,
+                    Request {
+                        request_type: RequestType::QueueWithdrawal as u32,
+                        address: underlying.clone(),
+                        amount: 15_0000000,
+                    },
+                ],
+            );
+            assert_eq!(actions.pool_transfer.len(), 0);
+            assert_eq!(samwise_state.get_supply(0), 5_0000000);
+            samwise_state.store(&e);
+
+            let mut frodo_state = User::load(&e, &frodo);
+            build_actions_from_request(
+                &e,
+                &mut pool,
+                &mut frodo_state,
+                vec![
+                    &e,
+                    Request {
+                        request_type: RequestType::QueueWithdrawal as u32,
+                        address: underlying.clone(),
+                        amount: 30_0000000,
+                    },
+                ],
+            );
+            assert_eq!(frodo_state.get_supply(0), 0);
+            frodo_state.store(&e);
+
+            let frodo_queue_position =
+                load_withdrawal_queue_position(&e, &underlying, &frodo).unwrap();
+            assert_eq!(frodo_queue_position.index, 1);
+            assert_eq!(frodo_queue_position.b_tokens, 20_0000000);
+            assert_eq!(frodo_queue_position.b_tokens_ahead, 15_0000000);
+
+            // merry supplies, which fills samwise and part of frodo
+            let mut merry_state = User::load(&e, &merry);
+            let actions = build_actions_from_request(
+                &e,
+                &mut pool,
+                &mut merry_state,
+                vec![
+                    &e,
+                    Request {
+                        request_type: RequestType::Supply as u32,
+                        address: underlying.clone(),
+                        amount: 20_0000000,
+                    },
+                ],
+            );
+            assert_eq!(
+                actions.spender_transfer.get_unchecked(underlying.clone()),
+                20_0000000
+            );
+            assert_eq!(actions.pool_transfer.len(), 0);
+            assert_eq!(
+                storage::get_withdrawal_claim(&e, &samwise, &underlying),
+                15_0000000
+            );
+            assert_eq!(
+                storage::get_withdrawal_claim(&e, &frodo, &underlying),
+                5_0000000
+            );
+            assert_eq!(storage::get_withdrawal_total(&e, &underlying), 20_0000000);
+            assert_eq!(merry_state.get_supply(0), 20_0000000);
+
+            assert!(load_withdrawal_queue_position(&e, &underlying, &samwise).is_none());
+            let frodo_queue_position =
+                load_withdrawal_queue_position(&e, &underlying, &frodo).unwrap();
+            assert_eq!(frodo_queue_position.index, 0);
+            assert_eq!(frodo_queue_position.b_tokens, 15_0000000);
+            assert_eq!(frodo_queue_position.b_tokens_ahead, 0);
+
+            // frodo's remaining queued b_tokens are still part of the reserve's supply
+            let reserve = pool.load_reserve(&e, &underlying, false);
+            assert_eq!(reserve.data.b_supply, reserve_data.b_supply);
+            // but do not earn emissions
+            assert_eq!(storage::get_withdrawal_queued(&e, &underlying), 15_0000000);
+            assert_eq!(
+                reserve.emission_b_supply(&e),
+                reserve_data.b_supply - 15_0000000
+            );
+            let escrow = User::load(&e, &e.current_contract_address());
+            assert_eq!(escrow.get_supply(0), 0);
+        });
+    }
+
+    #[test]
+    fn test_claim_withdrawal() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let frodo = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, underlying_client) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        underlying_client.mint(&pool, &20_0000000);
+        let pool_balance = underlying_client.balance(&pool);
+
+        e.as_contract(&pool, || {
+            storage::set_withdrawal_claim(&e, &samwise, &underlying, &15_0000000);
+            storage::set_withdrawal_claim(&e, &frodo, &underlying, &5_0000000);
+            storage::set_withdrawal_total(&e, &underlying, &20_0000000);
+
+            let claimed = execute_claim_withdrawal(&e, &samwise, &underlying, &frodo);
+            assert_eq!(claimed, 15_0000000);
+            assert_eq!(storage::get_withdrawal_claim(&e, &samwise, &underlying), 0);
+            assert_eq!(
+                storage::get_withdrawal_claim(&e, &frodo, &underlying),
+                5_0000000
+            );
+            assert_eq!(storage::get_withdrawal_total(&e, &underlying), 5_0000000);
+        });
+        assert_eq!(underlying_client.balance(&frodo), 15_0000000);
+        assert_eq!(underlying_client.balance(&pool), pool_balance - 15_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_claim_withdrawal_nothing_to_claim() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+
+        e.as_contract(&pool, || {
+            execute_claim_withdrawal(&e, &samwise, &underlying, &samwise);
+        });
+    }
+
+    #[test]
+    fn test_dequeue_withdrawal() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, mut reserve_data) = testutils::default_reserve_meta();
+        reserve_data.last_time = 600;
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![&e, Asset::Stellar(underlying.clone())],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000]);
+
+        e.ledger().set(LedgerInfo {
+            timestamp: 600,
+            protocol_version: 22,
+            sequence_number: 1234,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 10,
+            min_persistent_entry_ttl: 10,
+            max_entry_ttl: 3110400,
+        });
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+
+        let user_positions = Positions {
+            liabilities: map![&e],
+            collateral: map![&e],
+            supply: map![&e, (0, 20_0000000)],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &user_positions);
+
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+
+            let queued =
+                queue_withdrawal(&e, &mut pool, &mut samwise_state, &underlying, 10_0000000);
+            assert_eq!(queued, 10_0000000);
+            assert_eq!(storage::get_withdrawal_queued(&e, &underlying), 10_0000000);
+
+            let dequeued =
+                dequeue_withdrawal(&e, &mut pool, &mut samwise_state, &underlying, 4_0000000);
+            assert_eq!(dequeued, 4_0000000);
+            assert_eq!(samwise_state.get_supply(0), 14_0000000);
+            let position = load_withdrawal_queue_position(&e, &underlying, &samwise).unwrap();
+            assert_eq!(position.b_tokens, 6_0000000);
+
+            let dequeued =
+                dequeue_withdrawal(&e, &mut pool, &mut samwise_state, &underlying, 100_0000000);
+            assert_eq!(dequeued, 6_0000000);
+            assert_eq!(samwise_state.get_supply(0), 20_0000000);
+            assert!(load_withdrawal_queue_position(&e, &underlying, &samwise).is_none());
+            assert_eq!(storage::get_withdrawal_queued(&e, &underlying), 0);
+
+            let reserve = pool.load_reserve(&e, &underlying, false);
+            assert_eq!(reserve.data.b_supply, reserve_data.b_supply);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1224)")]
+    fn test_queue_withdrawal_under_min_collateral() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+        let (oracle, oracle_client) = testutils::create_mock_oracle(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        oracle_client.set_data(
+            &bombadil,
+            &Asset::Other(Symbol::new(&e, "USD")),
+            &vec![&e, Asset::Stellar(underlying.clone())],
+            &7,
+            &300,
+        );
+        oracle_client.set_price_stable(&vec![&e, 1_0000000]);
+
+        let pool_config = PoolConfig {
+            oracle,
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        let user_positions = Positions {
+            liabilities: map![&e],
+            collateral: map![&e],
+            supply: map![&e, (0, 20_0000000)],
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+            storage::set_user_positions(&e, &samwise, &user_positions);
+
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+            queue_withdrawal(&e, &mut pool, &mut samwise_state, &underlying, 0_5000000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1200)")]
+    fn test_dequeue_withdrawal_not_queued() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let bombadil = Address::generate(&e);
+        let samwise = Address::generate(&e);
+        let pool = testutils::create_pool(&e);
+
+        let (underlying, _) = testutils::create_token_contract(&e, &bombadil);
+        let (reserve_config, reserve_data) = testutils::default_reserve_meta();
+        testutils::create_reserve(&e, &pool, &underlying, &reserve_config, &reserve_data);
+
+        let pool_config = PoolConfig {
+            oracle: Address::generate(&e),
+            min_collateral: 1_0000000,
+            bstop_rate: 0_1000000,
+            status: 0,
+            max_positions: 2,
+        };
+        e.as_contract(&pool, || {
+            storage::set_pool_config(&e, &pool_config);
+
+            let mut pool = Pool::load(&e);
+            let mut samwise_state = User::load(&e, &samwise);
+            dequeue_withdrawal(&e, &mut pool, &mut samwise_state, &underlying, 1_0000000);
+        });
+    }
+}
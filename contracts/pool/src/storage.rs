@@ -1,9 +1,14 @@
 use soroban_sdk::{
-    contracttype, map, panic_with_error, unwrap::UnwrapOptimized, vec, Address, Env, IntoVal, Map,
-    String, Symbol, TryFromVal, Val, Vec,
+    contracttype, map, panic_with_error, unwrap::UnwrapOptimized, vec, Address, BytesN, Env,
+    IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
-use crate::{auctions::AuctionData, constants::MAX_RESERVES, pool::Positions, PoolError};
+use crate::{
+    auctions::AuctionData,
+    constants::MAX_RESERVES,
+    pool::{PackedPositions, Positions},
+    PoolError,
+};
 
 /********** Ledger Thresholds **********/
 
@@ -31,6 +36,77 @@ pub struct PoolConfig {
     pub max_positions: u32, // the maximum number of effective positions a single user can hold, and the max assets an auction can contain
 }
 
+/// The pool's per-user loan limits
+#[derive(Clone)]
+#[contracttype]
+pub struct PoolLoanLimits {
+    pub min_borrow: i128, // the minimum size of a single borrow request in the pool's base asset, or 0 for no minimum
+    pub max_liability: i128, // the maximum liability a single user can hold in the pool's base asset, or 0 for no maximum
+}
+
+/// The pool's keeper reward configuration
+#[derive(Clone)]
+#[contracttype]
+pub struct KeeperConfig {
+    pub reward: i128,         // the BLND paid to a keeper per rewarded maintenance call
+    pub max_per_period: i128, // the max BLND paid to keepers per keeper period
+    pub gulp_threshold: u32,  // the min gulp to reward as a pct of reserve supply (7 decimals)
+}
+
+/// The pool's keeper reward state
+#[derive(Clone)]
+#[contracttype]
+pub struct KeeperData {
+    pub budget: i128,      // the BLND set aside from emissions for keeper rewards
+    pub period_start: u64, // the start time of the current keeper period
+    pub paid: i128,        // the BLND paid to keepers during the current keeper period
+}
+
+/// The pool's soft liquidation configuration
+#[derive(Clone)]
+#[contracttype]
+pub struct SoftLiquidationConfig {
+    pub min_hf: i128, // the min health factor a user can be soft liquidated at (7 decimals)
+    pub discount: i128, // the discount on collateral received by the liquidator (7 decimals)
+    pub max_pct: i128, // the pct of a liability repaid per soft liquidation (7 decimals)
+}
+
+/// The pool's status policy. The q4w percentages are the backstop's queued for withdrawal
+/// percentages at which the pool's status is downgraded.
+#[derive(Clone)]
+#[contracttype]
+pub struct StatusPolicy {
+    pub on_ice_q4w: i128,       // the q4w pct that puts the pool on-ice (7 decimals)
+    pub frozen_q4w: i128,       // the q4w pct that freezes the pool (7 decimals)
+    pub admin_on_ice_q4w: i128, // the q4w pct that puts an admin active pool on-ice (7 decimals)
+    pub admin_frozen_q4w: i128, // the q4w pct that freezes an admin on-ice pool (7 decimals)
+    pub threshold_pc: i128,     // the backstop product constant needed to meet the threshold
+}
+
+/// A status policy change waiting for its timelock to expire
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedStatusPolicy {
+    pub new_policy: StatusPolicy,
+    pub unlock_time: u64,
+}
+
+/// A base asset change waiting for its timelock to expire
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedBaseAsset {
+    pub new_base_asset: Option<Address>,
+    pub unlock_time: u64,
+}
+
+/// A contract wasm upgrade waiting for its timelock to expire
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub unlock_time: u64,
+}
+
 /// The pool's emission config
 #[derive(Clone)]
 #[contracttype]
@@ -65,6 +141,15 @@ pub struct QueuedReserveInit {
     pub unlock_time: u64,
 }
 
+/// The delisting state of a reserve being sunset
+#[derive(Clone)]
+#[contracttype]
+pub struct ReserveSunset {
+    pub c_factor: u32,    // the collateral factor before the sunset began
+    pub unlock_time: u64, // the earliest time the sunset can begin
+    pub start_time: u64,  // the time the sunset began, or 0 if still queued
+}
+
 /// The data for a reserve asset
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -78,6 +163,14 @@ pub struct ReserveData {
     pub last_time: u64, // the last block the data was updated
 }
 
+/// A withdrawal of b_tokens queued against a reserve
+#[derive(Clone)]
+#[contracttype]
+pub struct QueuedWithdrawal {
+    pub user: Address,  // the user who queued the withdrawal
+    pub b_tokens: i128, // the amount of b_tokens waiting to be withdrawn
+}
+
 /// The emission data for the reserve b or d token
 #[derive(Clone)]
 #[contracttype]
@@ -104,8 +197,20 @@ pub struct UserEmissionData {
 const BACKSTOP_KEY: &str = "Backstop";
 const BLND_TOKEN_KEY: &str = "BLNDTkn";
 const POOL_CONFIG_KEY: &str = "Config";
+const LOAN_LIMITS_KEY: &str = "LoanLimits";
+const BASE_ASSET_KEY: &str = "BaseAsset";
+const QUEUED_BASE_ASSET_KEY: &str = "BaseAssetQ";
+const KEEPER_CONFIG_KEY: &str = "KeepCfg";
+const KEEPER_DATA_KEY: &str = "KeepData";
+const SOFT_LIQ_CONFIG_KEY: &str = "SoftLiqCfg";
+const STATUS_POLICY_KEY: &str = "StatusPol";
+const UPGRADE_KEY: &str = "Upgrade";
+const VERSION_KEY: &str = "Version";
+const QUEUED_STATUS_POLICY_KEY: &str = "StatusPolQ";
 const RES_LIST_KEY: &str = "ResList";
+const RES_FREE_KEY: &str = "ResFree";
 const POOL_EMIS_KEY: &str = "PoolEmis";
+const BORROWER_COUNT_KEY: &str = "BorrowCnt";
 
 #[derive(Clone)]
 #[contracttype]
@@ -114,6 +219,28 @@ pub struct UserReserveKey {
     reserve_id: u32,
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct RewardKey {
+    reserve_id: u32,
+    token: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct UserRewardKey {
+    user: Address,
+    reserve_id: u32,
+    token: Address,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct UserAssetKey {
+    user: Address,
+    asset: Address,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct AuctionKey {
@@ -121,6 +248,13 @@ pub struct AuctionKey {
     auct_type: u32, // the type of auction taking place
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub struct AuctionPageKey {
+    auct_type: u32, // the type of auction taking place
+    page: u32,      // the page of the index
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum PoolDataKey {
@@ -130,14 +264,46 @@ pub enum PoolDataKey {
     ResInit(Address),
     // A map of underlying asset's contract address to reserve data
     ResData(Address),
+    // A map of underlying asset's contract address to reserve sunset
+    ResSunset(Address),
+    // A map of underlying asset's contract address to queued withdrawals
+    WithdrawQueue(Address),
+    // A map of underlying asset's contract address to the total filled withdrawals not yet claimed
+    WithdrawTotal(Address),
+    // A map of underlying asset's contract address to the total b_tokens queued for withdrawal
+    WithdrawQueued(Address),
+    // The filled withdrawals of an underlying asset a user can claim
+    WithdrawClaim(UserAssetKey),
     // The reserve's emission data
     EmisData(u32),
-    // Map of positions in the pool for a user
+    // The lowest valid user emission index for a reserve token whose index was reclaimed
+    EmisFloor(u32),
+    // Map of positions in the pool for a user, stored before positions were packed
     Positions(Address),
+    // The packed positions in the pool for a user
+    UserPos(Address),
     // The emission information for a reserve asset for a user
     UserEmis(UserReserveKey),
     // The auction's data
     Auction(AuctionKey),
+    // The number of users with an auction of a type
+    AuctionCnt(u32),
+    // A page of the index of users with an auction of a type
+    Auctions(AuctionPageKey),
+    // A user's position in the index of users with an auction of a type
+    AuctionIdx(AuctionKey),
+    // The external reward tokens for a reserve token
+    RewardTokens(u32),
+    // The external reward program's emission data
+    RewardData(RewardKey),
+    // The external reward program's emission information for a user
+    UserReward(UserRewardKey),
+    // A page of the index of users with liabilities
+    Borrowers(u32),
+    // A user's position in the index of users with liabilities
+    BorrowerIdx(Address),
+    // If a user has opted in to soft liquidations
+    SoftLiq(Address),
 }
 
 /********** Storage **********/
@@ -169,36 +335,175 @@ fn get_persistent_default<K: IntoVal<Env, Val>, V: TryFromVal<Env, Val>, F: FnOn
 
 /********** User **********/
 
-/// Fetch the user's positions or return an empty Positions struct
+/// Fetch the user's positions or return an empty Positions struct. Falls back to the
+/// user's legacy positions if they have not been packed yet.
 ///
 /// ### Arguments
 /// * `user` - The address of the user
 pub fn get_user_positions(e: &Env, user: &Address) -> Positions {
+    let key = PoolDataKey::UserPos(user.clone());
+    if let Some(packed) = e
+        .storage()
+        .persistent()
+        .get::<PoolDataKey, PackedPositions>(&key)
+    {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+        return packed.unpack(e);
+    }
+    get_legacy_user_positions(e, user).unwrap_or_else(|| Positions::env_default(e))
+}
+
+/// Check if the user has packed positions
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn has_user_positions(e: &Env, user: &Address) -> bool {
+    let key = PoolDataKey::UserPos(user.clone());
+    e.storage().persistent().has(&key)
+}
+
+/// Set the user's positions. The first time a user's positions are packed, any legacy
+/// positions are deleted, as the packed positions replace them.
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `positions` - The new positions for the user
+pub fn set_user_positions(e: &Env, user: &Address, positions: &Positions) {
+    let key = PoolDataKey::UserPos(user.clone());
+    if !e.storage().persistent().has(&key) {
+        del_legacy_user_positions(e, user);
+    }
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, PackedPositions>(&key, &positions.pack(e));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Fetch the user's positions stored before positions were packed, if any
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn get_legacy_user_positions(e: &Env, user: &Address) -> Option<Positions> {
+    let key = PoolDataKey::Positions(user.clone());
+    let result = e.storage().persistent().get::<PoolDataKey, Positions>(&key);
+    if result.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    }
+    result
+}
+
+/// Delete the user's positions stored before positions were packed
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn del_legacy_user_positions(e: &Env, user: &Address) {
     let key = PoolDataKey::Positions(user.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/********** Borrowers **********/
+
+/// Fetch the number of users with liabilities
+pub fn get_borrower_count(e: &Env) -> u32 {
+    get_persistent_default(
+        e,
+        &Symbol::new(e, BORROWER_COUNT_KEY),
+        || 0,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the number of users with liabilities
+///
+/// ### Arguments
+/// * `count` - The number of users with liabilities
+pub fn set_borrower_count(e: &Env, count: u32) {
+    let key = Symbol::new(e, BORROWER_COUNT_KEY);
+    e.storage().persistent().set::<Symbol, u32>(&key, &count);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch a page of the index of users with liabilities
+///
+/// ### Arguments
+/// * `page` - The page of the index
+pub fn get_borrower_page(e: &Env, page: u32) -> Vec<Address> {
+    let key = PoolDataKey::Borrowers(page);
     get_persistent_default(
         e,
         &key,
-        || Positions::env_default(e),
-        LEDGER_THRESHOLD_USER,
-        LEDGER_BUMP_USER,
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
     )
 }
 
-/// Set the user's positions
+/// Set a page of the index of users with liabilities
+///
+/// ### Arguments
+/// * `page` - The page of the index
+/// * `borrowers` - The users in the page
+pub fn set_borrower_page(e: &Env, page: u32, borrowers: &Vec<Address>) {
+    let key = PoolDataKey::Borrowers(page);
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, Vec<Address>>(&key, borrowers);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the user's position in the index of users with liabilities, if it exists
 ///
 /// ### Arguments
 /// * `user` - The address of the user
-/// * `positions` - The new positions for the user
-pub fn set_user_positions(e: &Env, user: &Address, positions: &Positions) {
-    let key = PoolDataKey::Positions(user.clone());
+pub fn get_borrower_index(e: &Env, user: &Address) -> Option<u32> {
+    let key = PoolDataKey::BorrowerIdx(user.clone());
+    get_persistent_default(e, &key, || None, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Check if the user is in the index of users with liabilities
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn has_borrower_index(e: &Env, user: &Address) -> bool {
+    let key = PoolDataKey::BorrowerIdx(user.clone());
+    e.storage().persistent().has(&key)
+}
+
+/// Set the user's position in the index of users with liabilities
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `index` - The position of the user in the index
+pub fn set_borrower_index(e: &Env, user: &Address, index: u32) {
+    let key = PoolDataKey::BorrowerIdx(user.clone());
     e.storage()
         .persistent()
-        .set::<PoolDataKey, Positions>(&key, positions);
+        .set::<PoolDataKey, u32>(&key, &index);
     e.storage()
         .persistent()
         .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
 }
 
+/// Remove the user from the index of users with liabilities
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn del_borrower_index(e: &Env, user: &Address) {
+    let key = PoolDataKey::BorrowerIdx(user.clone());
+    e.storage().persistent().remove(&key);
+}
+
 /********** Admin **********/
 
 /// Fetch the current admin Address
@@ -325,111 +630,373 @@ pub fn set_pool_config(e: &Env, config: &PoolConfig) {
         .set::<Symbol, PoolConfig>(&Symbol::new(e, POOL_CONFIG_KEY), config);
 }
 
-/********** Reserve Config (ResConfig) **********/
+/// Fetch the pool's loan limits, or no limits if they have not been set
+pub fn get_loan_limits(e: &Env) -> PoolLoanLimits {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, LOAN_LIMITS_KEY))
+        .unwrap_or(PoolLoanLimits {
+            min_borrow: 0,
+            max_liability: 0,
+        })
+}
 
-/// Fetch the reserve data for an asset
+/// Set the pool's loan limits
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-///
-/// ### Panics
-/// If the reserve does not exist
-pub fn get_res_config(e: &Env, asset: &Address) -> ReserveConfig {
-    let key = PoolDataKey::ResConfig(asset.clone());
+/// * `limits` - The loan limits for the pool
+pub fn set_loan_limits(e: &Env, limits: &PoolLoanLimits) {
     e.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        .instance()
+        .set::<Symbol, PoolLoanLimits>(&Symbol::new(e, LOAN_LIMITS_KEY), limits);
+}
+
+/********** Base Asset **********/
+
+/// Fetch the reserve prices are denominated in, or None if prices are denominated in the
+/// oracle's base asset
+pub fn get_base_asset(e: &Env) -> Option<Address> {
     e.storage()
-        .persistent()
-        .get::<PoolDataKey, ReserveConfig>(&key)
-        .unwrap_optimized()
+        .instance()
+        .get::<Symbol, Address>(&Symbol::new(e, BASE_ASSET_KEY))
 }
 
-/// Set the reserve configuration for an asset
+/// Set the reserve prices are denominated in
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-/// * `config` - The reserve configuration for the asset
-pub fn set_res_config(e: &Env, asset: &Address, config: &ReserveConfig) {
-    let key = PoolDataKey::ResConfig(asset.clone());
+/// * `asset` - The underlying asset of the reserve
+pub fn set_base_asset(e: &Env, asset: &Address) {
     e.storage()
-        .persistent()
-        .set::<PoolDataKey, ReserveConfig>(&key, config);
+        .instance()
+        .set::<Symbol, Address>(&Symbol::new(e, BASE_ASSET_KEY), asset);
+}
+
+/// Delete the base asset, so prices are denominated in the oracle's base asset
+pub fn del_base_asset(e: &Env) {
     e.storage()
-        .persistent()
-        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        .instance()
+        .remove(&Symbol::new(e, BASE_ASSET_KEY));
 }
 
-/// Checks if a reserve exists for an asset
-///
-/// ### Arguments
-/// * `asset` - The contract address of the asset
-pub fn has_res(e: &Env, asset: &Address) -> bool {
-    let key = PoolDataKey::ResConfig(asset.clone());
-    e.storage().persistent().has(&key)
+/// Fetch the queued base asset change, if any
+pub fn get_queued_base_asset(e: &Env) -> Option<QueuedBaseAsset> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, QUEUED_BASE_ASSET_KEY))
 }
 
-/// Fetch a queued reserve set
+/// Set the queued base asset change
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-///
-/// ### Panics
-/// If the reserve set has not been queued
-pub fn get_queued_reserve_set(e: &Env, asset: &Address) -> QueuedReserveInit {
-    let key = PoolDataKey::ResInit(asset.clone());
+/// * `queued_base_asset` - The queued base asset change
+pub fn set_queued_base_asset(e: &Env, queued_base_asset: &QueuedBaseAsset) {
     e.storage()
-        .temporary()
-        .get::<PoolDataKey, QueuedReserveInit>(&key)
-        .unwrap_optimized()
+        .instance()
+        .set::<Symbol, QueuedBaseAsset>(&Symbol::new(e, QUEUED_BASE_ASSET_KEY), queued_base_asset);
 }
 
-/// Check if a reserve is actively queued
-///
-/// ### Arguments
-/// * `asset` - The contract address of the asset
-pub fn has_queued_reserve_set(e: &Env, asset: &Address) -> bool {
-    let key = PoolDataKey::ResInit(asset.clone());
-    e.storage().temporary().has(&key)
+/// Delete the queued base asset change
+pub fn del_queued_base_asset(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, QUEUED_BASE_ASSET_KEY));
 }
 
-/// Set a new queued reserve set
+/********** Status Policy **********/
+
+/// Fetch the pool's status policy
+#[allow(clippy::zero_prefixed_literal)]
+pub fn get_status_policy(e: &Env) -> StatusPolicy {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, STATUS_POLICY_KEY))
+        .unwrap_or(StatusPolicy {
+            on_ice_q4w: 0_3000000,
+            frozen_q4w: 0_6000000,
+            admin_on_ice_q4w: 0_5000000,
+            admin_frozen_q4w: 0_7500000,
+            threshold_pc: 100_000,
+        })
+}
+
+/// Set the pool's status policy
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-/// * `config` - The reserve configuration for the asset
-pub fn set_queued_reserve_set(e: &Env, res_init: &QueuedReserveInit, asset: &Address) {
-    let key = PoolDataKey::ResInit(asset.clone());
+/// * `policy` - The status policy
+pub fn set_status_policy(e: &Env, policy: &StatusPolicy) {
     e.storage()
-        .temporary()
-        .set::<PoolDataKey, QueuedReserveInit>(&key, res_init);
+        .instance()
+        .set::<Symbol, StatusPolicy>(&Symbol::new(e, STATUS_POLICY_KEY), policy);
+}
+
+/// Fetch the queued status policy change, if any
+pub fn get_queued_status_policy(e: &Env) -> Option<QueuedStatusPolicy> {
     e.storage()
-        .temporary()
-        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+        .instance()
+        .get(&Symbol::new(e, QUEUED_STATUS_POLICY_KEY))
 }
 
-/// Delete a queued reserve set
+/// Set the queued status policy change
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-///
-/// ### Panics
-/// If the reserve set has not been queued
-pub fn del_queued_reserve_set(e: &Env, asset: &Address) {
-    let key = PoolDataKey::ResInit(asset.clone());
-    e.storage().temporary().remove(&key);
+/// * `queued_policy` - The queued status policy change
+pub fn set_queued_status_policy(e: &Env, queued_policy: &QueuedStatusPolicy) {
+    e.storage().instance().set::<Symbol, QueuedStatusPolicy>(
+        &Symbol::new(e, QUEUED_STATUS_POLICY_KEY),
+        queued_policy,
+    );
 }
 
-/********** Reserve Data (ResData) **********/
+/// Delete the queued status policy change
+pub fn del_queued_status_policy(e: &Env) {
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, QUEUED_STATUS_POLICY_KEY));
+}
 
-/// Fetch the reserve data for an asset
+/********** Upgrades **********/
+
+/// Fetch the queued contract upgrade, if any
+pub fn get_queued_upgrade(e: &Env) -> Option<QueuedUpgrade> {
+    e.storage().instance().get(&Symbol::new(e, UPGRADE_KEY))
+}
+
+/// Set the queued contract upgrade
 ///
 /// ### Arguments
-/// * `asset` - The contract address of the asset
-///
-/// ### Panics
-/// If the reserve does not exist
-pub fn get_res_data(e: &Env, asset: &Address) -> ReserveData {
+/// * `upgrade` - The queued contract upgrade
+pub fn set_queued_upgrade(e: &Env, upgrade: &QueuedUpgrade) {
+    e.storage()
+        .instance()
+        .set::<Symbol, QueuedUpgrade>(&Symbol::new(e, UPGRADE_KEY), upgrade);
+}
+
+/// Delete the queued contract upgrade
+pub fn del_queued_upgrade(e: &Env) {
+    e.storage().instance().remove(&Symbol::new(e, UPGRADE_KEY));
+}
+
+/// Fetch the version of the pool's storage layout
+pub fn get_storage_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, VERSION_KEY))
+        .unwrap_or(1)
+}
+
+/// Set the version of the pool's storage layout
+///
+/// ### Arguments
+/// * `version` - The storage version
+pub fn set_storage_version(e: &Env, version: u32) {
+    e.storage()
+        .instance()
+        .set::<Symbol, u32>(&Symbol::new(e, VERSION_KEY), &version);
+}
+
+/********** Soft Liquidation **********/
+
+/// Fetch the pool's soft liquidation configuration
+pub fn get_soft_liquidation_config(e: &Env) -> SoftLiquidationConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, SOFT_LIQ_CONFIG_KEY))
+        .unwrap_or(SoftLiquidationConfig {
+            min_hf: 0,
+            discount: 0,
+            max_pct: 0,
+        })
+}
+
+/// Set the pool's soft liquidation configuration
+///
+/// ### Arguments
+/// * `config` - The soft liquidation configuration
+pub fn set_soft_liquidation_config(e: &Env, config: &SoftLiquidationConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, SoftLiquidationConfig>(&Symbol::new(e, SOFT_LIQ_CONFIG_KEY), config);
+}
+
+/// Check if the user has opted in to soft liquidations
+///
+/// ### Arguments
+/// * `user` - The address of the user
+pub fn get_soft_liquidation(e: &Env, user: &Address) -> bool {
+    let key = PoolDataKey::SoftLiq(user.clone());
+    get_persistent_default(e, &key, || false, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set if the user has opted in to soft liquidations
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `enabled` - If soft liquidations are enabled for the user
+pub fn set_soft_liquidation(e: &Env, user: &Address, enabled: bool) {
+    let key = PoolDataKey::SoftLiq(user.clone());
+    if enabled {
+        e.storage()
+            .persistent()
+            .set::<PoolDataKey, bool>(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/********** Keeper **********/
+
+/// Fetch the pool's keeper reward configuration
+pub fn get_keeper_config(e: &Env) -> KeeperConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEEPER_CONFIG_KEY))
+        .unwrap_or(KeeperConfig {
+            reward: 0,
+            max_per_period: 0,
+            gulp_threshold: 0,
+        })
+}
+
+/// Set the pool's keeper reward configuration
+///
+/// ### Arguments
+/// * `config` - The keeper reward configuration
+pub fn set_keeper_config(e: &Env, config: &KeeperConfig) {
+    e.storage()
+        .instance()
+        .set::<Symbol, KeeperConfig>(&Symbol::new(e, KEEPER_CONFIG_KEY), config);
+}
+
+/// Fetch the pool's keeper reward state
+pub fn get_keeper_data(e: &Env) -> KeeperData {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEEPER_DATA_KEY))
+        .unwrap_or(KeeperData {
+            budget: 0,
+            period_start: 0,
+            paid: 0,
+        })
+}
+
+/// Set the pool's keeper reward state
+///
+/// ### Arguments
+/// * `data` - The keeper reward state
+pub fn set_keeper_data(e: &Env, data: &KeeperData) {
+    e.storage()
+        .instance()
+        .set::<Symbol, KeeperData>(&Symbol::new(e, KEEPER_DATA_KEY), data);
+}
+
+/********** Reserve Config (ResConfig) **********/
+
+/// Fetch the reserve data for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+///
+/// ### Panics
+/// If the reserve does not exist
+pub fn get_res_config(e: &Env, asset: &Address) -> ReserveConfig {
+    let key = PoolDataKey::ResConfig(asset.clone());
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+    e.storage()
+        .persistent()
+        .get::<PoolDataKey, ReserveConfig>(&key)
+        .unwrap_optimized()
+}
+
+/// Set the reserve configuration for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `config` - The reserve configuration for the asset
+pub fn set_res_config(e: &Env, asset: &Address, config: &ReserveConfig) {
+    let key = PoolDataKey::ResConfig(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReserveConfig>(&key, config);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Checks if a reserve exists for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn has_res(e: &Env, asset: &Address) -> bool {
+    let key = PoolDataKey::ResConfig(asset.clone());
+    e.storage().persistent().has(&key)
+}
+
+/// Fetch a queued reserve set
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+///
+/// ### Panics
+/// If the reserve set has not been queued
+pub fn get_queued_reserve_set(e: &Env, asset: &Address) -> QueuedReserveInit {
+    let key = PoolDataKey::ResInit(asset.clone());
+    e.storage()
+        .temporary()
+        .get::<PoolDataKey, QueuedReserveInit>(&key)
+        .unwrap_optimized()
+}
+
+/// Check if a reserve is actively queued
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn has_queued_reserve_set(e: &Env, asset: &Address) -> bool {
+    let key = PoolDataKey::ResInit(asset.clone());
+    e.storage().temporary().has(&key)
+}
+
+/// Set a new queued reserve set
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `config` - The reserve configuration for the asset
+pub fn set_queued_reserve_set(e: &Env, res_init: &QueuedReserveInit, asset: &Address) {
+    let key = PoolDataKey::ResInit(asset.clone());
+    e.storage()
+        .temporary()
+        .set::<PoolDataKey, QueuedReserveInit>(&key, res_init);
+    e.storage()
+        .temporary()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Delete a queued reserve set
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+///
+/// ### Panics
+/// If the reserve set has not been queued
+pub fn del_queued_reserve_set(e: &Env, asset: &Address) {
+    let key = PoolDataKey::ResInit(asset.clone());
+    e.storage().temporary().remove(&key);
+}
+
+/********** Reserve Data (ResData) **********/
+
+/// Fetch the reserve data for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+///
+/// ### Panics
+/// If the reserve does not exist
+pub fn get_res_data(e: &Env, asset: &Address) -> ReserveData {
     let key = PoolDataKey::ResData(asset.clone());
     e.storage()
         .persistent()
@@ -440,19 +1007,219 @@ pub fn get_res_data(e: &Env, asset: &Address) -> ReserveData {
         .unwrap_optimized()
 }
 
-/// Set the reserve data for an asset
+/// Set the reserve data for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `data` - The reserve data for the asset
+pub fn set_res_data(e: &Env, asset: &Address, data: &ReserveData) {
+    let key = PoolDataKey::ResData(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReserveData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Delete the reserve config and data for an asset
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn del_res(e: &Env, asset: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&PoolDataKey::ResConfig(asset.clone()));
+    e.storage()
+        .persistent()
+        .remove(&PoolDataKey::ResData(asset.clone()));
+}
+
+/********** Reserve Sunset (ResSunset) **********/
+
+/// Fetch the sunset for a reserve, if it exists
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_res_sunset(e: &Env, asset: &Address) -> Option<ReserveSunset> {
+    let key = PoolDataKey::ResSunset(asset.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Check if a reserve has a sunset queued or in progress
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn has_res_sunset(e: &Env, asset: &Address) -> bool {
+    let key = PoolDataKey::ResSunset(asset.clone());
+    e.storage().persistent().has(&key)
+}
+
+/// Set the sunset for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `sunset` - The sunset for the reserve
+pub fn set_res_sunset(e: &Env, asset: &Address, sunset: &ReserveSunset) {
+    let key = PoolDataKey::ResSunset(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReserveSunset>(&key, sunset);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Delete the sunset for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn del_res_sunset(e: &Env, asset: &Address) {
+    let key = PoolDataKey::ResSunset(asset.clone());
+    e.storage().persistent().remove(&key);
+}
+
+/********** Withdrawal Queue (WithdrawQueue) **********/
+
+/// Fetch the queued withdrawals for a reserve, oldest first
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_withdrawal_queue(e: &Env, asset: &Address) -> Vec<QueuedWithdrawal> {
+    let key = PoolDataKey::WithdrawQueue(asset.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the queued withdrawals for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `queue` - The queued withdrawals, oldest first
+pub fn set_withdrawal_queue(e: &Env, asset: &Address, queue: &Vec<QueuedWithdrawal>) {
+    let key = PoolDataKey::WithdrawQueue(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, Vec<QueuedWithdrawal>>(&key, queue);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the total amount of filled withdrawals for a reserve that have not been claimed
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_withdrawal_total(e: &Env, asset: &Address) -> i128 {
+    let key = PoolDataKey::WithdrawTotal(asset.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || 0i128,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the total amount of filled withdrawals for a reserve that have not been claimed
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `amount` - The amount of unclaimed tokens
+pub fn set_withdrawal_total(e: &Env, asset: &Address, amount: &i128) {
+    let key = PoolDataKey::WithdrawTotal(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, i128>(&key, amount);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the total b_tokens queued for withdrawal for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+pub fn get_withdrawal_queued(e: &Env, asset: &Address) -> i128 {
+    let key = PoolDataKey::WithdrawQueued(asset.clone());
+    get_persistent_default(
+        e,
+        &key,
+        || 0i128,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the total b_tokens queued for withdrawal for a reserve
+///
+/// ### Arguments
+/// * `asset` - The contract address of the asset
+/// * `b_tokens` - The amount of queued b_tokens
+pub fn set_withdrawal_queued(e: &Env, asset: &Address, b_tokens: &i128) {
+    let key = PoolDataKey::WithdrawQueued(asset.clone());
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, i128>(&key, b_tokens);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the amount of filled withdrawals a user can claim for a reserve
 ///
 /// ### Arguments
+/// * `user` - The address of the user
 /// * `asset` - The contract address of the asset
-/// * `data` - The reserve data for the asset
-pub fn set_res_data(e: &Env, asset: &Address, data: &ReserveData) {
-    let key = PoolDataKey::ResData(asset.clone());
+pub fn get_withdrawal_claim(e: &Env, user: &Address, asset: &Address) -> i128 {
+    let key = PoolDataKey::WithdrawClaim(UserAssetKey {
+        user: user.clone(),
+        asset: asset.clone(),
+    });
+    get_persistent_default(e, &key, || 0i128, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set the amount of filled withdrawals a user can claim for a reserve
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `asset` - The contract address of the asset
+/// * `amount` - The amount of tokens the user can claim
+pub fn set_withdrawal_claim(e: &Env, user: &Address, asset: &Address, amount: &i128) {
+    let key = PoolDataKey::WithdrawClaim(UserAssetKey {
+        user: user.clone(),
+        asset: asset.clone(),
+    });
     e.storage()
         .persistent()
-        .set::<PoolDataKey, ReserveData>(&key, data);
+        .set::<PoolDataKey, i128>(&key, amount);
     e.storage()
         .persistent()
-        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Remove a user's claimable withdrawals for a reserve
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `asset` - The contract address of the asset
+pub fn del_withdrawal_claim(e: &Env, user: &Address, asset: &Address) {
+    let key = PoolDataKey::WithdrawClaim(UserAssetKey {
+        user: user.clone(),
+        asset: asset.clone(),
+    });
+    e.storage().persistent().remove(&key);
 }
 
 /********** Reserve List (ResList) **********/
@@ -476,7 +1243,8 @@ pub fn get_res_list(e: &Env) -> Vec<Address> {
 /// ### Panics
 /// If the number of reserves in the list exceeds 50
 ///
-// @dev: Once added it can't be removed
+// @dev: Once added it can't be removed, but a retired reserve's index can be
+//       reclaimed with `set_res_list_index`
 pub fn push_res_list(e: &Env, asset: &Address) -> u32 {
     let mut res_list = get_res_list(e);
     if res_list.len() >= MAX_RESERVES {
@@ -495,6 +1263,56 @@ pub fn push_res_list(e: &Env, asset: &Address) -> u32 {
     new_index
 }
 
+/// Replace the reserve at an existing index in the list
+///
+/// ### Arguments
+/// * `index` - The index of the reserve being replaced
+/// * `asset` - The contract address of the underlying asset
+///
+/// ### Panics
+/// If the index is not in the list
+pub fn set_res_list_index(e: &Env, index: u32, asset: &Address) {
+    let mut res_list = get_res_list(e);
+    if index >= res_list.len() {
+        panic_with_error!(e, PoolError::BadRequest)
+    }
+    res_list.set(index, asset.clone());
+    e.storage()
+        .persistent()
+        .set::<Symbol, Vec<Address>>(&Symbol::new(e, RES_LIST_KEY), &res_list);
+    e.storage().persistent().extend_ttl(
+        &Symbol::new(e, RES_LIST_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+}
+
+/// Fetch the list of reserve indexes that have been retired and can be reclaimed
+pub fn get_res_free_list(e: &Env) -> Vec<u32> {
+    get_persistent_default(
+        e,
+        &Symbol::new(e, RES_FREE_KEY),
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the list of reserve indexes that have been retired and can be reclaimed
+///
+/// ### Arguments
+/// * `free_list` - The retired reserve indexes
+pub fn set_res_free_list(e: &Env, free_list: &Vec<u32>) {
+    e.storage()
+        .persistent()
+        .set::<Symbol, Vec<u32>>(&Symbol::new(e, RES_FREE_KEY), free_list);
+    e.storage().persistent().extend_ttl(
+        &Symbol::new(e, RES_FREE_KEY),
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    );
+}
+
 /********** Reserve Emissions **********/
 
 /// Fetch the emission data for the reserve b or d token
@@ -527,6 +1345,39 @@ pub fn set_res_emis_data(e: &Env, res_token_index: &u32, res_emis_data: &Reserve
         .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/// Fetch the lowest user emission index that is valid for the reserve b or d token
+///
+/// User emission data below this index was recorded against a retired reserve that
+/// previously held the index, and should be treated as if it does not exist. Defaults to 0.
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+pub fn get_res_emis_floor(e: &Env, res_token_index: &u32) -> i128 {
+    let key = PoolDataKey::EmisFloor(*res_token_index);
+    get_persistent_default(
+        e,
+        &key,
+        || 0i128,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the lowest user emission index that is valid for the reserve b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `floor` - The new emission index floor
+pub fn set_res_emis_floor(e: &Env, res_token_index: &u32, floor: &i128) {
+    let key = PoolDataKey::EmisFloor(*res_token_index);
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, i128>(&key, floor);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
 /********** User Emissions **********/
 
 /// Fetch the users emission data for a reserve's b or d token
@@ -582,7 +1433,7 @@ pub fn get_pool_emissions(e: &Env) -> Map<u32, u64> {
 ///
 /// ### Arguments
 /// * `emissions` - The map of emissions by reserve token id to share of emissions as
-///                 a percentage of 1e7 (e.g. 15% = 1500000)
+///   a percentage of 1e7 (e.g. 15% = 1500000)
 pub fn set_pool_emissions(e: &Env, emissions: &Map<u32, u64>) {
     e.storage()
         .persistent()
@@ -594,6 +1445,131 @@ pub fn set_pool_emissions(e: &Env, emissions: &Map<u32, u64>) {
     );
 }
 
+/********** Reward Programs **********/
+
+/// Fetch the external reward tokens for a reserve's b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+pub fn get_reward_tokens(e: &Env, res_token_index: &u32) -> Vec<Address> {
+    get_persistent_default(
+        e,
+        &PoolDataKey::RewardTokens(*res_token_index),
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the external reward tokens for a reserve's b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `tokens` - The external reward tokens
+pub fn set_reward_tokens(e: &Env, res_token_index: &u32, tokens: &Vec<Address>) {
+    let key = PoolDataKey::RewardTokens(*res_token_index);
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, Vec<Address>>(&key, tokens);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the emission data of an external reward program for a reserve's b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `token` - The reward token
+pub fn get_reward_data(
+    e: &Env,
+    res_token_index: &u32,
+    token: &Address,
+) -> Option<ReserveEmissionData> {
+    let key = PoolDataKey::RewardData(RewardKey {
+        reserve_id: *res_token_index,
+        token: token.clone(),
+    });
+    get_persistent_default(
+        e,
+        &key,
+        || None,
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set the emission data of an external reward program for a reserve's b or d token
+///
+/// ### Arguments
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `token` - The reward token
+/// * `data` - The reward program's emission data
+pub fn set_reward_data(
+    e: &Env,
+    res_token_index: &u32,
+    token: &Address,
+    data: &ReserveEmissionData,
+) {
+    let key = PoolDataKey::RewardData(RewardKey {
+        reserve_id: *res_token_index,
+        token: token.clone(),
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, ReserveEmissionData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the users emission data of an external reward program for a reserve's b or d token
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `token` - The reward token
+pub fn get_user_reward(
+    e: &Env,
+    user: &Address,
+    res_token_index: &u32,
+    token: &Address,
+) -> Option<UserEmissionData> {
+    let key = PoolDataKey::UserReward(UserRewardKey {
+        user: user.clone(),
+        reserve_id: *res_token_index,
+        token: token.clone(),
+    });
+    get_persistent_default(e, &key, || None, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set the users emission data of an external reward program for a reserve's b or d token
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_index` - The d/bToken index for the reserve
+/// * `token` - The reward token
+/// * `data` - The new user emission data
+pub fn set_user_reward(
+    e: &Env,
+    user: &Address,
+    res_token_index: &u32,
+    token: &Address,
+    data: &UserEmissionData,
+) {
+    let key = PoolDataKey::UserReward(UserRewardKey {
+        user: user.clone(),
+        reserve_id: *res_token_index,
+        token: token.clone(),
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, UserEmissionData>(&key, data);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
 /********** Auctions ***********/
 
 /// Fetch the auction data for an auction
@@ -647,6 +1623,113 @@ pub fn set_auction(e: &Env, auction_type: &u32, user: &Address, auction_data: &A
         .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
 }
 
+/// Fetch the number of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+pub fn get_auction_count(e: &Env, auction_type: &u32) -> u32 {
+    let key = PoolDataKey::AuctionCnt(*auction_type);
+    get_persistent_default(e, &key, || 0, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED)
+}
+
+/// Set the number of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `count` - The number of users with an auction of the given type
+pub fn set_auction_count(e: &Env, auction_type: &u32, count: u32) {
+    let key = PoolDataKey::AuctionCnt(*auction_type);
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, u32>(&key, &count);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch a page of the index of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `page` - The page of the index
+pub fn get_auction_page(e: &Env, auction_type: &u32, page: u32) -> Vec<Address> {
+    let key = PoolDataKey::Auctions(AuctionPageKey {
+        auct_type: *auction_type,
+        page,
+    });
+    get_persistent_default(
+        e,
+        &key,
+        || vec![e],
+        LEDGER_THRESHOLD_SHARED,
+        LEDGER_BUMP_SHARED,
+    )
+}
+
+/// Set a page of the index of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `page` - The page of the index
+/// * `users` - The users in the page
+pub fn set_auction_page(e: &Env, auction_type: &u32, page: u32, users: &Vec<Address>) {
+    let key = PoolDataKey::Auctions(AuctionPageKey {
+        auct_type: *auction_type,
+        page,
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, Vec<Address>>(&key, users);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED);
+}
+
+/// Fetch the user's position in the index of users with an auction of the given type, if it exists
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `user` - The user involved in the auction
+pub fn get_auction_index(e: &Env, auction_type: &u32, user: &Address) -> Option<u32> {
+    let key = PoolDataKey::AuctionIdx(AuctionKey {
+        user: user.clone(),
+        auct_type: *auction_type,
+    });
+    get_persistent_default(e, &key, || None, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER)
+}
+
+/// Set the user's position in the index of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `user` - The user involved in the auction
+/// * `index` - The position of the user in the index
+pub fn set_auction_index(e: &Env, auction_type: &u32, user: &Address, index: u32) {
+    let key = PoolDataKey::AuctionIdx(AuctionKey {
+        user: user.clone(),
+        auct_type: *auction_type,
+    });
+    e.storage()
+        .persistent()
+        .set::<PoolDataKey, u32>(&key, &index);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER);
+}
+
+/// Remove the user from the index of users with an auction of the given type
+///
+/// ### Arguments
+/// * `auction_type` - The type of auction
+/// * `user` - The user involved in the auction
+pub fn del_auction_index(e: &Env, auction_type: &u32, user: &Address) {
+    let key = PoolDataKey::AuctionIdx(AuctionKey {
+        user: user.clone(),
+        auct_type: *auction_type,
+    });
+    e.storage().persistent().remove(&key);
+}
+
 /// Remove an auction
 ///
 /// ### Arguments
@@ -659,3 +1742,71 @@ pub fn del_auction(e: &Env, auction_type: &u32, user: &Address) {
     });
     e.storage().temporary().remove(&key);
 }
+
+/********** TTL Maintenance **********/
+
+/// Fetch the keys of every entry stored for the user. Only keys with an existing entry are
+/// returned. Auction entries are stored in temporary storage, all others in persistent storage.
+///
+/// ### Arguments
+/// * `user` - The address of the user
+/// * `res_token_ids` - The reserve token ids the user holds a position in
+pub fn get_user_storage_keys(
+    e: &Env,
+    user: &Address,
+    res_token_ids: &Vec<u32>,
+) -> Vec<PoolDataKey> {
+    let mut keys: Vec<PoolDataKey> = vec![e];
+    let mut push_persistent = |key: PoolDataKey| {
+        if e.storage().persistent().has(&key) {
+            keys.push_back(key);
+        }
+    };
+
+    push_persistent(PoolDataKey::UserPos(user.clone()));
+    push_persistent(PoolDataKey::Positions(user.clone()));
+    push_persistent(PoolDataKey::BorrowerIdx(user.clone()));
+    push_persistent(PoolDataKey::SoftLiq(user.clone()));
+    for res_token_id in res_token_ids.iter() {
+        push_persistent(PoolDataKey::UserEmis(UserReserveKey {
+            user: user.clone(),
+            reserve_id: res_token_id,
+        }));
+        for token in get_reward_tokens(e, &res_token_id).iter() {
+            push_persistent(PoolDataKey::UserReward(UserRewardKey {
+                user: user.clone(),
+                reserve_id: res_token_id,
+                token,
+            }));
+        }
+    }
+
+    for auct_type in 0..3 {
+        let key = PoolDataKey::Auction(AuctionKey {
+            user: user.clone(),
+            auct_type,
+        });
+        if e.storage().temporary().has(&key) {
+            keys.push_back(key);
+        }
+    }
+    keys
+}
+
+/// Extend the TTL of a user's entry. The entry must exist.
+///
+/// ### Arguments
+/// * `key` - The key of the entry
+pub fn bump_user_storage_key(e: &Env, key: &PoolDataKey) {
+    match key {
+        PoolDataKey::Auction(_) => {
+            e.storage()
+                .temporary()
+                .extend_ttl(key, LEDGER_THRESHOLD_SHARED, LEDGER_BUMP_SHARED)
+        }
+        _ => e
+            .storage()
+            .persistent()
+            .extend_ttl(key, LEDGER_THRESHOLD_USER, LEDGER_BUMP_USER),
+    }
+}
@@ -70,7 +70,7 @@ pub(crate) fn create_blnd_token<'a>(
 
 //***** Oracle ******
 
-pub(crate) fn create_mock_oracle(e: &Env) -> (Address, MockPriceOracleClient) {
+pub(crate) fn create_mock_oracle(e: &Env) -> (Address, MockPriceOracleClient<'_>) {
     let contract_address = e.register(MockPriceOracleWASM, ());
     (
         contract_address.clone(),
@@ -80,10 +80,10 @@ pub(crate) fn create_mock_oracle(e: &Env) -> (Address, MockPriceOracleClient) {
 
 //***** Pool Factory ******
 
-pub(crate) fn create_mock_pool_factory(e: &Env) -> (Address, MockPoolFactoryClient) {
+pub(crate) fn create_mock_pool_factory(e: &Env) -> (Address, MockPoolFactoryClient<'_>) {
     let pool_init_meta = PoolInitMeta {
         backstop: Address::generate(e),
-        pool_hash: BytesN::<32>::from_array(&e, &[0u8; 32]),
+        pool_hash: BytesN::<32>::from_array(e, &[0u8; 32]),
         blnd_id: Address::generate(e),
     };
     let contract_address = e.register(MockPoolFactory {}, (pool_init_meta,));
@@ -134,6 +134,7 @@ pub(crate) fn create_backstop<'a>(
             usdc_token,
             pool_factory,
             vec![e, (pool_address.clone(), 40_000_000 * SCALAR_7)],
+            Address::generate(e),
         ),
     );
     e.as_contract(pool_address, || {
@@ -161,8 +162,8 @@ pub(crate) fn create_comet_lp_pool<'a>(
 
     let blnd_client = MockTokenClient::new(e, blnd_token);
     let usdc_client = MockTokenClient::new(e, usdc_token);
-    blnd_client.mint(&admin, &1_000_0000000);
-    usdc_client.mint(&admin, &25_0000000);
+    blnd_client.mint(admin, &1_000_0000000);
+    usdc_client.mint(admin, &25_0000000);
 
     client.init(
         admin,
@@ -274,10 +275,10 @@ pub(crate) fn create_reserve(
 ) {
     let mut new_reserve_config = reserve_config.clone();
     e.as_contract(pool_address, || {
-        let index = storage::push_res_list(e, &token_address);
+        let index = storage::push_res_list(e, token_address);
         new_reserve_config.index = index;
-        storage::set_res_config(e, &token_address, &new_reserve_config);
-        storage::set_res_data(e, &token_address, &reserve_data);
+        storage::set_res_config(e, token_address, &new_reserve_config);
+        storage::set_res_data(e, token_address, reserve_data);
     });
     let underlying_client = MockTokenClient::new(e, token_address);
 
@@ -292,5 +293,5 @@ pub(crate) fn create_reserve(
     let to_mint_pool = total_supply - total_liabilities + reserve_data.backstop_credit;
     underlying_client
         .mock_all_auths()
-        .mint(&pool_address, &to_mint_pool);
+        .mint(pool_address, &to_mint_pool);
 }
@@ -113,6 +113,7 @@ pub fn create<'a>(wasm: bool) -> TestFixture<'a> {
                 (bombadil.clone(), 10_000_000 * SCALAR_7),
                 (frodo.clone(), 30_000_000 * SCALAR_7)
             ],
+            &bombadil,
         );
         let pool_hash = e.deployer().upload_contract_wasm(POOL_WASM);
         let pool_init_meta = PoolInitMeta {
@@ -504,6 +504,7 @@ fn test_backstop_constructor() {
     let blnd_token = Address::generate(&e);
     let usdc_token = Address::generate(&e);
     let pool_factory = Address::generate(&e);
+    let admin = Address::generate(&e);
     let drop_list: Vec<(Address, i128)> = vec![
         &e,
         (Address::generate(&e), 10_000_000_0000000),
@@ -520,6 +521,7 @@ fn test_backstop_constructor() {
             usdc_token.clone(),
             pool_factory.clone(),
             drop_list.clone(),
+            admin.clone(),
         ),
     );
 
@@ -558,6 +560,13 @@ fn test_backstop_constructor() {
             .get::<Symbol, Address>(&Symbol::new(&e, "PoolFact"))
             .unwrap();
         assert_eq!(contract_pool_factory, pool_factory);
+
+        let contract_admin = e
+            .storage()
+            .instance()
+            .get::<Symbol, Address>(&Symbol::new(&e, "Admin"))
+            .unwrap();
+        assert_eq!(contract_admin, admin);
     });
 
     let backstop_client = BackstopClient::new(&e, &contract_id);
@@ -590,6 +599,7 @@ fn test_backstop_constructor_over_max() {
             usdc_token.clone(),
             pool_factory.clone(),
             drop_list.clone(),
+            Address::generate(&e),
         ),
     );
 }